@@ -33,7 +33,9 @@ use openidconnect::{
     reqwest::async_http_client,
     ClientId, ClientSecret, IssuerUrl, RedirectUrl,
 };
-use router_resolver::{config_handler, graphql_playground, integration_webhook, upload_file};
+use router_resolver::{
+    calendar_feed, config_handler, graphql_playground, integration_webhook, upload_file,
+};
 use sea_orm::DatabaseConnection;
 use statistics_resolver::StatisticsQuery;
 use statistics_service::StatisticsService;
@@ -145,8 +147,10 @@ pub async fn create_app_services(
         .route("/config", get(config_handler))
         .route("/graphql", gql)
         .route("/upload", post(upload_file))
+        .route("/calendar/:token", get(calendar_feed))
         .layer(Extension(config.clone()))
         .layer(Extension(integration_service.clone()))
+        .layer(Extension(miscellaneous_service.clone()))
         .layer(Extension(schema))
         .layer(TowerTraceLayer::new_for_http())
         .layer(TowerCatchPanicLayer::new())
@@ -39,6 +39,16 @@ pub async fn run_frequent_jobs(
         .send_pending_immediate_notifications()
         .await
         .trace_ok();
+    app_services
+        .miscellaneous_service
+        .send_pending_notification_digests()
+        .await
+        .trace_ok();
+    app_services
+        .miscellaneous_service
+        .retry_failed_notification_deliveries()
+        .await
+        .trace_ok();
     Ok(())
 }
 
@@ -130,8 +140,17 @@ pub async fn perform_mp_application_job(
                 .perform_background_jobs()
                 .await
         }
-        MpApplicationJob::PerformExport(user_id) => {
-            app_services.exporter_service.perform_export(user_id).await
+        MpApplicationJob::PerformExport(user_id, input) => {
+            app_services
+                .exporter_service
+                .perform_export(user_id, input)
+                .await
+        }
+        MpApplicationJob::PerformInstanceBackup(user_id, passphrase) => {
+            app_services
+                .exporter_service
+                .perform_instance_backup(user_id, passphrase)
+                .await
         }
         MpApplicationJob::UpdateExerciseLibrary => {
             app_services
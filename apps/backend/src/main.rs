@@ -6,16 +6,19 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use apalis::{
     layers::WorkerBuilderExt,
     prelude::{MemoryStorage, Monitor, WorkerBuilder, WorkerFactoryFn},
 };
 use apalis_cron::{CronStream, Schedule};
+use application_utils::set_provider_http_config;
 use aws_sdk_s3::config::Region;
 use common_utils::{ryot_log, PROJECT_NAME, TEMP_DIR};
 use dependent_models::CompleteExport;
 use env_utils::APP_VERSION;
+use exporter_service::{create_instance_backup, encrypt_export, restore_instance_backup};
+use itertools::Itertools;
 use logs_wheel::LogFileInitializer;
 use migrations::Migrator;
 use schematic::schema::{SchemaGenerator, TypeScriptRenderer, YamlTemplateRenderer};
@@ -41,6 +44,13 @@ mod job;
 
 static BASE_DIR: &str = env!("CARGO_MANIFEST_DIR");
 static LOGGING_ENV_VAR: &str = "RUST_LOG";
+static INSTANCE_BACKUP_PASSPHRASE_ENV_VAR: &str = "INSTANCE_BACKUP_PASSPHRASE";
+static INSTANCE_BACKUP_USAGE: &str = "Usage: backend <backup|restore> <path>";
+
+enum InstanceBackupCommand {
+    Backup(PathBuf),
+    Restore(PathBuf),
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -57,6 +67,21 @@ async fn main() -> Result<()> {
     }
     init_tracing()?;
 
+    let args = env::args().skip(1).collect_vec();
+    let instance_backup_command = match args.as_slice() {
+        [] => None,
+        [command, path] if command == "backup" => {
+            Some(InstanceBackupCommand::Backup(PathBuf::from(path)))
+        }
+        [command, path] if command == "restore" => {
+            Some(InstanceBackupCommand::Restore(PathBuf::from(path)))
+        }
+        _ => {
+            eprintln!("{}", INSTANCE_BACKUP_USAGE);
+            std::process::exit(2);
+        }
+    };
+
     ryot_log!(info, "Running version: {}", APP_VERSION);
 
     let config = Arc::new(config::load_app_config()?);
@@ -66,6 +91,8 @@ async fn main() -> Result<()> {
         sleep(duration).await;
     }
 
+    set_provider_http_config(config.media.provider_http.clone());
+
     let sync_every_minutes = config.integration.sync_every_minutes;
     let disable_background_jobs = config.server.disable_background_jobs;
 
@@ -106,6 +133,10 @@ async fn main() -> Result<()> {
         bail!("There was an error running the database migrations.");
     };
 
+    if let Some(command) = instance_backup_command {
+        return run_instance_backup_command(&db, command).await;
+    }
+
     let lp_application_job_storage = MemoryStorage::new();
     let mp_application_job_storage = MemoryStorage::new();
     let hp_application_job_storage = MemoryStorage::new();
@@ -226,6 +257,43 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Handle the `backup <path>` and `restore <path>` commands. A backup can only be restored
+/// into a database that does not contain any data. If a passphrase is set in the
+/// environment, backups are encrypted with it and encrypted backups are decrypted with it.
+async fn run_instance_backup_command(
+    db: &DatabaseConnection,
+    command: InstanceBackupCommand,
+) -> Result<()> {
+    let passphrase = env::var(INSTANCE_BACKUP_PASSPHRASE_ENV_VAR)
+        .ok()
+        .filter(|p| !p.is_empty());
+    let path = match command {
+        InstanceBackupCommand::Backup(path) => {
+            create_instance_backup(db, &path)
+                .await
+                .map_err(|e| anyhow!(e.message))?;
+            match passphrase {
+                None => path,
+                Some(passphrase) => encrypt_export(path, &passphrase)
+                    .await
+                    .map_err(|e| anyhow!(e.message))?,
+            }
+        }
+        InstanceBackupCommand::Restore(path) => {
+            restore_instance_backup(db, &path, passphrase.as_deref())
+                .await
+                .map_err(|e| anyhow!(e.message))?;
+            path
+        }
+    };
+    ryot_log!(
+        info,
+        "Completed instance backup command using {}",
+        path.display()
+    );
+    Ok(())
+}
+
 fn init_tracing() -> Result<()> {
     let tmp_dir = PathBuf::new().join(TEMP_DIR);
     create_dir_all(&tmp_dir)?;
@@ -1034,6 +1034,22 @@ export const DisplaySummarySection = ({
 					},
 				]}
 			/>
+			<DisplayStatForMediaType
+				lot={MediaLot.BoardGame}
+				data={[
+					{
+						label: "Plays",
+						value: latestUserSummary.boardGameCount,
+						type: "number",
+					},
+					{
+						label: "Time played",
+						value: latestUserSummary.totalBoardGameDuration,
+						type: "duration",
+						hideIfZero: true,
+					},
+				]}
+			/>
 			<DisplayStatForMediaType
 				lot={MediaLot.VisualNovel}
 				data={[
@@ -1104,6 +1120,16 @@ export const DisplaySummarySection = ({
 					},
 				]}
 			/>
+			<DisplayStatForMediaType
+				lot={MediaLot.Comic}
+				data={[
+					{
+						label: "Comics",
+						value: latestUserSummary.comicCount,
+						type: "number",
+					},
+				]}
+			/>
 			<DisplayStatForMediaType
 				lot={MediaLot.Anime}
 				data={[
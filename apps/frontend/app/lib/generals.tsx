@@ -24,7 +24,9 @@ import {
 	IconDeviceDesktop,
 	IconDeviceTv,
 	IconDeviceTvOld,
+	IconDice5,
 	IconHeadphones,
+	IconMask,
 	IconMicrophone,
 	IconMusic,
 } from "@tabler/icons-react";
@@ -179,6 +181,15 @@ export const getLot = (lot: unknown) => {
 	return match(newLot)
 		.with("anime", "animes", () => MediaLot.Anime)
 		.with("manga", "mangas", () => MediaLot.Manga)
+		.with("comic", "comics", () => MediaLot.Comic)
+		.with(
+			"board_game",
+			"boardgame",
+			"board game",
+			"board_games",
+			"boardgames",
+			() => MediaLot.BoardGame,
+		)
 		.with("books", "book", () => MediaLot.Book)
 		.with("movies", "movie", () => MediaLot.Movie)
 		.with("tv", "show", "shows", () => MediaLot.Show)
@@ -240,6 +251,14 @@ export const getLotGradient = (lot: MediaLot) =>
 			from: "orange",
 			to: "pink",
 		}))
+		.with(MediaLot.Comic, () => ({
+			from: "yellow",
+			to: "red",
+		}))
+		.with(MediaLot.BoardGame, () => ({
+			from: "lime",
+			to: "orange",
+		}))
 		.exhaustive();
 
 /**
@@ -267,7 +286,7 @@ export const getVerb = (verb: Verb, lot: MediaLot) =>
 	match(verb)
 		.with(Verb.Read, () => {
 			return match(lot)
-				.with(MediaLot.Book, MediaLot.Manga, () => "read")
+				.with(MediaLot.Book, MediaLot.Manga, MediaLot.Comic, () => "read")
 				.with(
 					MediaLot.Movie,
 					MediaLot.Show,
@@ -280,6 +299,7 @@ export const getVerb = (verb: Verb, lot: MediaLot) =>
 					MediaLot.Music,
 					MediaLot.VideoGame,
 					MediaLot.Podcast,
+					MediaLot.BoardGame,
 					() => "play",
 				)
 				.otherwise(() => {
@@ -317,6 +337,7 @@ export const getMetadataIcon = (lot: MediaLot) =>
 	match(lot)
 		.with(MediaLot.Book, () => IconBook)
 		.with(MediaLot.Manga, () => IconBooks)
+		.with(MediaLot.Comic, () => IconMask)
 		.with(MediaLot.Music, () => IconMusic)
 		.with(MediaLot.Movie, () => IconDeviceTv)
 		.with(MediaLot.Anime, () => IconDeviceTvOld)
@@ -325,6 +346,7 @@ export const getMetadataIcon = (lot: MediaLot) =>
 		.with(MediaLot.Podcast, () => IconMicrophone)
 		.with(MediaLot.AudioBook, () => IconHeadphones)
 		.with(MediaLot.VideoGame, () => IconBrandAppleArcade)
+		.with(MediaLot.BoardGame, () => IconDice5)
 		.exhaustive();
 
 export const applicationBaseUrl =
@@ -491,11 +513,13 @@ export const MediaColors: EntityColor = {
 	AUDIO_BOOK: "orange",
 	BOOK: "lime",
 	MANGA: "purple",
+	COMIC: "grape",
 	MOVIE: "cyan",
 	PODCAST: "yellow",
 	SHOW: "red",
 	VISUAL_NOVEL: "pink",
 	VIDEO_GAME: "teal",
+	BOARD_GAME: "orange.8",
 	WORKOUT: "violet",
 	REVIEW: "green.5",
 	USER_MEASUREMENT: "indigo",
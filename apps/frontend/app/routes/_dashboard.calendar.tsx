@@ -1,30 +1,48 @@
 import {
 	ActionIcon,
+	Alert,
 	Box,
 	Button,
 	Container,
+	CopyButton,
+	Flex,
 	Group,
 	Stack,
 	Text,
+	TextInput,
+	ThemeIcon,
 	Title,
+	Tooltip,
 } from "@mantine/core";
-import type { LoaderFunctionArgs, MetaArgs } from "@remix-run/node";
-import { useLoaderData } from "@remix-run/react";
+import type {
+	ActionFunctionArgs,
+	LoaderFunctionArgs,
+	MetaArgs,
+} from "@remix-run/node";
+import { Form, useActionData, useLoaderData } from "@remix-run/react";
 import {
 	type CalendarEventPartFragment,
+	GenerateCalendarFeedTokenDocument,
 	MediaLot,
 	UserCalendarEventsDocument,
 	type UserCalendarEventsQuery,
 } from "@ryot/generated/graphql/backend/graphql";
 import { sum } from "@ryot/ts-utils";
-import { IconChevronLeft, IconChevronRight } from "@tabler/icons-react";
+import {
+	IconCalendarShare,
+	IconCheck,
+	IconChevronLeft,
+	IconChevronRight,
+	IconCopy,
+	IconEye,
+} from "@tabler/icons-react";
 import { Fragment } from "react/jsx-runtime";
 import { match } from "ts-pattern";
 import { z } from "zod";
 import { zx } from "zodix";
 import { ApplicationGrid } from "~/components/common";
 import { MetadataDisplayItem } from "~/components/media";
-import { dayjsLib } from "~/lib/generals";
+import { applicationBaseUrl, dayjsLib } from "~/lib/generals";
 import { useAppSearchParam } from "~/lib/hooks";
 import {
 	getEnhancedCookieName,
@@ -55,10 +73,24 @@ export const meta = (_args: MetaArgs<typeof loader>) => {
 	return [{ title: "Calendar | Ryot" }];
 };
 
+export const action = async ({ request }: ActionFunctionArgs) => {
+	const { generateCalendarFeedToken } =
+		await serverGqlService.authenticatedRequest(
+			request,
+			GenerateCalendarFeedTokenDocument,
+			{},
+		);
+	return { generateCalendarFeedToken };
+};
+
 export default function Page() {
 	const loaderData = useLoaderData<typeof loader>();
+	const actionData = useActionData<typeof action>();
 	const [_, { setP }] = useAppSearchParam(loaderData.cookieName);
 	const date = dayjsLib(loaderData.query.date);
+	const feedUrl = actionData
+		? `${applicationBaseUrl}/backend/calendar/${actionData.generateCalendarFeedToken}.ics`
+		: undefined;
 
 	return (
 		<Container>
@@ -87,8 +119,47 @@ export default function Page() {
 						>
 							<IconChevronRight />
 						</ActionIcon>
+						<Form replace method="POST">
+							<Tooltip label="Subscribe to calendar">
+								<ActionIcon variant="outline" ml="xs" type="submit">
+									<IconCalendarShare />
+								</ActionIcon>
+							</Tooltip>
+						</Form>
 					</Button.Group>
 				</Group>
+				{feedUrl ? (
+					<Alert
+						color="yellow"
+						title="Add this URL to your calendar application. Any URL generated previously will stop working."
+					>
+						<Flex align="center">
+							<CopyButton value={feedUrl}>
+								{({ copied, copy }) => (
+									<Tooltip
+										label={copied ? "Copied" : "Copy"}
+										withArrow
+										position="right"
+									>
+										<ActionIcon color={copied ? "teal" : "gray"} onClick={copy}>
+											{copied ? (
+												<IconCheck size={16} />
+											) : (
+												<IconCopy size={16} />
+											)}
+										</ActionIcon>
+									</Tooltip>
+								)}
+							</CopyButton>
+							<TextInput
+								readOnly
+								value={feedUrl}
+								style={{ flex: 1 }}
+								onClick={(e) => e.currentTarget.select()}
+							/>
+						</Flex>
+					</Alert>
+				) : null}
 				{loaderData.userCalendarEvents.length > 0 ? (
 					<Stack gap={4}>
 						<Box>
@@ -145,6 +216,15 @@ const DisplayCalendarEvent = ({
 		<MetadataDisplayItem
 			metadataId={calEvent.metadataId}
 			altName={calEvent.episodeName || calEvent.metadataTitle}
+			topRight={
+				calEvent.monitoredEntity ? (
+					<Tooltip label={`Monitoring ${calEvent.monitoredEntity.name}`}>
+						<ThemeIcon variant="transparent" size="sm" color="cyan">
+							<IconEye size={16} />
+						</ThemeIcon>
+					</Tooltip>
+				) : undefined
+			}
 			rightLabel={`${match(calEvent.metadataLot)
 				.with(
 					MediaLot.Show,
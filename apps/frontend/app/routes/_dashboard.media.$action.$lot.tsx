@@ -369,9 +369,13 @@ export default function Page() {
 							<DebouncedSearchInput
 								initialValue={loaderData.query.query}
 								enhancedQueryParams={loaderData.cookieName}
-								placeholder={`Sift through your ${changeCase(
-									loaderData.lot.toLowerCase(),
-								).toLowerCase()}s`}
+								placeholder={
+									mediaSearch.url.source === MediaSource.Rss
+										? "Enter the URL of a podcast feed"
+										: `Sift through your ${changeCase(
+												loaderData.lot.toLowerCase(),
+											).toLowerCase()}s`
+								}
 							/>
 							{mediaSearch.mediaSources.length > 1 ? (
 								<Select
@@ -50,7 +50,9 @@ import {
 	MetadataVideoSource,
 	type PodcastEpisode,
 	SeenState,
+	SwitchMetadataSourceDocument,
 	UpdateSeenItemDocument,
+	UserLot,
 	UserMetadataDetailsDocument,
 	type UserMetadataDetailsQuery,
 	UserReviewScale,
@@ -203,6 +205,23 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 				{ type: "success", message: "Metadata merged successfully" },
 			);
 		})
+		.with("switchMetadataSource", async () => {
+			const submission = processSubmission(
+				formData,
+				switchMetadataSourceSchema,
+			);
+			await serverGqlService.authenticatedRequest(
+				request,
+				SwitchMetadataSourceDocument,
+				{ input: submission },
+			);
+			return Response.json({ status: "success", tt: new Date() } as const, {
+				headers: await createToastHeaders({
+					type: "success",
+					message: "Provider switched successfully",
+				}),
+			});
+		})
 		.with("editSeenItem", async () => {
 			const submission = processSubmission(formData, editSeenItem);
 			submission.reviewId = submission.reviewId || "";
@@ -240,6 +259,12 @@ const mergeMetadataSchema = z.object({
 	mergeInto: z.string(),
 });
 
+const switchMetadataSourceSchema = z.object({
+	metadataId: z.string(),
+	identifier: z.string(),
+	source: z.nativeEnum(MediaSource),
+});
+
 const dateString = z
 	.string()
 	.transform((v) => formatDateToNaiveDate(new Date(v)));
@@ -278,6 +303,13 @@ export default function Page() {
 		mergeMetadataModalOpened,
 		{ open: mergeMetadataModalOpen, close: mergeMetadataModalClose },
 	] = useDisclosure(false);
+	const [
+		switchMetadataSourceModalOpened,
+		{
+			open: switchMetadataSourceModalOpen,
+			close: switchMetadataSourceModalClose,
+		},
+	] = useDisclosure(false);
 	const [_m, setMetadataToUpdate] = useMetadataProgressUpdate();
 	const [_r, setEntityToReview] = useReviewEntity();
 	const [_a, setAddEntityToCollectionData] = useAddEntityToCollection();
@@ -317,6 +349,20 @@ export default function Page() {
 			`${loaderData.metadataDetails.mangaSpecifics.chapters} chapters`,
 		loaderData.metadataDetails.mangaSpecifics?.volumes &&
 			`${loaderData.metadataDetails.mangaSpecifics.volumes} volumes`,
+		loaderData.metadataDetails.comicSpecifics?.issueNumber &&
+			`Issue #${loaderData.metadataDetails.comicSpecifics.issueNumber}`,
+		loaderData.metadataDetails.comicSpecifics?.publisher,
+		loaderData.metadataDetails.boardGameSpecifics?.maxPlayers &&
+			`${loaderData.metadataDetails.boardGameSpecifics.minPlayers ?? 1}-${loaderData.metadataDetails.boardGameSpecifics.maxPlayers} players`,
+		loaderData.metadataDetails.boardGameSpecifics?.playTime &&
+			humanizeDuration(
+				dayjsLib
+					.duration(
+						loaderData.metadataDetails.boardGameSpecifics.playTime,
+						"minute",
+					)
+					.asMilliseconds(),
+			),
 		loaderData.metadataDetails.movieSpecifics?.runtime &&
 			humanizeDuration(
 				dayjsLib
@@ -485,7 +531,18 @@ export default function Page() {
 											.with(MediaSource.Vndb, () => "vndb.ico")
 											.with(MediaSource.YoutubeMusic, () => "youtube-music.png")
 											.with(MediaSource.Hardcover, () => "hardcover.png")
-											.with(MediaSource.Custom, () => undefined)
+											.with(
+												MediaSource.Tvdb,
+												MediaSource.Custom,
+												MediaSource.Remote,
+												MediaSource.ComicVine,
+												MediaSource.MusicBrainz,
+												MediaSource.BoardGameGeek,
+												MediaSource.PodcastIndex,
+												MediaSource.Rawg,
+												MediaSource.Rss,
+												() => undefined,
+											)
 											.exhaustive()}`}
 									/>
 									<Text fz="sm">
@@ -498,7 +555,9 @@ export default function Page() {
 												MediaSource.Tmdb,
 												MediaSource.Vndb,
 												MediaSource.Anilist,
+												MediaSource.Rawg,
 												MediaSource.Listennotes,
+												MediaSource.BoardGameGeek,
 												() => "%",
 											)
 											.with(
@@ -513,10 +572,16 @@ export default function Page() {
 												() => "/10",
 											)
 											.with(
+												MediaSource.Tvdb,
 												MediaSource.Custom,
+												MediaSource.Remote,
 												MediaSource.Itunes,
+												MediaSource.ComicVine,
 												MediaSource.Openlibrary,
+												MediaSource.MusicBrainz,
 												MediaSource.YoutubeMusic,
+												MediaSource.PodcastIndex,
+												MediaSource.Rss,
 												() => undefined,
 											)
 											.exhaustive()}
@@ -718,6 +783,12 @@ export default function Page() {
 								opened={mergeMetadataModalOpened}
 								metadataId={loaderData.metadataId}
 							/>
+							<SwitchMetadataSourceModal
+								onClose={switchMetadataSourceModalClose}
+								opened={switchMetadataSourceModalOpened}
+								metadataId={loaderData.metadataId}
+								linkedSources={loaderData.metadataDetails.linkedSources}
+							/>
 							<MediaScrollArea>
 								<SimpleGrid cols={{ base: 1, md: 2 }} spacing="lg">
 									<Menu shadow="md">
@@ -989,6 +1060,12 @@ export default function Page() {
 											<Menu.Item onClick={mergeMetadataModalOpen}>
 												Merge media
 											</Menu.Item>
+											{userDetails.lot === UserLot.Admin &&
+											loaderData.metadataDetails.linkedSources.length > 0 ? (
+												<Menu.Item onClick={switchMetadataSourceModalOpen}>
+													Switch provider
+												</Menu.Item>
+											) : null}
 											<Form
 												method="POST"
 												action={withQuery(".", { intent: "removeItem" })}
@@ -1520,6 +1597,64 @@ const MergeMetadataModal = (props: {
 	);
 };
 
+const SwitchMetadataSourceModal = (props: {
+	opened: boolean;
+	metadataId: string;
+	onClose: () => void;
+	linkedSources: MetadataDetailsQuery["metadataDetails"]["linkedSources"];
+}) => {
+	const [selected, setSelected] = useState<string | null>(null);
+	const linkedSource = props.linkedSources.find((l) => l.source === selected);
+
+	return (
+		<Modal
+			opened={props.opened}
+			onClose={props.onClose}
+			withCloseButton={false}
+			centered
+		>
+			<Form
+				replace
+				method="POST"
+				action={withQuery(".", { intent: "switchMetadataSource" })}
+			>
+				<input hidden name="metadataId" defaultValue={props.metadataId} />
+				<input hidden name="source" value={linkedSource?.source} readOnly />
+				<input
+					hidden
+					name="identifier"
+					value={linkedSource?.identifier}
+					readOnly
+				/>
+				<Stack>
+					<Title order={3}>Switch provider</Title>
+					<Text>
+						This will use the selected provider to get the details of this media
+						for all users. All history, reviews, and collections are retained.
+					</Text>
+					<Select
+						required
+						value={selected}
+						onChange={setSelected}
+						label="Provider"
+						data={props.linkedSources.map((l) => ({
+							value: l.source,
+							label: changeCase(l.source),
+						}))}
+					/>
+					<Button
+						type="submit"
+						onClick={props.onClose}
+						disabled={!linkedSource}
+					>
+						Submit
+					</Button>
+				</Stack>
+			</Form>
+		</Modal>
+	);
+};
+
 const HistoryItem = (props: {
 	index: number;
 	history: History;
@@ -1607,6 +1742,16 @@ const HistoryItem = (props: {
 
 		return null;
 	})();
+	const displayBoardGameExtraInformation = (() => {
+		const { playerCount, duration, winner } =
+			props.history.boardGameExtraInformation || {};
+		const information = [
+			isNumber(playerCount) ? `${playerCount} players` : null,
+			isNumber(duration) ? `${duration} min` : null,
+			winner ? `Won by ${winner}` : null,
+		].filter((s) => s !== null);
+		return information.length > 0 ? information.join(", ") : null;
+	})();
 	const watchedOnInformation = props.history.providerWatchedOn;
 
 	const filteredDisplayInformation = [
@@ -1615,6 +1760,7 @@ const HistoryItem = (props: {
 		displayPodcastExtraInformation,
 		displayAnimeExtraInformation,
 		displayMangaExtraInformation,
+		displayBoardGameExtraInformation,
 	].filter((s) => s !== null);
 	const displayAllInformation =
 		filteredDisplayInformation.length > 0
@@ -76,6 +76,7 @@ const searchUrlSchema = z.object({
 	isTmdbCompany: zx.BoolAsString.optional(),
 	isAnilistStudio: zx.BoolAsString.optional(),
 	isHardcoverPublisher: zx.BoolAsString.optional(),
+	isMusicBrainzLabel: zx.BoolAsString.optional(),
 	source: z.nativeEnum(MediaSource).default(MediaSource.Tmdb),
 });
 
@@ -127,6 +128,7 @@ export const loader = async ({ request, params }: LoaderFunctionArgs) => {
 							isTmdbCompany: urlParse.isTmdbCompany,
 							isAnilistStudio: urlParse.isAnilistStudio,
 							isHardcoverPublisher: urlParse.isHardcoverPublisher,
+							isMusicBrainzLabel: urlParse.isMusicBrainzLabel,
 						},
 						search: { page: query[pageQueryParam], query: query.query },
 					},
@@ -269,6 +271,16 @@ export default function Page() {
 									}
 								/>
 							) : null}
+							{loaderData.peopleSearch?.url.source ===
+							MediaSource.MusicBrainz ? (
+								<Checkbox
+									label="Label"
+									checked={loaderData.peopleSearch?.url.isMusicBrainzLabel}
+									onChange={(e) =>
+										setP("isMusicBrainzLabel", String(e.target.checked))
+									}
+								/>
+							) : null}
 						</>
 					) : null}
 				</Group>
@@ -407,6 +419,11 @@ const commitPerson = async (
 			"isHardcoverPublisher",
 			String(additionalData.isHardcoverPublisher),
 		);
+	if (additionalData.isMusicBrainzLabel)
+		data.append(
+			"isMusicBrainzLabel",
+			String(additionalData.isMusicBrainzLabel),
+		);
 	const resp = await fetch($path("/actions", { intent: "commitPerson" }), {
 		method: "POST",
 		body: data,
@@ -1,4 +1,5 @@
 import {
+	Alert,
 	Anchor,
 	Button,
 	Code,
@@ -10,6 +11,7 @@ import {
 	Select,
 	Stack,
 	Switch,
+	Text,
 	TextInput,
 	Textarea,
 	Title,
@@ -26,10 +28,17 @@ import {
 	CreateCustomMetadataDocument,
 	MediaLot,
 	MetadataDetailsDocument,
+	ScrapeCustomMetadataDocument,
 	UpdateCustomMetadataDocument,
 } from "@ryot/generated/graphql/backend/graphql";
 import { camelCase, changeCase, processSubmission } from "@ryot/ts-utils";
-import { IconCalendar, IconPhoto, IconVideo } from "@tabler/icons-react";
+import {
+	IconAlertCircle,
+	IconCalendar,
+	IconLink,
+	IconPhoto,
+	IconVideo,
+} from "@tabler/icons-react";
 import { $path } from "remix-routes";
 import invariant from "tiny-invariant";
 import { match } from "ts-pattern";
@@ -45,6 +54,7 @@ enum Action {
 
 const searchParamsSchema = z.object({
 	id: z.string().optional(),
+	url: z.string().optional(),
 	lot: z.nativeEnum(MediaLot).optional(),
 });
 
@@ -65,7 +75,26 @@ export const loader = async ({ params, request }: LoaderFunctionArgs) => {
 			return metadataDetails;
 		})
 		.exhaustive();
-	return { query, action, details };
+	const scraped = await match(action)
+		.with(Action.Create, async () => {
+			if (!query.url) return undefined;
+			try {
+				const { scrapeCustomMetadata } =
+					await serverGqlService.authenticatedRequest(
+						request,
+						ScrapeCustomMetadataDocument,
+						{ input: { url: query.url, lot: query.lot } },
+					);
+				return { data: scrapeCustomMetadata };
+			} catch (e) {
+				if (!(e instanceof Response && e.ok)) throw e;
+				const { message } = await e.json();
+				return { error: message as string };
+			}
+		})
+		.with(Action.Edit, () => undefined)
+		.exhaustive();
+	return { query, action, details, scraped };
 };
 
 export const meta = (_args: MetaArgs<typeof loader>) => {
@@ -133,10 +162,43 @@ export default function Page() {
 	const loaderData = useLoaderData<typeof loader>();
 	const coreDetails = useCoreDetails();
 	const fileUploadNotAllowed = !coreDetails.fileStorageEnabled;
+	const scraped = loaderData.scraped?.data;
 
 	return (
 		<Container>
-			<Form method="POST" encType="multipart/form-data">
+			{loaderData.action === Action.Create ? (
+				<Form method="GET">
+					{loaderData.query.lot ? (
+						<input hidden name="lot" defaultValue={loaderData.query.lot} />
+					) : null}
+					<Stack mb="md">
+						<Group wrap="nowrap" align="end">
+							<TextInput
+								flex={1}
+								name="url"
+								type="url"
+								label="Fetch details from a web page"
+								leftSection={<IconLink />}
+								placeholder="https://example.com/some-book"
+								defaultValue={loaderData.query.url}
+							/>
+							<Button type="submit" variant="default">
+								Fetch
+							</Button>
+						</Group>
+						{loaderData.scraped?.error ? (
+							<Alert color="red" icon={<IconAlertCircle />}>
+								{loaderData.scraped.error}
+							</Alert>
+						) : null}
+					</Stack>
+				</Form>
+			) : null}
+			<Form
+				method="POST"
+				key={loaderData.query.url}
+				encType="multipart/form-data"
+			>
 				<input hidden name="action" defaultValue={loaderData.action} />
 				{loaderData.details ? (
 					<input hidden name="id" defaultValue={loaderData.details.id} />
@@ -152,14 +214,16 @@ export default function Page() {
 						autoFocus
 						name="title"
 						label="Title"
-						defaultValue={loaderData.details?.title}
+						defaultValue={loaderData.details?.title || scraped?.title}
 					/>
 					<Group wrap="nowrap">
 						<Select
 							required
 							name="lot"
 							label="Type"
-							defaultValue={loaderData.details?.lot || loaderData.query.lot}
+							defaultValue={
+								loaderData.details?.lot || scraped?.lot || loaderData.query.lot
+							}
 							data={Object.values(MediaLot).map((v) => ({
 								value: v,
 								label: changeCase(v),
@@ -169,7 +233,9 @@ export default function Page() {
 							mt="md"
 							name="isNsfw"
 							label="Is it NSFW?"
-							defaultChecked={loaderData.details?.isNsfw || undefined}
+							defaultChecked={
+								loaderData.details?.isNsfw || scraped?.isNsfw || undefined
+							}
 						/>
 					</Group>
 					<JsonInput
@@ -180,13 +246,19 @@ export default function Page() {
 							loaderData.details?.movieSpecifics ||
 								loaderData.details?.showSpecifics ||
 								loaderData.details?.mangaSpecifics ||
+								loaderData.details?.comicSpecifics ||
+								loaderData.details?.boardGameSpecifics ||
 								loaderData.details?.animeSpecifics ||
 								loaderData.details?.podcastSpecifics ||
 								loaderData.details?.bookSpecifics ||
 								loaderData.details?.audioBookSpecifics ||
 								loaderData.details?.visualNovelSpecifics ||
 								loaderData.details?.videoGameSpecifics ||
-								loaderData.details?.musicSpecifics,
+								loaderData.details?.musicSpecifics ||
+								scraped?.bookSpecifics ||
+								scraped?.movieSpecifics ||
+								scraped?.musicSpecifics ||
+								scraped?.videoGameSpecifics,
 						)}
 						description={
 							<>
@@ -202,8 +274,22 @@ export default function Page() {
 						label="Description"
 						name="description"
 						description="Markdown is supported"
-						defaultValue={loaderData.details?.description || undefined}
+						defaultValue={
+							loaderData.details?.description ||
+							scraped?.description ||
+							undefined
+						}
 					/>
+					{scraped?.images?.length ? (
+						<>
+							{scraped.images.map((image) => (
+								<input hidden key={image} name="images" defaultValue={image} />
+							))}
+							<Text size="sm" c="dimmed">
+								{scraped.images.length} image(s) from the web page will be added
+							</Text>
+						</>
+					) : null}
 					{!fileUploadNotAllowed ? (
 						<FileInput
 							multiple
@@ -234,16 +320,22 @@ export default function Page() {
 						name="publishYear"
 						label="Publish year"
 						leftSection={<IconCalendar />}
-						defaultValue={loaderData.details?.publishYear || undefined}
+						defaultValue={
+							loaderData.details?.publishYear ||
+							scraped?.publishYear ||
+							undefined
+						}
 					/>
 					<TextInput
 						name="creators"
 						label="Creators"
 						placeholder="Comma separated names"
-						defaultValue={loaderData.details?.creators
-							.flatMap((c) => c.items)
-							.map((c) => c.name)
-							.join(", ")}
+						defaultValue={
+							loaderData.details?.creators
+								.flatMap((c) => c.items)
+								.map((c) => c.name)
+								.join(", ") || scraped?.creators?.join(", ")
+						}
 					/>
 					<TextInput
 						name="genres"
@@ -251,6 +343,7 @@ export default function Page() {
 						placeholder="Comma separated values"
 						defaultValue={
 							loaderData.details?.genres.map((g) => g.name).join(", ") ||
+							scraped?.genres?.join(", ") ||
 							undefined
 						}
 					/>
@@ -11,6 +11,7 @@ import {
 	Flex,
 	Group,
 	Indicator,
+	PasswordInput,
 	Progress,
 	Select,
 	Stack,
@@ -31,6 +32,7 @@ import { Form, useLoaderData } from "@remix-run/react";
 import {
 	DeployExportJobDocument,
 	DeployImportJobDocument,
+	ExportFormat,
 	ImportReportsDocument,
 	ImportSource,
 	UserExportsDocument,
@@ -134,10 +136,11 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 			});
 		})
 		.with("deployExport", async () => {
+			const input = processSubmission(formData, deployExportFormSchema);
 			await serverGqlService.authenticatedRequest(
 				request,
 				DeployExportJobDocument,
-				{},
+				{ input },
 			);
 			return Response.json({ status: "success" } as const, {
 				headers: await createToastHeaders({
@@ -177,7 +180,15 @@ const movaryImportFormSchema = z.object({
 	watchlist: z.string(),
 });
 
-const jsonImportFormSchema = z.object({ export: z.string() });
+const jsonImportFormSchema = z.object({
+	export: z.string(),
+	passphrase: z.string().optional(),
+});
+
+const deployExportFormSchema = z.object({
+	format: z.nativeEnum(ExportFormat),
+	passphrase: z.string().optional(),
+});
 
 const malImportFormSchema = z.object({
 	animePath: z.string().optional(),
@@ -374,10 +385,18 @@ export default function Page() {
 													<>
 														<FileInput
 															label="JSON export file"
-															accept=".json"
+															accept=".json,.enc"
 															required
 															name="export"
 														/>
+														{deployImportSource ===
+														ImportSource.GenericJson ? (
+															<PasswordInput
+																name="passphrase"
+																label="Passphrase"
+																description="Required if the export was encrypted"
+															/>
+														) : null}
 													</>
 												),
 											)
@@ -544,11 +563,24 @@ export default function Page() {
 								encType="multipart/form-data"
 								action={withQuery(".", { intent: "deployExport" })}
 							>
-								<input
-									hidden
-									name="dummy"
-									defaultValue="this is required because of the encType"
-								/>
+								<Stack gap="xs">
+									<Select
+										required
+										name="format"
+										label="Format"
+										allowDeselect={false}
+										defaultValue={ExportFormat.GenericJson}
+										data={Object.values(ExportFormat).map((f) => ({
+											label: changeCase(f),
+											value: f,
+										}))}
+									/>
+									<PasswordInput
+										name="passphrase"
+										label="Passphrase"
+										description="If set, the export will be encrypted with this passphrase"
+									/>
+								</Stack>
 								<Tooltip
 									label="Please enable file storage to use this feature"
 									disabled={!fileUploadNotAllowed}
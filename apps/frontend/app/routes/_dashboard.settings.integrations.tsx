@@ -6,6 +6,7 @@ import {
 	Box,
 	Button,
 	Checkbox,
+	Code,
 	Container,
 	CopyButton,
 	Flex,
@@ -32,6 +33,8 @@ import {
 	CreateUserIntegrationDocument,
 	DeleteUserIntegrationDocument,
 	GenerateAuthTokenDocument,
+	InitiateMalLoginDocument,
+	InitiateTraktDeviceLoginDocument,
 	IntegrationProvider,
 	MediaSource,
 	UpdateUserIntegrationDocument,
@@ -47,6 +50,7 @@ import {
 	IconPencil,
 	IconTrash,
 } from "@tabler/icons-react";
+import { useMutation } from "@tanstack/react-query";
 import { useState } from "react";
 import { match } from "ts-pattern";
 import { withQuery } from "ufo";
@@ -55,6 +59,7 @@ import { zx } from "zodix";
 import {
 	PRO_REQUIRED_MESSAGE,
 	applicationBaseUrl,
+	clientGqlService,
 	commaDelimitedString,
 	dayjsLib,
 	openConfirmationModal,
@@ -72,7 +77,11 @@ const PRO_INTEGRATIONS = [
 ];
 const YANK_INTEGRATIONS = [
 	IntegrationProvider.Komga,
+	IntegrationProvider.Steam,
+	IntegrationProvider.Trakt,
+	IntegrationProvider.Anilist,
 	IntegrationProvider.PlexYank,
+	IntegrationProvider.Myanimelist,
 	IntegrationProvider.YoutubeMusic,
 	IntegrationProvider.Audiobookshelf,
 ];
@@ -83,6 +92,7 @@ const PUSH_INTEGRATIONS = [
 ];
 const SYNC_TO_OWNED_COLLECTION_INTEGRATIONS = [
 	IntegrationProvider.Komga,
+	IntegrationProvider.Steam,
 	IntegrationProvider.PlexYank,
 	IntegrationProvider.Audiobookshelf,
 ];
@@ -205,6 +215,12 @@ const createSchema = z.object({
 			jellyfinPushUsername: z.string().optional(),
 			jellyfinPushPassword: z.string().optional(),
 			youtubeMusicAuthCookie: z.string().optional(),
+			steamApiKey: z.string().optional(),
+			steamUserId: z.string().optional(),
+			traktDeviceCode: z.string().optional(),
+			anilistToken: z.string().optional(),
+			malCode: z.string().optional(),
+			malCodeVerifier: z.string().optional(),
 		})
 		.optional(),
 });
@@ -507,7 +523,11 @@ const CreateIntegrationModal = (props: {
 									label="Select a provider"
 									name="providerSpecifics.komgaProvider"
 									required
-									data={[MediaSource.Anilist, MediaSource.Mal].map((is) => ({
+									data={[
+										MediaSource.Anilist,
+										MediaSource.Mal,
+										MediaSource.ComicVine,
+									].map((is) => ({
 										label: changeCase(is),
 										value: is,
 									}))}
@@ -528,6 +548,43 @@ const CreateIntegrationModal = (props: {
 								/>
 							</>
 						))
+						.with(IntegrationProvider.Steam, () => (
+							<>
+								<TextInput
+									required
+									label="API key"
+									name="providerSpecifics.steamApiKey"
+								/>
+								<TextInput
+									required
+									label="Steam ID"
+									name="providerSpecifics.steamUserId"
+									description="The 17 digit ID of your Steam account"
+								/>
+							</>
+						))
+						.with(IntegrationProvider.Trakt, () => <TraktLoginInputs />)
+						.with(IntegrationProvider.Anilist, () => (
+							<TextInput
+								required
+								label="Token"
+								name="providerSpecifics.anilistToken"
+								description={
+									<Text size="xs" c="dimmed">
+										Please follow the{" "}
+										<Anchor
+											target="_blank"
+											rel="noreferrer noopener"
+											href="https://docs.ryot.io/integrations#anilist"
+										>
+											docs
+										</Anchor>{" "}
+										to get a token
+									</Text>
+								}
+							/>
+						))
+						.with(IntegrationProvider.Myanimelist, () => <MalLoginInputs />)
 						.with(IntegrationProvider.YoutubeMusic, () => (
 							<>
 								<TextInput
@@ -612,6 +669,113 @@ const CreateIntegrationModal = (props: {
 	);
 };
 
+const TraktLoginInputs = () => {
+	const initiateLoginMutation = useMutation({
+		mutationFn: async () => {
+			const { initiateTraktDeviceLogin } = await clientGqlService.request(
+				InitiateMalLoginDocument,
+	InitiateTraktDeviceLoginDocument,
+			);
+			return initiateTraktDeviceLogin;
+		},
+	});
+	const deviceCode = initiateLoginMutation.data;
+
+	return (
+		<>
+			{deviceCode ? (
+				<>
+					<input
+						hidden
+						readOnly
+						name="providerSpecifics.traktDeviceCode"
+						value={deviceCode.deviceCode}
+					/>
+					<Alert>
+						Go to{" "}
+						<Anchor
+							target="_blank"
+							rel="noreferrer noopener"
+							href={deviceCode.verificationUrl}
+						>
+							{deviceCode.verificationUrl}
+						</Anchor>{" "}
+						and enter the code <Code>{deviceCode.userCode}</Code>. Create the
+						integration once you have approved it.
+					</Alert>
+				</>
+			) : null}
+			<Button
+				variant="outline"
+				loading={initiateLoginMutation.isPending}
+				onClick={() => initiateLoginMutation.mutate()}
+			>
+				{deviceCode ? "Get a new code" : "Log in to Trakt"}
+			</Button>
+			{initiateLoginMutation.isError ? (
+				<Text size="xs" c="red">
+					{initiateLoginMutation.error.message}
+				</Text>
+			) : null}
+		</>
+	);
+};
+
+const MalLoginInputs = () => {
+	const initiateLoginMutation = useMutation({
+		mutationFn: async () => {
+			const { initiateMalLogin } = await clientGqlService.request(
+				InitiateMalLoginDocument,
+			);
+			return initiateMalLogin;
+		},
+	});
+	const authorization = initiateLoginMutation.data;
+
+	return (
+		<>
+			{authorization ? (
+				<>
+					<input
+						hidden
+						readOnly
+						name="providerSpecifics.malCodeVerifier"
+						value={authorization.codeVerifier}
+					/>
+					<Alert>
+						<Anchor
+							target="_blank"
+							rel="noreferrer noopener"
+							href={authorization.url}
+						>
+							Authorize Ryot
+						</Anchor>{" "}
+						on MyAnimeList and paste the <Code>code</Code> parameter of the
+						page you are redirected to below.
+					</Alert>
+					<TextInput
+						required
+						label="Authorization code"
+						name="providerSpecifics.malCode"
+					/>
+				</>
+			) : null}
+			<Button
+				variant="outline"
+				loading={initiateLoginMutation.isPending}
+				onClick={() => initiateLoginMutation.mutate()}
+			>
+				{authorization ? "Get a new link" : "Log in to MyAnimeList"}
+			</Button>
+			{initiateLoginMutation.isError ? (
+				<Text size="xs" c="red">
+					{initiateLoginMutation.error.message}
+				</Text>
+			) : null}
+		</>
+	);
+};
+
 const ArrInputs = (props: { name: string }) => {
 	const collections = useUserCollections();
 
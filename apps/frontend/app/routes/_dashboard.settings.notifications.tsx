@@ -1,12 +1,14 @@
 import {
 	ActionIcon,
 	Anchor,
+	Badge,
 	Box,
 	Button,
 	Container,
 	Flex,
 	Group,
 	Modal,
+	MultiSelect,
 	NumberInput,
 	Paper,
 	Select,
@@ -26,14 +28,19 @@ import { Form, Link, useLoaderData } from "@remix-run/react";
 import {
 	CreateUserNotificationPlatformDocument,
 	DeleteUserNotificationPlatformDocument,
+	MediaLot,
+	NotificationDeliveryStatus,
 	NotificationPlatformLot,
 	TestUserNotificationPlatformsDocument,
 	UpdateUserNotificationPlatformDocument,
+	UserNotificationContent,
 	UserNotificationPlatformsDocument,
 	type UserNotificationPlatformsQuery,
 } from "@ryot/generated/graphql/backend/graphql";
 import { changeCase, getActionIntent, processSubmission } from "@ryot/ts-utils";
 import {
+	IconFilter,
+	IconHistory,
 	IconPlayerPause,
 	IconPlayerPlay,
 	IconTrash,
@@ -44,7 +51,11 @@ import { match } from "ts-pattern";
 import { withQuery } from "ufo";
 import { z } from "zod";
 import { zx } from "zodix";
-import { dayjsLib, openConfirmationModal } from "~/lib/generals";
+import {
+	commaDelimitedString,
+	dayjsLib,
+	openConfirmationModal,
+} from "~/lib/generals";
 import { useConfirmSubmit, useCoreDetails } from "~/lib/hooks";
 import { createToastHeaders, serverGqlService } from "~/lib/utilities.server";
 
@@ -68,7 +79,30 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 	const intent = getActionIntent(request);
 	return await match(intent)
 		.with("create", async () => {
-			const submission = processSubmission(formData, createSchema);
+			const {
+				smtpServer,
+				smtpUser,
+				smtpPassword,
+				smtpMailbox,
+				contentTypes,
+				mediaLots,
+				...input
+			} = processSubmission(formData, createSchema);
+			const smtp =
+				smtpServer && smtpUser && smtpPassword
+					? {
+							user: smtpUser,
+							server: smtpServer,
+							mailbox: smtpMailbox,
+							password: smtpPassword,
+						}
+					: undefined;
+			const submission = {
+				...input,
+				smtp,
+				contentTypes: contentTypes as Array<UserNotificationContent> | undefined,
+				mediaLots: mediaLots as Array<MediaLot> | undefined,
+			};
 			await serverGqlService.authenticatedRequest(
 				request,
 				CreateUserNotificationPlatformDocument,
@@ -121,6 +155,29 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 				}),
 			});
 		})
+		.with("updateFilters", async () => {
+			const { notificationId, contentTypes, mediaLots } = processSubmission(
+				formData,
+				updateFiltersSchema,
+			);
+			// an empty list removes the filter, so clearing a select sends everything
+			const input = {
+				notificationId,
+				contentTypes: (contentTypes || []) as Array<UserNotificationContent>,
+				mediaLots: (mediaLots || []) as Array<MediaLot>,
+			};
+			await serverGqlService.authenticatedRequest(
+				request,
+				UpdateUserNotificationPlatformDocument,
+				{ input },
+			);
+			return Response.json({ status: "success", submission: input } as const, {
+				headers: await createToastHeaders({
+					type: "success",
+					message: "Notification filters updated successfully",
+				}),
+			});
+		})
 		.run();
 };
 
@@ -133,6 +190,12 @@ const createSchema = z.object({
 	apiToken: z.string().optional(),
 	authHeader: z.string().optional(),
 	priority: z.number().optional(),
+	smtpUser: z.string().optional(),
+	smtpServer: z.string().optional(),
+	smtpMailbox: z.string().optional(),
+	smtpPassword: z.string().optional(),
+	contentTypes: commaDelimitedString,
+	mediaLots: commaDelimitedString,
 });
 
 const updateSchema = z.object({
@@ -140,6 +203,22 @@ const updateSchema = z.object({
 	isDisabled: zx.BoolAsString.optional(),
 });
 
+const updateFiltersSchema = z.object({
+	notificationId: z.string(),
+	contentTypes: commaDelimitedString,
+	mediaLots: commaDelimitedString,
+});
+
+const contentTypesData = Object.values(UserNotificationContent).map((v) => ({
+	label: changeCase(v),
+	value: v,
+}));
+
+const mediaLotsData = Object.values(MediaLot).map((v) => ({
+	label: changeCase(v),
+	value: v,
+}));
+
 export default function Page() {
 	const loaderData = useLoaderData<typeof loader>();
 	const coreDetails = useCoreDetails();
@@ -261,15 +340,54 @@ export default function Page() {
 												label="Email ID"
 												required
 												name="apiToken"
-												disabled={!coreDetails.smtpEnabled}
-												description={
-													coreDetails.smtpEnabled ? null : "SMTP is not enabled"
-												}
+											/>
+											<Text size="xs" c="dimmed">
+												{coreDetails.smtpEnabled
+													? "Leave the following empty to use the SMTP server configured for this instance"
+													: "SMTP is not enabled for this instance, please provide your own server"}
+											</Text>
+											<TextInput
+												label="SMTP Server"
+												name="smtpServer"
+												required={!coreDetails.smtpEnabled}
+											/>
+											<TextInput
+												label="SMTP User"
+												name="smtpUser"
+												required={!coreDetails.smtpEnabled}
+											/>
+											<TextInput
+												type="password"
+												label="SMTP Password"
+												name="smtpPassword"
+												required={!coreDetails.smtpEnabled}
+											/>
+											<TextInput
+												label="Mailbox"
+												name="smtpMailbox"
+												placeholder="Ryot <no-reply@mailer.io>"
 											/>
 										</>
 									))
+									.with(NotificationPlatformLot.Matrix, () => (
+										<>
+											<TextInput
+												label="Homeserver Url"
+												required
+												name="baseUrl"
+											/>
+											<TextInput label="Access Token" required name="apiToken" />
+											<TextInput label="Room ID" required name="chatId" />
+										</>
+									))
+									.with(NotificationPlatformLot.Slack, () => (
+										<>
+											<TextInput label="Webhook Url" required name="baseUrl" />
+										</>
+									))
 									.exhaustive()
 							: null}
+						<NotificationFilterInputs />
 						<Button type="submit">Submit</Button>
 					</Stack>
 				</Box>
@@ -327,13 +445,98 @@ export default function Page() {
 	);
 }
 
+const deliveryStatusColors = {
+	[NotificationDeliveryStatus.Delivered]: "green",
+	[NotificationDeliveryStatus.Failed]: "yellow",
+	[NotificationDeliveryStatus.Abandoned]: "red",
+};
+
 const DisplayNotification = (props: {
 	notification: UserNotificationPlatformsQuery["userNotificationPlatforms"][number];
 }) => {
 	const submit = useConfirmSubmit();
+	const [
+		filtersModalOpened,
+		{ open: openFiltersModal, close: closeFiltersModal },
+	] = useDisclosure(false);
+	const [
+		deliveriesModalOpened,
+		{ open: openDeliveriesModal, close: closeDeliveriesModal },
+	] = useDisclosure(false);
 
 	return (
 		<Paper p="xs" withBorder>
+			<Modal
+				centered
+				opened={filtersModalOpened}
+				onClose={closeFiltersModal}
+				withCloseButton={false}
+			>
+				<Form
+					replace
+					method="POST"
+					onSubmit={closeFiltersModal}
+					action={withQuery(".", { intent: "updateFilters" })}
+				>
+					<input
+						hidden
+						name="notificationId"
+						defaultValue={props.notification.id}
+					/>
+					<Stack>
+						<NotificationFilterInputs
+							contentTypes={props.notification.contentTypes}
+							mediaLots={props.notification.mediaLots}
+						/>
+						<Button type="submit">Update</Button>
+					</Stack>
+				</Form>
+			</Modal>
+			<Modal
+				centered
+				size="lg"
+				title="Recent deliveries"
+				opened={deliveriesModalOpened}
+				onClose={closeDeliveriesModal}
+			>
+				<Stack>
+					{props.notification.deliveries.length > 0 ? (
+						props.notification.deliveries.map((delivery) => (
+							<Paper key={delivery.createdOn} p="xs" withBorder>
+								<Group justify="space-between" wrap="nowrap">
+									<Text size="sm" lineClamp={2}>
+										{delivery.message}
+									</Text>
+									<Badge
+										size="sm"
+										variant="light"
+										color={deliveryStatusColors[delivery.status]}
+									>
+										{changeCase(delivery.status)}
+									</Badge>
+								</Group>
+								<Text size="xs" c="dimmed">
+									Attempts: {delivery.attempts}, last attempted{" "}
+									{dayjsLib(delivery.lastAttemptedOn).fromNow()}
+									{delivery.nextAttemptOn ? (
+										<>
+											, next attempt{" "}
+											{dayjsLib(delivery.nextAttemptOn).fromNow()}
+										</>
+									) : null}
+								</Text>
+								{delivery.error ? (
+									<Text size="xs" c="red">
+										{delivery.error}
+									</Text>
+								) : null}
+							</Paper>
+						))
+					) : (
+						<Text>No notifications have been sent to this platform yet</Text>
+					)}
+				</Stack>
+			</Modal>
 			<Flex align="center" justify="space-between">
 				<Box w="80%">
 					<Text size="sm" truncate>
@@ -345,8 +548,38 @@ const DisplayNotification = (props: {
 					<Text size="xs">
 						Created: {dayjsLib(props.notification.createdOn).fromNow()}
 					</Text>
+					{props.notification.consecutiveFailures ? (
+						<Text size="xs" c="red">
+							The last {props.notification.consecutiveFailures} deliveries
+							failed
+						</Text>
+					) : null}
+					{props.notification.contentTypes ? (
+						<Text size="xs" c="dimmed" truncate>
+							Only: {props.notification.contentTypes.map(changeCase).join(", ")}
+						</Text>
+					) : null}
+					{props.notification.mediaLots ? (
+						<Text size="xs" c="dimmed" truncate>
+							Media: {props.notification.mediaLots.map(changeCase).join(", ")}
+						</Text>
+					) : null}
 				</Box>
 				<Group>
+					<Tooltip label="Recent deliveries">
+						<ActionIcon
+							color="gray"
+							variant="subtle"
+							onClick={openDeliveriesModal}
+						>
+							<IconHistory />
+						</ActionIcon>
+					</Tooltip>
+					<Tooltip label="Filters">
+						<ActionIcon color="blue" variant="subtle" onClick={openFiltersModal}>
+							<IconFilter />
+						</ActionIcon>
+					</Tooltip>
 					<Form method="POST" action={withQuery(".", { intent: "update" })}>
 						<ActionIcon color="indigo" variant="subtle" type="submit">
 							{props.notification.isDisabled ? (
@@ -396,3 +629,29 @@ const DisplayNotification = (props: {
 		</Paper>
 	);
 };
+
+const NotificationFilterInputs = (props: {
+	contentTypes?: Array<UserNotificationContent> | null;
+	mediaLots?: Array<MediaLot> | null;
+}) => (
+	<>
+		<MultiSelect
+			searchable
+			clearable
+			name="contentTypes"
+			label="Notification types"
+			description="Leave empty to send notifications of all types"
+			data={contentTypesData}
+			defaultValue={props.contentTypes || undefined}
+		/>
+		<MultiSelect
+			searchable
+			clearable
+			name="mediaLots"
+			label="Media types"
+			description="Leave empty to send notifications about media of all types"
+			data={mediaLotsData}
+			defaultValue={props.mediaLots || undefined}
+		/>
+	</>
+);
@@ -20,6 +20,7 @@ import {
 	Tabs,
 	TagsInput,
 	Text,
+	TextInput,
 	Title,
 	rem,
 } from "@mantine/core";
@@ -30,8 +31,10 @@ import {
 	DashboardElementLot,
 	GridPacking,
 	MediaLot,
+	ShowEpisodeOrdering,
 	UpdateUserPreferenceDocument,
 	UserNotificationContent,
+	UserNotificationDigest,
 	type UserPreferences,
 	UserReviewScale,
 	UserUnitSystem,
@@ -337,6 +340,48 @@ export default function Page() {
 										}
 									}}
 								/>
+								<Select
+									size="xs"
+									disabled={!!isEditDisabled}
+									label="Episode ordering used for TVDB shows"
+									defaultValue={userPreferences.general.showEpisodeOrdering}
+									data={Object.values(ShowEpisodeOrdering).map((c) => ({
+										value: c,
+										label: startCase(snakeCase(c)),
+									}))}
+									onChange={(val) => {
+										if (val) {
+											updatePreference((draft) => {
+												draft.general.showEpisodeOrdering =
+													val as ShowEpisodeOrdering;
+											});
+										}
+									}}
+								/>
+								<TextInput
+									size="xs"
+									disabled={!!isEditDisabled}
+									label="Language of titles and descriptions"
+									description="Two letter code, eg: de. Applied to media when it is next updated."
+									defaultValue={userPreferences.general.language || undefined}
+									onChange={(ev) => {
+										updatePreference((draft) => {
+											draft.general.language = ev.currentTarget.value || null;
+										});
+									}}
+								/>
+								<TextInput
+									size="xs"
+									disabled={!!isEditDisabled}
+									label="Region for watch providers"
+									description="Two letter country code, eg: DE"
+									defaultValue={userPreferences.general.region || undefined}
+									onChange={(ev) => {
+										updatePreference((draft) => {
+											draft.general.region = ev.currentTarget.value || null;
+										});
+									}}
+								/>
 							</SimpleGrid>
 							<Input.Wrapper
 								label="Grid packing"
@@ -415,6 +460,78 @@ export default function Page() {
 									});
 								}}
 							/>
+							<SimpleGrid cols={{ base: 1, md: 3 }}>
+								<Select
+									size="xs"
+									label="Delivery"
+									allowDeselect={false}
+									disabled={!!isEditDisabled}
+									defaultValue={userPreferences.notifications.digest}
+									data={Object.values(UserNotificationDigest).map((c) => ({
+										value: c,
+										label: startCase(snakeCase(c)),
+									}))}
+									onChange={(val) => {
+										if (val) {
+											updatePreference((draft) => {
+												draft.notifications.digest =
+													val as UserNotificationDigest;
+											});
+										}
+									}}
+								/>
+								<NumberInput
+									size="xs"
+									min={0}
+									max={23}
+									label="Digest hour"
+									description="In the timezone of the server, not your own"
+									defaultValue={userPreferences.notifications.digestHour}
+									disabled={
+										!!isEditDisabled ||
+										changingUserPreferences.value.notifications.digest ===
+											UserNotificationDigest.Immediate
+									}
+									onChange={(val) => {
+										if (isNumber(val)) {
+											updatePreference((draft) => {
+												draft.notifications.digestHour = val;
+											});
+										}
+									}}
+								/>
+								<Select
+									size="xs"
+									label="Digest day"
+									allowDeselect={false}
+									description="For weekly digests, in the timezone of the server"
+									defaultValue={userPreferences.notifications.digestWeekday.toString()}
+									disabled={
+										!!isEditDisabled ||
+										changingUserPreferences.value.notifications.digest !==
+											UserNotificationDigest.Weekly
+									}
+									data={[
+										"Monday",
+										"Tuesday",
+										"Wednesday",
+										"Thursday",
+										"Friday",
+										"Saturday",
+										"Sunday",
+									].map((label, idx) => ({
+										label,
+										value: (idx + 1).toString(),
+									}))}
+									onChange={(val) => {
+										if (val) {
+											updatePreference((draft) => {
+												draft.notifications.digestWeekday = Number(val);
+											});
+										}
+									}}
+								/>
+							</SimpleGrid>
 							<Divider />
 							<Text>
 								The notifications you want to receive in your configured
@@ -495,6 +612,10 @@ export default function Page() {
 												UserNotificationContent.NewWorkoutCreated,
 												() => "A new workout is created",
 											)
+											.with(
+												UserNotificationContent.EntityReminderDue,
+												() => "A reminder you set is due",
+											)
 											.exhaustive()}
 									/>
 								))}
@@ -1269,6 +1269,28 @@ const MetadataNewProgressUpdateForm = ({
 						/>
 					</>
 				) : null}
+				{metadataDetails.lot === MediaLot.BoardGame ? (
+					<>
+						<Group wrap="nowrap">
+							<NumberInput
+								min={1}
+								hideControls
+								label="Players"
+								name="boardGamePlayerCount"
+							/>
+							<NumberInput
+								min={0}
+								hideControls
+								label="Duration (minutes)"
+								name="boardGameDuration"
+								defaultValue={
+									metadataDetails.boardGameSpecifics?.playTime ?? undefined
+								}
+							/>
+						</Group>
+						<TextInput label="Winner" name="boardGameWinner" />
+					</>
+				) : null}
 				{metadataDetails.lot === MediaLot.Podcast ? (
 					<>
 						<Text fw="bold">Select episode</Text>
@@ -1304,6 +1326,7 @@ const MetadataNewProgressUpdateForm = ({
 							MediaLot.Podcast,
 							MediaLot.Anime,
 							MediaLot.Manga,
+							MediaLot.BoardGame,
 						].includes(metadataDetails.lot)
 							? v !== WatchTimes.JustStartedIt
 							: true,
@@ -38,7 +38,7 @@ import invariant from "tiny-invariant";
 import { match } from "ts-pattern";
 import { z } from "zod";
 import { zx } from "zodix";
-import { redirectToQueryParam } from "~/lib/generals";
+import { emptyNumberString, redirectToQueryParam } from "~/lib/generals";
 import {
 	MetadataIdSchema,
 	MetadataSpecificsSchema,
@@ -111,6 +111,7 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 							isTmdbCompany: submission.isTmdbCompany,
 							isAnilistStudio: submission.isAnilistStudio,
 							isHardcoverPublisher: submission.isHardcoverPublisher,
+							isMusicBrainzLabel: submission.isMusicBrainzLabel,
 						},
 					},
 				},
@@ -269,6 +270,9 @@ export const action = async ({ request }: ActionFunctionArgs) => {
 				mangaChapterNumber: submission.mangaChapterNumber,
 				mangaVolumeNumber: submission.mangaVolumeNumber,
 				providerWatchedOn: submission.providerWatchedOn,
+				boardGamePlayerCount: submission.boardGamePlayerCount,
+				boardGameDuration: submission.boardGameDuration,
+				boardGameWinner: submission.boardGameWinner,
 			};
 			const updates = [];
 			const showSpecifics = metadataDetails.showSpecifics?.seasons || [];
@@ -511,6 +515,7 @@ const commitPersonSchema = z.object({
 	isTmdbCompany: zx.BoolAsString.optional(),
 	isAnilistStudio: zx.BoolAsString.optional(),
 	isHardcoverPublisher: zx.BoolAsString.optional(),
+	isMusicBrainzLabel: zx.BoolAsString.optional(),
 });
 
 const reviewCommentSchema = z.object({
@@ -561,6 +566,12 @@ const progressUpdateSchema = z
 		animeAllEpisodesBefore: zx.CheckboxAsString.optional(),
 		mangaAllChaptersOrVolumesBefore: zx.CheckboxAsString.optional(),
 		providerWatchedOn: z.string().optional(),
+		boardGamePlayerCount: emptyNumberString,
+		boardGameDuration: emptyNumberString,
+		boardGameWinner: z
+			.string()
+			.optional()
+			.transform((v) => v || undefined),
 	})
 	.merge(MetadataIdSchema)
 	.merge(MetadataSpecificsSchema);
@@ -3,8 +3,12 @@ use std::path::PathBuf;
 use anyhow::Result;
 use async_graphql::SimpleObject;
 use common_utils::PROJECT_NAME;
+use enum_models::{MediaLot, MediaSource};
 use env_utils::{DEFAULT_MAL_CLIENT_ID, DEFAULT_TMDB_ACCESS_TOKEN};
-use schematic::{derive_enum, validate::not_empty, Config, ConfigEnum, ConfigLoader, HandlerError};
+use schematic::{
+    derive_enum, validate::not_empty, Config, ConfigEnum, ConfigLoader, HandlerError,
+    ParseEnvResult, Schematic,
+};
 use serde::{Deserialize, Serialize};
 
 fn default_tmdb_access_token(_ctx: &()) -> Result<Option<String>, HandlerError> {
@@ -131,6 +135,37 @@ pub struct BookConfig {
     pub google_books: GoogleBooksConfig,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "BOARD_GAMES_BOARD_GAME_GEEK_")]
+pub struct BoardGameGeekConfig {
+    /// The application token for the BoardGameGeek XML API.
+    /// [More information](/docs/guides/board-games.md).
+    pub api_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case")]
+pub struct BoardGameConfig {
+    /// Settings related to BoardGameGeek.
+    #[setting(nested)]
+    pub board_game_geek: BoardGameGeekConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "COMICS_COMIC_VINE_")]
+pub struct ComicVineConfig {
+    /// The API key for the Comic Vine API. [More information](/docs/guides/comics.md).
+    pub api_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case")]
+pub struct ComicConfig {
+    /// Settings related to Comic Vine.
+    #[setting(nested)]
+    pub comic_vine: ComicVineConfig,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
 #[config(rename_all = "snake_case", env_prefix = "DATABASE_")]
 pub struct DatabaseConfig {
@@ -143,12 +178,86 @@ pub struct DatabaseConfig {
 #[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
 pub struct ExerciseConfig {}
 
+/// An external service that exposes metadata over HTTP using the remote
+/// provider protocol.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Schematic)]
+#[serde(rename_all = "snake_case")]
+pub struct RemoteProviderConfig {
+    /// A unique name for the provider. It is used to namespace people identifiers.
+    pub name: String,
+    /// The base URL of the provider, for example `https://provider.example.com/api`.
+    pub url: String,
+    /// The media types this provider will be used for.
+    pub lots: Vec<MediaLot>,
+    /// An optional token sent as a bearer token with every request.
+    pub api_token: Option<String>,
+}
+
+fn parse_remote_providers(var: String) -> ParseEnvResult<Vec<RemoteProviderConfig>> {
+    serde_json::from_str(&var)
+        .map(Some)
+        .map_err(|e| HandlerError(e.to_string()))
+}
+
+/// Overrides for the requests made to a specific provider. Unset values fall
+/// back to the built-in limits of the source and the global settings.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Schematic)]
+#[serde(rename_all = "snake_case")]
+pub struct ProviderHttpOverrideConfig {
+    /// The source these settings apply to.
+    pub source: MediaSource,
+    /// The number of requests per second allowed on average.
+    pub requests_per_second: Option<f64>,
+    /// The number of requests that can be made in a burst.
+    pub burst: Option<u32>,
+    /// The maximum number of times a failed request will be retried.
+    pub max_retries: Option<u32>,
+    /// The number of seconds search responses will be cached for.
+    pub cache_ttl_seconds: Option<u64>,
+}
+
+fn parse_provider_http_overrides(var: String) -> ParseEnvResult<Vec<ProviderHttpOverrideConfig>> {
+    serde_json::from_str(&var)
+        .map(Some)
+        .map_err(|e| HandlerError(e.to_string()))
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MEDIA_PROVIDER_HTTP_")]
+pub struct ProviderHttpConfig {
+    /// The maximum number of times a request to a provider will be retried when it is
+    /// rate limited or fails with a server error.
+    #[setting(default = 3)]
+    pub max_retries: u32,
+    /// The number of seconds successful search responses from providers will be cached
+    /// on disk. Details are never cached. Set to `0` to disable caching.
+    #[setting(default = 3600)]
+    pub cache_ttl_seconds: u64,
+    /// Settings for specific sources. When set via the environment, this must be a
+    /// JSON array.
+    #[setting(default = vec![], parse_env = parse_provider_http_overrides)]
+    pub overrides: Vec<ProviderHttpOverrideConfig>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "MEDIA_")]
 pub struct MediaConfig {
     /// Number of days after which a media should be removed from the Monitoring collection.
     #[setting(default = 30)]
     pub monitoring_remove_after_days: i64,
+    /// External metadata providers that implement the remote provider protocol.
+    /// When set via the environment, this must be a JSON array.
+    #[setting(default = vec![], parse_env = parse_remote_providers)]
+    pub remote_providers: Vec<RemoteProviderConfig>,
+    /// Settings related to the requests made to providers.
+    #[setting(nested)]
+    pub provider_http: ProviderHttpConfig,
+}
+
+impl MediaConfig {
+    pub fn remote_provider_for_lot(&self, lot: MediaLot) -> Option<&RemoteProviderConfig> {
+        self.remote_providers.iter().find(|p| p.lots.contains(&lot))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -162,12 +271,27 @@ pub struct TmdbConfig {
     pub locale: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MOVIES_AND_SHOWS_TVDB_")]
+pub struct TvdbConfig {
+    /// The API key for the TVDB API. [More information](/docs/guides/shows.md).
+    pub api_key: String,
+    /// The subscriber PIN, required only for user-supported API keys.
+    pub pin: String,
+    /// The three letter language code to use for making requests to TVDB API.
+    #[setting(default = "eng")]
+    pub locale: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case")]
 pub struct MovieAndShowConfig {
     /// Settings related to TMDB.
     #[setting(nested)]
     pub tmdb: TmdbConfig,
+    /// Settings related to TVDB.
+    #[setting(nested)]
+    pub tvdb: TvdbConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -185,12 +309,25 @@ pub struct ITunesConfig {
     pub locale: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "PODCASTS_PODCAST_INDEX_")]
+pub struct PodcastIndexConfig {
+    /// The API key for the Podcast Index API.
+    /// [More information](/docs/guides/podcasts.md).
+    pub api_key: String,
+    /// The API secret for the Podcast Index API.
+    pub api_secret: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case")]
 pub struct PodcastConfig {
     /// Settings related to Listennotes.
     #[setting(nested)]
     pub listennotes: ListenNotesConfig,
+    /// Settings related to Podcast Index.
+    #[setting(nested)]
+    pub podcast_index: PodcastIndexConfig,
     /// Settings related to iTunes.
     #[setting(nested)]
     pub itunes: ITunesConfig,
@@ -223,24 +360,39 @@ pub struct IgdbConfig {
     pub image_size: IgdbImageSize,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "VIDEO_GAMES_RAWG_")]
+pub struct RawgConfig {
+    /// The API key for RAWG. Used for video games tracking when Twitch
+    /// credentials are not provided. [More information](/docs/guides/video-games.md).
+    pub api_key: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case")]
 pub struct VideoGameConfig {
     /// Settings related to IGDB.
     #[setting(nested)]
     pub igdb: IgdbConfig,
+    /// Settings related to RAWG.
+    #[setting(nested)]
+    pub rawg: RawgConfig,
     /// Settings related to Twitch.
     #[setting(nested)]
     pub twitch: TwitchConfig,
 }
 
 impl VideoGameConfig {
+    pub fn is_igdb_enabled(&self) -> bool {
+        !self.twitch.client_id.is_empty() && !self.twitch.client_secret.is_empty()
+    }
+
+    pub fn is_rawg_enabled(&self) -> bool {
+        !self.rawg.api_key.is_empty()
+    }
+
     pub fn is_enabled(&self) -> bool {
-        let mut enabled = false;
-        if !self.twitch.client_id.is_empty() && !self.twitch.client_secret.is_empty() {
-            enabled = true;
-        }
-        enabled
+        self.is_igdb_enabled() || self.is_rawg_enabled()
     }
 }
 
@@ -306,12 +458,50 @@ pub struct FrontendConfig {
     pub umami: FrontendUmamiConfig,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "INTEGRATION_TRAKT_")]
+pub struct IntegrationTraktConfig {
+    /// The client ID of the Trakt application. **Required** to enable the Trakt
+    /// integration. [More information](/docs/integrations.md#trakt).
+    pub client_id: String,
+    /// The client secret of the Trakt application.
+    pub client_secret: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "INTEGRATION_MAL_")]
+pub struct IntegrationMalConfig {
+    /// The client ID of the MyAnimeList application. **Required** to enable the
+    /// MyAnimeList integration. [More information](/docs/integrations.md#myanimelist).
+    pub client_id: String,
+    /// The client secret of the MyAnimeList application.
+    pub client_secret: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "INTEGRATION_")]
 pub struct IntegrationConfig {
     /// Sync data from push and yank based integrations every `n` minutes.
     #[setting(default = 5)]
     pub sync_every_minutes: i32,
+    /// Settings related to the Trakt integration.
+    #[setting(nested)]
+    pub trakt: IntegrationTraktConfig,
+    /// Settings related to the MyAnimeList integration.
+    #[setting(nested)]
+    pub mal: IntegrationMalConfig,
+}
+
+impl IntegrationTraktConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.client_id.is_empty() && !self.client_secret.is_empty()
+    }
+}
+
+impl IntegrationMalConfig {
+    pub fn is_enabled(&self) -> bool {
+        !self.client_id.is_empty() && !self.client_secret.is_empty()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -410,9 +600,15 @@ pub struct AppConfig {
     /// Settings related to audio books.
     #[setting(nested)]
     pub audio_books: AudioBookConfig,
+    /// Settings related to board games.
+    #[setting(nested)]
+    pub board_games: BoardGameConfig,
     /// Settings related to books.
     #[setting(nested)]
     pub books: BookConfig,
+    /// Settings related to comics.
+    #[setting(nested)]
+    pub comics: ComicConfig,
     /// The database related settings.
     #[setting(nested)]
     pub database: DatabaseConfig,
@@ -461,16 +657,27 @@ impl AppConfig {
         let gt = || "****".to_owned();
         let mut cl = self.clone();
         cl.anime_and_manga.mal.client_id = gt();
+        cl.board_games.board_game_geek.api_token = gt();
         cl.books.hardcover.api_key = gt();
         cl.books.google_books.api_key = gt();
+        cl.comics.comic_vine.api_key = gt();
         cl.database.url = gt();
         cl.file_storage.s3_region = gt();
         cl.file_storage.s3_bucket_name = gt();
         cl.file_storage.s3_access_key_id = gt();
         cl.file_storage.s3_secret_access_key = gt();
         cl.file_storage.s3_url = gt();
+        cl.integration.trakt.client_id = gt();
+        cl.integration.trakt.client_secret = gt();
+        cl.integration.mal.client_id = gt();
+        cl.integration.mal.client_secret = gt();
         cl.movies_and_shows.tmdb.access_token = gt();
+        cl.movies_and_shows.tvdb.api_key = gt();
+        cl.movies_and_shows.tvdb.pin = gt();
         cl.podcasts.listennotes.api_token = gt();
+        cl.podcasts.podcast_index.api_key = gt();
+        cl.podcasts.podcast_index.api_secret = gt();
+        cl.video_games.rawg.api_key = gt();
         cl.video_games.twitch.client_id = gt();
         cl.video_games.twitch.client_secret = gt();
         cl.users.jwt_secret = gt();
@@ -484,6 +691,9 @@ impl AppConfig {
         cl.server.oidc.issuer_url = gt();
         cl.server.pro_key = gt();
         cl.server.admin_access_token = gt();
+        for provider in cl.media.remote_providers.iter_mut() {
+            provider.api_token = provider.api_token.as_ref().map(|_| gt());
+        }
         cl
     }
 }
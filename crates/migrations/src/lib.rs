@@ -65,6 +65,15 @@ mod m20250113_changes_for_issue_1169;
 mod m20250115_changes_for_issue_1110;
 mod m20250116_changes_for_issue_1161;
 mod m20250117_is_last_v7_migration;
+mod m20250118_add_payload_to_user_notification;
+mod m20250119_add_digest_to_notification_preferences;
+mod m20250120_add_routing_to_notification_platform;
+mod m20250121_create_notification_delivery;
+mod m20250122_add_episode_ordering_to_general_preferences;
+mod m20250123_add_comic_media_lot;
+mod m20250124_add_board_game_media_lot;
+mod m20250125_add_localized_details_to_metadata;
+mod m20250126_add_rendered_message_to_notification_delivery;
 
 pub use m20230410_create_metadata::Metadata as AliasedMetadata;
 pub use m20230413_create_person::Person as AliasedPerson;
@@ -151,6 +160,15 @@ impl MigratorTrait for Migrator {
             Box::new(m20250115_changes_for_issue_1110::Migration),
             Box::new(m20250116_changes_for_issue_1161::Migration),
             Box::new(m20250117_is_last_v7_migration::Migration),
+            Box::new(m20250118_add_payload_to_user_notification::Migration),
+            Box::new(m20250119_add_digest_to_notification_preferences::Migration),
+            Box::new(m20250120_add_routing_to_notification_platform::Migration),
+            Box::new(m20250121_create_notification_delivery::Migration),
+            Box::new(m20250122_add_episode_ordering_to_general_preferences::Migration),
+            Box::new(m20250123_add_comic_media_lot::Migration),
+            Box::new(m20250124_add_board_game_media_lot::Migration),
+            Box::new(m20250125_add_localized_details_to_metadata::Migration),
+            Box::new(m20250126_add_rendered_message_to_notification_delivery::Migration),
         ]
     }
 }
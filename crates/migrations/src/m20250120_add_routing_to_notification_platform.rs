@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        if !manager
+            .has_column("notification_platform", "content_types")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "notification_platform" ADD COLUMN "content_types" TEXT[]"#,
+            )
+            .await?;
+        }
+        if !manager
+            .has_column("notification_platform", "media_lots")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "notification_platform" ADD COLUMN "media_lots" TEXT[]"#,
+            )
+            .await?;
+        }
+        db.execute_unprepared(
+            r#"
+UPDATE
+  "user"
+SET
+  preferences = JSONB_SET(
+    preferences,
+    '{notifications,to_send}',
+    (preferences -> 'notifications' -> 'to_send') || '"EntityReminderDue"'
+  )
+where
+  NOT (
+    preferences -> 'notifications' -> 'to_send' ? 'EntityReminderDue'
+  );
+            "#,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use sea_orm_migration::prelude::*;
+
+use super::{
+    m20240712_create_notification_platform::NotificationPlatform,
+    m20241214_create_user_notification::UserNotification,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum NotificationDelivery {
+    Id,
+    Table,
+    Error,
+    Status,
+    Message,
+    Attempts,
+    CreatedOn,
+    NextAttemptOn,
+    LastAttemptedOn,
+    UserNotificationId,
+    NotificationPlatformId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        if !manager
+            .has_column("notification_platform", "consecutive_failures")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "notification_platform" ADD COLUMN "consecutive_failures" INTEGER"#,
+            )
+            .await?;
+        }
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationDelivery::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationDelivery::Id)
+                            .uuid()
+                            .not_null()
+                            .default(PgFunc::gen_random_uuid())
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::Message)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::Status)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::Attempts)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NotificationDelivery::Error).text())
+                    .col(
+                        ColumnDef::new(NotificationDelivery::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::LastAttemptedOn)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::NextAttemptOn)
+                            .timestamp_with_time_zone(),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationDelivery::NotificationPlatformId)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NotificationDelivery::UserNotificationId).uuid())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("notification_delivery_to_notification_platform_foreign_key")
+                            .from(
+                                NotificationDelivery::Table,
+                                NotificationDelivery::NotificationPlatformId,
+                            )
+                            .to(NotificationPlatform::Table, NotificationPlatform::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("notification_delivery_to_user_notification_foreign_key")
+                            .from(
+                                NotificationDelivery::Table,
+                                NotificationDelivery::UserNotificationId,
+                            )
+                            .to(UserNotification::Table, UserNotification::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("notification_delivery__notification_platform_id__index")
+                    .table(NotificationDelivery::Table)
+                    .col(NotificationDelivery::NotificationPlatformId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        if !manager.has_column("metadata", "comic_specifics").await? {
+            db.execute_unprepared(r#"ALTER TABLE "metadata" ADD COLUMN "comic_specifics" JSONB"#)
+                .await?;
+        }
+        db.execute_unprepared(
+            r#"
+ALTER TABLE "daily_user_activity" ADD COLUMN IF NOT EXISTS "comic_count" INTEGER NOT NULL DEFAULT 0;
+
+UPDATE "user"
+SET "preferences" = jsonb_set(
+    "preferences",
+    '{features_enabled,media,specific}',
+    ("preferences" -> 'features_enabled' -> 'media' -> 'specific') || '["comic"]'::jsonb
+)
+WHERE NOT ("preferences" -> 'features_enabled' -> 'media' -> 'specific') ? 'comic';
+"#,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
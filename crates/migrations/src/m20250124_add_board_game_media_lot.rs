@@ -0,0 +1,49 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        if !manager
+            .has_column("metadata", "board_game_specifics")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "metadata" ADD COLUMN "board_game_specifics" JSONB"#,
+            )
+            .await?;
+        }
+        if !manager
+            .has_column("seen", "board_game_extra_information")
+            .await?
+        {
+            db.execute_unprepared(
+                r#"ALTER TABLE "seen" ADD COLUMN "board_game_extra_information" JSONB"#,
+            )
+            .await?;
+        }
+        db.execute_unprepared(
+            r#"
+ALTER TABLE "daily_user_activity" ADD COLUMN IF NOT EXISTS "board_game_count" INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE "daily_user_activity" ADD COLUMN IF NOT EXISTS "board_game_duration" INTEGER NOT NULL DEFAULT 0;
+
+UPDATE "user"
+SET "preferences" = jsonb_set(
+    "preferences",
+    '{features_enabled,media,specific}',
+    ("preferences" -> 'features_enabled' -> 'media' -> 'specific') || '["board_game"]'::jsonb
+)
+WHERE NOT ("preferences" -> 'features_enabled' -> 'media' -> 'specific') ? 'board_game';
+"#,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use chrono::DateTime;
 use chrono_tz::Tz;
 use database_models::seen;
-use media_models::{DeployImportJobInput, ProgressUpdateInput, ReviewPostedEvent};
+use media_models::{
+    DeployExportJobInput, DeployImportJobInput, ProgressUpdateInput, ReviewPostedEvent,
+};
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use uuid::Uuid;
@@ -19,7 +21,8 @@ pub enum MpApplicationJob {
     UpdatePerson(String),
     SyncIntegrationsData,
     UpdateExerciseLibrary,
-    PerformExport(String),
+    PerformExport(String, DeployExportJobInput),
+    PerformInstanceBackup(String, String),
     UpdateGithubExercises,
     UpdateMetadata(String),
     PerformBackgroundTasks,
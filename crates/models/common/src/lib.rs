@@ -5,7 +5,10 @@ use enum_meta::{meta, Meta};
 use enum_models::{EntityLot, MediaLot, MediaSource};
 use rust_decimal::Decimal;
 use schematic::{ConfigEnum, Schematic};
-use sea_orm::{prelude::DateTimeUtc, FromJsonQueryResult};
+use sea_orm::{
+    prelude::{DateTimeUtc, StringLen},
+    DeriveActiveEnum, FromJsonQueryResult,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::{Display, EnumIter};
@@ -186,9 +189,27 @@ pub struct ChangeCollectionToEntityInput {
     pub information: Option<serde_json::Value>,
 }
 
-#[derive(Enum, Eq, PartialEq, Copy, Clone, Debug, Serialize, Deserialize, Display, EnumIter)]
+#[derive(
+    Enum,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    Display,
+    EnumIter,
+    DeriveActiveEnum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "PascalCase"
+)]
 pub enum UserNotificationContent {
     ReviewPosted,
+    EntityReminderDue,
     MetadataPublished,
     NewWorkoutCreated,
     MetadataStatusChanged,
@@ -202,6 +223,22 @@ pub enum UserNotificationContent {
     MetadataChaptersOrEpisodesChanged,
 }
 
+/// Structured information attached to a notification so that it can be rendered richly
+/// on the platforms that support it.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, FromJsonQueryResult)]
+pub struct UserNotificationPayload {
+    /// The title of the entity that this notification is about.
+    pub title: Option<String>,
+    /// The URL of an image of the entity, usually the poster.
+    pub image: Option<String>,
+    /// The URL of the page of the entity in the frontend.
+    pub action_url: Option<String>,
+    pub content: Option<UserNotificationContent>,
+    /// The lot of the media that this notification is about, if any.
+    pub media_lot: Option<MediaLot>,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct ExportJob {
     pub size: i64,
@@ -289,6 +326,7 @@ pub struct PersonSourceSpecifics {
     pub is_tmdb_company: Option<bool>,
     pub is_anilist_studio: Option<bool>,
     pub is_hardcover_publisher: Option<bool>,
+    pub is_music_brainz_label: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -345,6 +383,13 @@ pub struct YoutubeMusicSongListened {
     pub listened_on: NaiveDate,
 }
 
+#[skip_serializing_none]
+#[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SteamGameMapping {
+    pub app_id: i64,
+    pub source: MediaSource,
+}
+
 #[skip_serializing_none]
 #[derive(
     Clone, Hash, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Display,
@@ -353,7 +398,9 @@ pub enum ApplicationCacheKey {
     CoreDetails,
     IgdbSettings,
     TmdbSettings,
+    TvdbSettings,
     ListennotesSettings,
+    SteamGameMapping(SteamGameMapping),
     UserCollectionsList(UserLevelCacheKey<()>),
     UserAnalyticsParameters(UserLevelCacheKey<()>),
     UserMetadataRecommendations(UserLevelCacheKey<()>),
@@ -29,6 +29,9 @@ pub struct Model {
     pub podcast_count: i32,
     pub podcast_duration: i32,
     pub manga_count: i32,
+    pub comic_count: i32,
+    pub board_game_count: i32,
+    pub board_game_duration: i32,
     pub movie_count: i32,
     pub movie_duration: i32,
     pub music_count: i32,
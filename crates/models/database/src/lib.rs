@@ -21,6 +21,7 @@ pub mod metadata_to_metadata;
 pub mod metadata_to_metadata_group;
 pub mod metadata_to_person;
 pub mod monitored_entity;
+pub mod notification_delivery;
 pub mod notification_platform;
 pub mod person;
 pub mod review;
@@ -4,9 +4,10 @@ use async_trait::async_trait;
 use chrono::NaiveDate;
 use enum_models::{MediaLot, MediaSource};
 use media_models::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, MangaSpecifics, MetadataExternalIdentifiers,
-    MetadataFreeCreator, MetadataImage, MetadataVideo, MovieSpecifics, MusicSpecifics,
-    PodcastSpecifics, ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics, WatchProvider,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicSpecifics,
+    MangaSpecifics, MetadataExternalIdentifiers, MetadataFreeCreator, MetadataImage,
+    MetadataLocalizedDetails, MetadataVideo, MovieSpecifics, MusicSpecifics, PodcastSpecifics,
+    ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics, WatchProvider,
 };
 use nanoid::nanoid;
 use rust_decimal::Decimal;
@@ -43,6 +44,8 @@ pub struct Model {
     pub show_specifics: Option<ShowSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
     pub movie_specifics: Option<MovieSpecifics>,
     pub podcast_specifics: Option<PodcastSpecifics>,
@@ -55,6 +58,8 @@ pub struct Model {
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
     #[sea_orm(column_type = "Json")]
     pub external_identifiers: Option<MetadataExternalIdentifiers>,
+    #[sea_orm(column_type = "Json")]
+    pub localized_details: Option<Vec<MetadataLocalizedDetails>>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
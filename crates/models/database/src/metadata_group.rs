@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
     "MetadataGroupWithoutId",
     "#[derive(Clone, Default, Debug, Serialize, Deserialize, Hash)]"
 ))]
+#[boilermates(attr_for("MetadataGroupWithoutId", "#[serde(default)]"))]
 pub struct Model {
     #[boilermates(not_in("MetadataGroupWithoutId"))]
     #[sea_orm(primary_key, auto_increment = false)]
@@ -2,7 +2,8 @@
 
 use async_graphql::SimpleObject;
 use async_trait::async_trait;
-use enum_models::NotificationPlatformLot;
+use common_models::UserNotificationContent;
+use enum_models::{MediaLot, NotificationPlatformLot};
 use nanoid::nanoid;
 use sea_orm::{entity::prelude::*, ActiveValue};
 use user_models::NotificationPlatformSpecifics;
@@ -21,6 +22,13 @@ pub struct Model {
     pub description: String,
     #[graphql(skip)]
     pub user_id: String,
+    /// If set, only notifications of these types are sent to this platform.
+    pub content_types: Option<Vec<UserNotificationContent>>,
+    /// If set, notifications about media are only sent to this platform if the media
+    /// belongs to one of these lots.
+    pub media_lots: Option<Vec<MediaLot>>,
+    /// The number of deliveries that have failed in a row.
+    pub consecutive_failures: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
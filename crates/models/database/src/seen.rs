@@ -6,8 +6,8 @@ use chrono::{NaiveDate, Utc};
 use educe::Educe;
 use enum_models::{EntityLot, SeenState};
 use media_models::{
-    SeenAnimeExtraInformation, SeenMangaExtraInformation, SeenPodcastExtraInformation,
-    SeenShowExtraInformation,
+    SeenAnimeExtraInformation, SeenBoardGameExtraInformation, SeenMangaExtraInformation,
+    SeenPodcastExtraInformation, SeenShowExtraInformation,
 };
 use nanoid::nanoid;
 use rust_decimal::Decimal;
@@ -39,6 +39,7 @@ pub struct Model {
     pub podcast_extra_information: Option<SeenPodcastExtraInformation>,
     pub anime_extra_information: Option<SeenAnimeExtraInformation>,
     pub manga_extra_information: Option<SeenMangaExtraInformation>,
+    pub board_game_extra_information: Option<SeenBoardGameExtraInformation>,
     pub manual_time_spent: Option<Decimal>,
     // Generated columns
     pub last_updated_on: DateTimeUtc,
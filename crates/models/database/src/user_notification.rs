@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.1
 
+use common_models::UserNotificationPayload;
 use enum_models::UserNotificationLot;
 use sea_orm::entity::prelude::*;
 use uuid::Uuid;
@@ -13,6 +14,7 @@ pub struct Model {
     pub user_id: String,
     pub lot: UserNotificationLot,
     pub is_addressed: Option<bool>,
+    pub payload: Option<UserNotificationPayload>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -10,7 +10,8 @@ use config::FrontendConfig;
 use database_models::{
     collection, exercise, metadata,
     metadata_group::{self, MetadataGroupWithoutId},
-    person, seen, user, user_measurement, user_to_entity, workout, workout_template,
+    notification_delivery, notification_platform, person, seen, user, user_measurement,
+    user_to_entity, workout, workout_template,
 };
 use enum_models::{
     ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic, ExerciseMuscle,
@@ -97,6 +98,14 @@ pub struct UserWorkoutDetails {
     pub collections: Vec<collection::Model>,
 }
 
+#[derive(Debug, SimpleObject, Clone)]
+pub struct UserNotificationPlatform {
+    #[graphql(flatten)]
+    pub details: notification_platform::Model,
+    /// The most recent attempts to deliver notifications to this platform.
+    pub deliveries: Vec<notification_delivery::Model>,
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct UserExerciseDetails {
     pub reviews: Vec<ReviewItem>,
@@ -119,6 +128,7 @@ pub struct MetadataGroupPersonRelated {
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Hash)]
+#[serde(default)]
 pub struct PersonDetails {
     pub name: String,
     pub identifier: String,
@@ -347,6 +357,9 @@ pub struct DailyUserActivityItem {
     pub podcast_count: i64,
     pub total_podcast_duration: i64,
     pub manga_count: i64,
+    pub comic_count: i64,
+    pub board_game_count: i64,
+    pub total_board_game_duration: i64,
     pub movie_count: i64,
     pub total_movie_duration: i64,
     pub music_count: i64,
@@ -447,7 +460,9 @@ pub struct EmptyCacheValue {
 }
 
 pub type IgdbSettings = String;
+pub type TvdbSettings = String;
 pub type YoutubeMusicSongListenedResponse = bool;
+pub type SteamGameMappingResponse = Option<String>;
 pub type ListennotesSettings = HashMap<i32, String>;
 pub type UserCollectionsListResponse = Vec<CollectionItem>;
 pub type UserMetadataRecommendationsResponse = Vec<String>;
@@ -459,12 +474,14 @@ pub type MetadataGroupSearchResponse = SearchResults<MetadataGroupSearchItem>;
 pub enum ApplicationCacheValue {
     TmdbSettings(TmdbSettings),
     IgdbSettings(IgdbSettings),
+    TvdbSettings(TvdbSettings),
     UserAnalytics(UserAnalytics),
     CoreDetails(Box<CoreDetails>),
     PeopleSearch(PeopleSearchResponse),
     ProgressUpdateCache(EmptyCacheValue),
     MetadataSearch(MetadataSearchResponse),
     ListennotesSettings(ListennotesSettings),
+    SteamGameMapping(SteamGameMappingResponse),
     MetadataRecentlyConsumed(EmptyCacheValue),
     UserAnalyticsParameters(ApplicationDateRange),
     UserCollectionsList(UserCollectionsListResponse),
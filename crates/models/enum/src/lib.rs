@@ -39,6 +39,8 @@ pub enum MediaLot {
     AudioBook,
     VideoGame,
     VisualNovel,
+    Comic,
+    BoardGame,
 }
 
 /// The different sources (or providers) from which data can be obtained from.
@@ -65,20 +67,28 @@ pub enum MediaLot {
 #[serde(rename_all = "snake_case")]
 pub enum MediaSource {
     Mal,
+    Rss,
     Igdb,
+    Rawg,
     Tmdb,
+    Tvdb,
     Vndb,
     #[default]
     Custom,
     Itunes,
+    Remote,
     Anilist,
     Audible,
+    ComicVine,
     Hardcover,
     Listennotes,
     GoogleBooks,
+    PodcastIndex,
     Openlibrary,
+    MusicBrainz,
     MangaUpdates,
     YoutubeMusic,
+    BoardGameGeek,
 }
 
 #[derive(
@@ -107,6 +117,22 @@ pub enum UserNotificationLot {
     Immediate,
 }
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "String(StringLen::None)",
+    rename_all = "snake_case"
+)]
+pub enum NotificationDeliveryStatus {
+    Delivered,
+    /// The delivery failed and will be attempted again.
+    Failed,
+    /// The delivery failed permanently or ran out of attempts.
+    Abandoned,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -231,6 +257,25 @@ pub enum ImportSource {
     Audiobookshelf,
 }
 
+/// The different formats in which the data of a user can be exported.
+#[derive(
+    Eq, Enum, Copy, Debug, Clone, Default, Display, EnumIter, PartialEq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// A single JSON file following the `CompleteExport` schema.
+    #[default]
+    GenericJson,
+    /// A ZIP archive of flat CSV files.
+    GenericCsv,
+    /// A Letterboxd compatible diary CSV containing movies.
+    Letterboxd,
+    /// A Goodreads compatible library CSV containing books.
+    Goodreads,
+    /// A Trakt compatible history JSON containing movies and shows.
+    Trakt,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -572,11 +617,15 @@ pub enum IntegrationProvider {
     Emby,
     Kodi,
     Komga,
+    Steam,
     Radarr,
     Sonarr,
+    Trakt,
+    Anilist,
     PlexSink,
     PlexYank,
     GenericJson,
+    Myanimelist,
     YoutubeMusic,
     JellyfinPush,
     JellyfinSink,
@@ -612,4 +661,6 @@ pub enum NotificationPlatformLot {
     PushSafer,
     Email,
     Telegram,
+    Matrix,
+    Slack,
 }
@@ -5,18 +5,19 @@ use boilermates::boilermates;
 use chrono::{NaiveDate, NaiveDateTime};
 use common_models::{
     CollectionExtraInformation, IdAndNamedObject, PersonSourceSpecifics, SearchInput, StoredUrl,
-    StringIdObject,
+    StringIdObject, UserNotificationContent,
 };
 use common_utils::deserialize_date;
 use enum_models::{
-    EntityLot, ImportSource, IntegrationProvider, MediaLot, MediaSource, NotificationPlatformLot,
-    SeenState, Visibility,
+    EntityLot, ExportFormat, ImportSource, IntegrationProvider, MediaLot, MediaSource,
+    NotificationPlatformLot, SeenState, Visibility,
 };
 use rust_decimal::Decimal;
 use schematic::Schematic;
 use sea_orm::{prelude::DateTimeUtc, EnumIter, FromJsonQueryResult, FromQueryResult};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use user_models::NotificationPlatformSmtpSettings;
 
 #[derive(Debug, Default, SimpleObject, Serialize, Deserialize, Clone)]
 pub struct EntityWithLot {
@@ -212,6 +213,12 @@ pub struct ShowEpisode {
     pub overview: Option<String>,
     pub poster_images: Vec<String>,
     pub runtime: Option<i32>,
+    /// The number of the episode when all regular episodes are numbered sequentially.
+    pub absolute_number: Option<i32>,
+    /// The season of the episode in the DVD or Blu-ray release.
+    pub dvd_season_number: Option<i32>,
+    /// The number of the episode in the DVD or Blu-ray release.
+    pub dvd_episode_number: Option<i32>,
 }
 
 #[skip_serializing_none]
@@ -328,6 +335,46 @@ pub struct MangaSpecifics {
     pub url: Option<String>,
 }
 
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "ComicSpecificsInput")]
+pub struct ComicSpecifics {
+    pub volume: Option<String>,
+    pub publisher: Option<String>,
+    pub issue_number: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(
+    Eq,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    InputObject,
+    SimpleObject,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "BoardGameSpecificsInput")]
+pub struct BoardGameSpecifics {
+    pub min_players: Option<i32>,
+    pub max_players: Option<i32>,
+    pub play_time: Option<i32>,
+}
+
 #[derive(PartialEq, Default, Eq, Debug, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct MetadataSearchItem {
     pub title: String,
@@ -378,6 +425,11 @@ pub struct ProgressUpdateInput {
     pub podcast_episode_number: Option<i32>,
     pub provider_watched_on: Option<String>,
     pub manga_chapter_number: Option<Decimal>,
+    pub board_game_player_count: Option<i32>,
+    pub board_game_duration: Option<i32>,
+    pub board_game_winner: Option<String>,
+    #[graphql(skip_input)]
+    pub manual_time_spent: Option<Decimal>,
 }
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
@@ -423,14 +475,36 @@ pub struct WatchProvider {
     pub languages: HashSet<String>,
 }
 
+/// An equivalent item of a metadata on another provider.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, SimpleObject)]
+pub struct MetadataLinkedSource {
+    pub source: MediaSource,
+    pub identifier: String,
+}
+
+/// The title and description of a metadata in a language other than the one it was
+/// fetched in.
+#[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+pub struct MetadataLocalizedDetails {
+    pub language: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
 #[derive(
     Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, SimpleObject, Default,
 )]
+#[serde(default)]
 pub struct MetadataExternalIdentifiers {
     pub tvdb_id: Option<i32>,
+    pub tmdb_id: Option<i32>,
+    pub imdb_id: Option<String>,
+    pub isbns: Vec<String>,
+    pub linked_sources: Vec<MetadataLinkedSource>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
 pub struct MetadataDetails {
     pub lot: MediaLot,
     pub title: String,
@@ -455,6 +529,8 @@ pub struct MetadataDetails {
     pub movie_specifics: Option<MovieSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
     pub suggestions: Vec<PartialMetadataWithoutId>,
     pub podcast_specifics: Option<PodcastSpecifics>,
@@ -489,6 +565,14 @@ pub struct ImportOrExportMetadataItemSeen {
     pub manga_chapter_number: Option<Decimal>,
     /// If for a manga, the volume which was seen.
     pub manga_volume_number: Option<i32>,
+    /// If for a board game, the number of players in the play.
+    pub board_game_player_count: Option<i32>,
+    /// If for a board game, the duration of the play in minutes.
+    pub board_game_duration: Option<i32>,
+    /// If for a board game, the winner of the play.
+    pub board_game_winner: Option<String>,
+    /// The time spent on this item in seconds, if tracked manually.
+    pub manual_time_spent: Option<Decimal>,
     /// The provider this item was watched on.
     pub provider_watched_on: Option<String>,
 }
@@ -728,6 +812,16 @@ pub struct SeenMangaExtraInformation {
     pub volume: Option<i32>,
 }
 
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Clone, SimpleObject, FromJsonQueryResult,
+)]
+pub struct SeenBoardGameExtraInformation {
+    pub player_count: Option<i32>,
+    /// The duration of the play in minutes.
+    pub duration: Option<i32>,
+    pub winner: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ReviewPostedEvent {
     pub obj_id: String,
@@ -764,6 +858,8 @@ pub struct MetadataPartialDetails {
     #[graphql(skip)]
     pub images: Option<Vec<MetadataImage>>,
     pub publish_year: Option<i32>,
+    #[graphql(skip)]
+    pub localized_details: Option<Vec<MetadataLocalizedDetails>>,
 }
 
 #[derive(Debug, InputObject)]
@@ -881,6 +977,54 @@ pub struct IntegrationProviderSpecifics {
     pub jellyfin_push_password: Option<String>,
 
     pub youtube_music_auth_cookie: Option<String>,
+
+    pub steam_api_key: Option<String>,
+    pub steam_user_id: Option<String>,
+
+    /// The device code that was approved on Trakt. It is exchanged for the tokens
+    /// below when the integration is created.
+    #[graphql(skip_output)]
+    pub trakt_device_code: Option<String>,
+    #[graphql(skip)]
+    pub trakt_access_token: Option<String>,
+    #[graphql(skip)]
+    pub trakt_refresh_token: Option<String>,
+    #[graphql(skip)]
+    pub trakt_token_expires_at: Option<DateTimeUtc>,
+
+    pub anilist_token: Option<String>,
+
+    /// The authorization code that was returned by MyAnimeList along with the code
+    /// verifier it was requested with. They are exchanged for the tokens below when
+    /// the integration is created.
+    #[graphql(skip_output)]
+    pub mal_code: Option<String>,
+    #[graphql(skip_output)]
+    pub mal_code_verifier: Option<String>,
+    #[graphql(skip)]
+    pub mal_access_token: Option<String>,
+    #[graphql(skip)]
+    pub mal_refresh_token: Option<String>,
+    #[graphql(skip)]
+    pub mal_token_expires_at: Option<DateTimeUtc>,
+}
+
+/// The code that the user has to enter on Trakt to authorize Ryot.
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+pub struct TraktDeviceCode {
+    pub user_code: String,
+    pub device_code: String,
+    pub verification_url: String,
+    /// The number of seconds after which the code expires.
+    pub expires_in: i64,
+}
+
+/// The page where the user can authorize Ryot to access their MyAnimeList account.
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+pub struct MalAuthorizationUrl {
+    pub url: String,
+    /// Has to be sent back along with the authorization code.
+    pub code_verifier: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, SimpleObject)]
@@ -948,6 +1092,8 @@ pub struct DeployIgdbImportInput {
 pub struct DeployJsonImportInput {
     // The file path of the uploaded JSON export.
     pub export: String,
+    // The passphrase that the export was encrypted with, if any.
+    pub passphrase: Option<String>,
 }
 
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
@@ -977,7 +1123,19 @@ pub struct DeployImportJobInput {
     pub jellyfin: Option<DeployUrlAndKeyAndUsernameImportInput>,
 }
 
-#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+#[derive(Debug, Default, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployExportJobInput {
+    #[graphql(default)]
+    pub format: ExportFormat,
+    /// If set, the export file will be encrypted with a key derived from this passphrase.
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, SimpleObject, Clone)]
+#[graphql(
+    name = "ScrapedCustomMetadata",
+    input_name = "CreateCustomMetadataInput"
+)]
 pub struct CreateCustomMetadataInput {
     pub title: String,
     pub lot: MediaLot,
@@ -993,6 +1151,8 @@ pub struct CreateCustomMetadataInput {
     pub music_specifics: Option<MusicSpecifics>,
     pub movie_specifics: Option<MovieSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub podcast_specifics: Option<PodcastSpecifics>,
     pub audio_book_specifics: Option<AudioBookSpecifics>,
@@ -1000,6 +1160,13 @@ pub struct CreateCustomMetadataInput {
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct ScrapeCustomMetadataInput {
+    pub url: String,
+    /// The type of media on the page. Detected from the page if not provided.
+    pub lot: Option<MediaLot>,
+}
+
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 pub struct UpdateCustomMetadataInput {
     pub existing_metadata_id: String,
@@ -1034,12 +1201,19 @@ pub struct CreateUserNotificationPlatformInput {
     pub auth_header: Option<String>,
     pub priority: Option<i32>,
     pub chat_id: Option<String>,
+    pub smtp: Option<NotificationPlatformSmtpSettings>,
+    pub content_types: Option<Vec<UserNotificationContent>>,
+    pub media_lots: Option<Vec<MediaLot>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 pub struct UpdateUserNotificationPlatformInput {
     pub notification_id: String,
     pub is_disabled: Option<bool>,
+    /// Pass an empty list to send notifications of all types to the platform.
+    pub content_types: Option<Vec<UserNotificationContent>>,
+    /// Pass an empty list to send notifications about media of all lots to the platform.
+    pub media_lots: Option<Vec<MediaLot>>,
 }
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
@@ -1241,6 +1415,8 @@ pub struct GraphqlMetadataDetails {
     pub movie_specifics: Option<MovieSpecifics>,
     pub music_specifics: Option<MusicSpecifics>,
     pub manga_specifics: Option<MangaSpecifics>,
+    pub comic_specifics: Option<ComicSpecifics>,
+    pub board_game_specifics: Option<BoardGameSpecifics>,
     pub anime_specifics: Option<AnimeSpecifics>,
     pub podcast_specifics: Option<PodcastSpecifics>,
     pub creators: Vec<MetadataCreatorGroupedByRole>,
@@ -1248,6 +1424,7 @@ pub struct GraphqlMetadataDetails {
     pub video_game_specifics: Option<VideoGameSpecifics>,
     #[graphql(skip)]
     pub external_identifiers: Option<MetadataExternalIdentifiers>,
+    pub linked_sources: Vec<MetadataLinkedSource>,
     pub visual_novel_specifics: Option<VisualNovelSpecifics>,
 }
 
@@ -1372,6 +1549,13 @@ pub struct MarkEntityAsPartialInput {
     pub entity_lot: EntityLot,
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+pub struct SwitchMetadataSourceInput {
+    pub metadata_id: String,
+    pub source: MediaSource,
+    pub identifier: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
 pub struct PresignedPutUrlResponse {
     pub upload_url: String,
@@ -1389,6 +1573,14 @@ pub struct CreateReviewCommentInput {
     pub should_delete: Option<bool>,
 }
 
+/// A monitored person or group that the media of a calendar event is associated with.
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+pub struct CalendarEventMonitoredEntity {
+    pub name: String,
+    pub entity_id: String,
+    pub entity_lot: EntityLot,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, Default)]
 pub struct GraphqlCalendarEvent {
     pub date: NaiveDate,
@@ -1401,6 +1593,9 @@ pub struct GraphqlCalendarEvent {
     pub show_extra_information: Option<SeenShowExtraInformation>,
     pub podcast_extra_information: Option<SeenPodcastExtraInformation>,
     pub anime_extra_information: Option<SeenAnimeExtraInformation>,
+    /// Set when the media is not tracked by the user and is only shown because it is
+    /// associated with a person or group that they monitor.
+    pub monitored_entity: Option<CalendarEventMonitoredEntity>,
 }
 
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, Default)]
@@ -1423,6 +1618,15 @@ pub enum UserUpcomingCalendarEventInput {
     NextDays(u64),
 }
 
+/// The filters for the calendar feed of a user. Multiple values are separated by commas.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UserCalendarFeedInput {
+    /// The media types to include, for example `show,movie`.
+    pub lots: Option<String>,
+    /// The IDs of the collections the media must be a part of.
+    pub collections: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 pub struct PresignedPutUrlInput {
     pub file_name: String,
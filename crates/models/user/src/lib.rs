@@ -3,7 +3,7 @@ use common_models::UserNotificationContent;
 use educe::Educe;
 use enum_models::{MediaLot, UserLot};
 use fitness_models::{SetRestTimersSettings, UserUnitSystem};
-use sea_orm::{FromJsonQueryResult, Iterable};
+use sea_orm::{prelude::DateTimeUtc, FromJsonQueryResult, Iterable};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use strum::EnumString;
@@ -38,6 +38,28 @@ pub struct UserNotificationsPreferences {
     pub to_send: Vec<UserNotificationContent>,
     #[educe(Default = true)]
     pub enabled: bool,
+    #[graphql(default)]
+    pub digest: UserNotificationDigest,
+    /// The hour (0-23) at which digests are sent. This is in the timezone of the server
+    /// (set with `TZ`), not the timezone of the user.
+    #[educe(Default = 9)]
+    #[graphql(default = 9)]
+    pub digest_hour: u32,
+    /// The day of the week (1-7, starting with 1 for Monday) on which weekly digests are
+    /// sent. Like the hour, it is in the timezone of the server.
+    #[educe(Default = 1)]
+    #[graphql(default = 1)]
+    pub digest_weekday: u32,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Enum, Clone, Eq, PartialEq, FromJsonQueryResult, Copy, Default,
+)]
+pub enum UserNotificationDigest {
+    #[default]
+    Immediate,
+    Daily,
+    Weekly,
 }
 
 #[derive(
@@ -355,6 +377,29 @@ pub enum UserReviewScale {
     ThreePointSmiley,
 }
 
+/// The order in which the episodes of a show are grouped into seasons when tracking it.
+/// Only used for shows from providers that support multiple orderings.
+#[derive(
+    Debug,
+    Serialize,
+    Default,
+    Deserialize,
+    Enum,
+    Clone,
+    Eq,
+    PartialEq,
+    FromJsonQueryResult,
+    Copy,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive, serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShowEpisodeOrdering {
+    #[default]
+    Aired,
+    Dvd,
+    Absolute,
+}
+
 #[derive(
     Debug, Serialize, Deserialize, Enum, Clone, Eq, PartialEq, FromJsonQueryResult, Copy, Default,
 )]
@@ -443,12 +488,19 @@ pub struct UserGeneralPreferences {
     pub grid_packing: GridPacking,
     #[educe(Default = UserReviewScale::OutOfHundred)]
     pub review_scale: UserReviewScale,
+    #[graphql(default)]
+    #[educe(Default = ShowEpisodeOrdering::Aired)]
+    pub show_episode_ordering: ShowEpisodeOrdering,
     #[educe(Default = false)]
     pub disable_watch_providers: bool,
     #[educe(Default = false)]
     pub disable_integrations: bool,
     #[educe(Default = false)]
     pub disable_navigation_animation: bool,
+    /// The ISO 639-1 code of the language to display titles and descriptions in.
+    pub language: Option<String>,
+    /// The ISO 3166-1 code of the country to display watch providers for.
+    pub region: Option<String>,
     #[educe(Default(expression = vec![
         UserGeneralDashboardElement {
             num_elements: Some(8),
@@ -527,11 +579,32 @@ pub enum NotificationPlatformSpecifics {
     },
     Email {
         email: String,
+        smtp: Option<NotificationPlatformSmtpSettings>,
     },
     Telegram {
         bot_token: String,
         chat_id: String,
     },
+    Matrix {
+        url: String,
+        token: String,
+        room_id: String,
+    },
+    Slack {
+        url: String,
+    },
+}
+
+/// The SMTP server to send emails from, used instead of the one configured for the
+/// instance.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, InputObject)]
+pub struct NotificationPlatformSmtpSettings {
+    pub server: String,
+    pub user: String,
+    #[graphql(secret)]
+    pub password: String,
+    /// Defaults to the mailbox configured for the instance.
+    pub mailbox: Option<String>,
 }
 
 #[derive(
@@ -539,6 +612,9 @@ pub enum NotificationPlatformSpecifics {
 )]
 pub struct UserExtraInformation {
     pub scheduled_for_workout_revision: bool,
+    pub last_notification_digest_sent_on: Option<DateTimeUtc>,
+    /// The token used to access the calendar feed of the user.
+    pub calendar_feed_token: Option<String>,
 }
 
 #[derive(Debug, InputObject)]
@@ -1,22 +1,20 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use common_models::{PersonSourceSpecifics, SearchDetails, StoredUrl};
 use common_utils::PAGE_SIZE;
 use config::AnilistPreferredLanguage;
-use dependent_models::{
-    PersonDetails, MetadataPersonRelated, PeopleSearchResponse, SearchResults,
-};
+use dependent_models::{MetadataPersonRelated, PeopleSearchResponse, PersonDetails, SearchResults};
 use enum_models::{MediaLot, MediaSource};
 use graphql_client::{GraphQLQuery, Response};
 use itertools::Itertools;
 use media_models::{
     AnimeAiringScheduleSpecifics, AnimeSpecifics, MangaSpecifics, MetadataDetails,
-    MetadataImageForMediaDetails, MetadataSearchItem, MetadataVideo, MetadataVideoSource,
-    PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
+    MetadataImageForMediaDetails, MetadataLocalizedDetails, MetadataSearchItem, MetadataVideo,
+    MetadataVideoSource, PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
 };
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use sea_orm::prelude::DateTimeUtc;
 use traits::MediaProvider;
@@ -80,13 +78,13 @@ struct StudioQuery;
 
 #[derive(Debug, Clone)]
 pub struct AnilistService {
-    client: Client,
+    client: ClientWithMiddleware,
     preferred_language: AnilistPreferredLanguage,
 }
 
 impl AnilistService {
     async fn new(config: &config::AnilistConfig) -> Self {
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::Anilist, None);
         Self {
             client,
             preferred_language: config.preferred_language.clone(),
@@ -147,6 +145,7 @@ impl MediaProvider for NonMediaAnilistService {
                 .base
                 .client
                 .post(URL)
+                .with_extension(CacheableProviderRequest)
                 .json(&body)
                 .send()
                 .await
@@ -189,6 +188,7 @@ impl MediaProvider for NonMediaAnilistService {
                 .base
                 .client
                 .post(URL)
+                .with_extension(CacheableProviderRequest)
                 .json(&body)
                 .send()
                 .await
@@ -448,6 +448,14 @@ impl MediaProvider for AnilistAnimeService {
         Ok(details)
     }
 
+    async fn metadata_localized_details(
+        &self,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        localized_details(&self.base.client, identifier, languages).await
+    }
+
     async fn metadata_search(
         &self,
         query: &str,
@@ -492,6 +500,14 @@ impl MediaProvider for AnilistMangaService {
         Ok(details)
     }
 
+    async fn metadata_localized_details(
+        &self,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        localized_details(&self.base.client, identifier, languages).await
+    }
+
     async fn metadata_search(
         &self,
         query: &str,
@@ -516,7 +532,7 @@ impl MediaProvider for AnilistMangaService {
 }
 
 async fn media_details(
-    client: &Client,
+    client: &ClientWithMiddleware,
     id: &str,
     preferred_language: &AnilistPreferredLanguage,
 ) -> Result<MetadataDetails> {
@@ -708,7 +724,7 @@ async fn media_details(
 }
 
 async fn search(
-    client: &Client,
+    client: &ClientWithMiddleware,
     media_type: media_search_query::MediaType,
     query: &str,
     page: Option<i32>,
@@ -726,6 +742,7 @@ async fn search(
     let body = MediaSearchQuery::build_query(variables);
     let search = client
         .post(URL)
+        .with_extension(CacheableProviderRequest)
         .json(&body)
         .send()
         .await
@@ -769,6 +786,30 @@ async fn search(
     Ok((media, total, next_page))
 }
 
+/// Anilist only has titles in english and japanese (native and romanized), so
+/// only those languages can be localized.
+async fn localized_details(
+    client: &ClientWithMiddleware,
+    id: &str,
+    languages: &[String],
+) -> Result<Vec<MetadataLocalizedDetails>> {
+    let mut localized = vec![];
+    for language in languages {
+        let preferred_language = match language.as_str() {
+            "en" => AnilistPreferredLanguage::English,
+            "ja" => AnilistPreferredLanguage::Native,
+            _ => continue,
+        };
+        let details = media_details(client, id, &preferred_language).await?;
+        localized.push(MetadataLocalizedDetails {
+            description: None,
+            title: Some(details.title),
+            language: language.to_owned(),
+        });
+    }
+    Ok(localized)
+}
+
 fn get_in_preferred_language(
     native: Option<String>,
     english: Option<String>,
@@ -1,11 +1,11 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use common_models::{NamedObject, PersonSourceSpecifics, SearchDetails};
 use common_utils::{convert_date_to_year, convert_string_to_date, PAGE_SIZE};
 use convert_case::{Case, Casing};
 use database_models::metadata_group::MetadataGroupWithoutId;
-use dependent_models::{PersonDetails, PeopleSearchResponse, SearchResults};
+use dependent_models::{PeopleSearchResponse, PersonDetails, SearchResults};
 use educe::Educe;
 use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
@@ -15,7 +15,7 @@ use media_models::{
     PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
 };
 use paginate::Pages;
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -129,7 +129,7 @@ struct AudibleItemSimResponse {
 #[derive(Debug, Clone)]
 pub struct AudibleService {
     url: String,
-    client: Client,
+    client: ClientWithMiddleware,
     locale: String,
 }
 
@@ -153,7 +153,7 @@ impl AudibleService {
 
     pub async fn new(config: &config::AudibleConfig) -> Self {
         let url = Self::url_from_locale(&config.locale);
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::Audible, None);
         Self {
             url,
             client,
@@ -173,9 +173,10 @@ impl MediaProvider for AudibleService {
     ) -> Result<PeopleSearchResponse> {
         let internal_page: usize = page.unwrap_or(1).try_into().unwrap();
         let req_internal_page = internal_page - 1;
-        let client = Client::new();
+        let client = get_provider_http_client(MediaSource::Audible, None);
         let data: Vec<AudibleAuthor> = client
             .get(format!("{}/authors", AUDNEX_URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({ "region": self.locale, "name": query }))
             .send()
             .await
@@ -214,7 +215,7 @@ impl MediaProvider for AudibleService {
         identity: &str,
         _source_specifics: &Option<PersonSourceSpecifics>,
     ) -> Result<PersonDetails> {
-        let client = Client::new();
+        let client = get_provider_http_client(MediaSource::Audible, None);
         let data: AudnexResponse = client
             .get(format!("{}/authors/{}", AUDNEX_URL, identity))
             .query(&json!({ "region": self.locale }))
@@ -365,6 +366,7 @@ impl MediaProvider for AudibleService {
         let rsp = self
             .client
             .get(&self.url)
+            .with_extension(CacheableProviderRequest)
             .query(&SearchQuery {
                 title: query.to_owned(),
                 num_results: PAGE_SIZE,
@@ -0,0 +1,461 @@
+use anyhow::{anyhow, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use common_models::{PersonSourceSpecifics, SearchDetails, StoredUrl};
+use common_utils::PAGE_SIZE;
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupSearchResponse, MetadataPersonRelated, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    BoardGameSpecifics, CommitMediaInput, MetadataDetails, MetadataGroupSearchItem, MetadataImage,
+    MetadataImageForMediaDetails, MetadataSearchItem, PartialMetadataPerson,
+    PartialMetadataWithoutId, UniqueMediaIdentifier,
+};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use traits::MediaProvider;
+
+static URL: &str = "https://boardgamegeek.com/xmlapi2";
+/// The XML API does not expose people, so their details are fetched from the API
+/// that powers the website.
+static GEEKDO_URL: &str = "https://api.geekdo.com/api";
+static EXPANSION_LINK: &str = "boardgameexpansion";
+static DESIGNER_LINK: &str = "boardgamedesigner";
+static CATEGORY_LINK: &str = "boardgamecategory";
+/// BoardGameGeek uses this designer for games whose designer is unknown.
+const UNCREDITED_DESIGNER_ID: i32 = 3;
+/// The maximum number of linked items returned for a person in a single request.
+const LINKED_ITEMS_LIMIT: i32 = 50;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggValue {
+    value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggName {
+    #[serde(rename = "type")]
+    lot: String,
+    value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggLink {
+    id: i32,
+    value: String,
+    #[serde(rename = "type")]
+    lot: String,
+    inbound: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggRatings {
+    average: Option<BggValue>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggStatistics {
+    ratings: Option<BggRatings>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggItem {
+    id: i32,
+    #[serde(rename = "type")]
+    lot: String,
+    image: Option<String>,
+    thumbnail: Option<String>,
+    description: Option<String>,
+    minplayers: Option<BggValue>,
+    maxplayers: Option<BggValue>,
+    playingtime: Option<BggValue>,
+    yearpublished: Option<BggValue>,
+    statistics: Option<BggStatistics>,
+    #[serde(default, rename = "name")]
+    names: Vec<BggName>,
+    #[serde(default, rename = "link")]
+    links: Vec<BggLink>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct BggItems {
+    #[serde(default, rename = "item")]
+    items: Vec<BggItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct GeekItem {
+    name: String,
+    href: Option<String>,
+    imageurl: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct GeekItemResponse {
+    item: GeekItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct GeekLinkedItem {
+    name: String,
+    objectid: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct GeekLinkedItemsResponse {
+    items: Vec<GeekLinkedItem>,
+}
+
+/// Descriptions are escaped twice, so the entities that remain after parsing are
+/// decoded here.
+fn get_description(description: &Option<String>) -> Option<String> {
+    description
+        .as_ref()
+        .map(|d| {
+            d.replace("&#10;", "\n")
+                .replace("&quot;", "\"")
+                .replace("&rsquo;", "'")
+                .replace("&ndash;", "-")
+                .replace("&mdash;", "-")
+                .replace("&amp;", "&")
+        })
+        .filter(|d| !d.is_empty())
+}
+
+fn get_value<T: std::str::FromStr>(value: &Option<BggValue>) -> Option<T> {
+    value.as_ref().and_then(|v| v.value.parse().ok())
+}
+
+impl BggItem {
+    fn title(&self) -> String {
+        self.names
+            .iter()
+            .find(|n| n.lot == "primary")
+            .or_else(|| self.names.first())
+            .map(|n| n.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// Games which have not been published yet have the year set to zero.
+    fn publish_year(&self) -> Option<i32> {
+        get_value(&self.yearpublished).filter(|&y| y != 0)
+    }
+
+    fn links_of(&self, lot: &'static str) -> impl Iterator<Item = &BggLink> {
+        self.links.iter().filter(move |l| l.lot == lot)
+    }
+
+    /// Expansions link to the games they expand with an inbound link, while base
+    /// games link to their expansions with an outbound one.
+    fn expansion_links(&self, inbound: bool) -> impl Iterator<Item = &BggLink> {
+        self.links_of(EXPANSION_LINK)
+            .filter(move |l| (l.inbound.as_deref() == Some("true")) == inbound)
+    }
+}
+
+pub struct BoardGameGeekService {
+    api_token: String,
+    client: ClientWithMiddleware,
+}
+
+impl BoardGameGeekService {
+    pub async fn new(config: &config::BoardGameGeekConfig) -> Self {
+        let client = get_provider_http_client(MediaSource::BoardGameGeek, None);
+        Self {
+            client,
+            api_token: config.api_token.clone(),
+        }
+    }
+}
+
+impl BoardGameGeekService {
+    fn items_request(&self, path: &str, query: Value) -> RequestBuilder {
+        self.client
+            .get(format!("{}/{}", URL, path))
+            .bearer_auth(&self.api_token)
+            .query(&query)
+    }
+
+    async fn get_items(&self, request: RequestBuilder) -> Result<Vec<BggItem>> {
+        let text = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .text()
+            .await?;
+        let data: BggItems = serde_xml_rs::from_str(&text)?;
+        Ok(data.items)
+    }
+
+    async fn get_geekdo<T: DeserializeOwned>(&self, path: &str, query: Value) -> Result<T> {
+        let data = self
+            .client
+            .get(format!("{}/{}", GEEKDO_URL, path))
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        Ok(data)
+    }
+
+    async fn thing(&self, identifier: &str) -> Result<BggItem> {
+        self.get_items(self.items_request("thing", json!({ "id": identifier, "stats": 1 })))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No board game found with identifier {}", identifier))
+    }
+
+    /// The search endpoint returns every match without any images, so results are
+    /// paginated here and the details of the current page are fetched separately.
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+    ) -> Result<(Vec<BggItem>, SearchDetails)> {
+        let page = page.unwrap_or(1).max(1);
+        let request = self
+            .items_request("search", json!({ "query": query, "type": "boardgame" }))
+            .with_extension(CacheableProviderRequest);
+        let results = self.get_items(request).await?;
+        let total = results.len().try_into().unwrap();
+        let next_page = (page * PAGE_SIZE < total).then_some(page + 1);
+        let ids = results
+            .into_iter()
+            .skip(((page - 1) * PAGE_SIZE).try_into().unwrap())
+            .take(PAGE_SIZE.try_into().unwrap())
+            .map(|i| i.id)
+            .collect_vec();
+        let items = match ids.is_empty() {
+            true => vec![],
+            false => {
+                let things = self
+                    .get_items(self.items_request("thing", json!({ "id": ids.iter().join(",") })))
+                    .await?;
+                ids.into_iter()
+                    .filter_map(|id| things.iter().find(|t| t.id == id).cloned())
+                    .collect()
+            }
+        };
+        Ok((items, SearchDetails { total, next_page }))
+    }
+
+    fn partial_game(&self, identifier: String, title: String) -> PartialMetadataWithoutId {
+        PartialMetadataWithoutId {
+            title,
+            identifier,
+            lot: MediaLot::BoardGame,
+            source: MediaSource::BoardGameGeek,
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl MediaProvider for BoardGameGeekService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (items, details) = self.search(query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|i| MetadataSearchItem {
+                title: i.title(),
+                publish_year: i.publish_year(),
+                identifier: i.id.to_string(),
+                image: i.thumbnail.or(i.image),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let data = self.thing(identifier).await?;
+        let people = data
+            .links_of(DESIGNER_LINK)
+            .filter(|l| l.id != UNCREDITED_DESIGNER_ID)
+            .map(|l| PartialMetadataPerson {
+                name: l.value.clone(),
+                identifier: l.id.to_string(),
+                source: MediaSource::BoardGameGeek,
+                role: "Designer".to_owned(),
+                ..Default::default()
+            })
+            .collect();
+        let groups = match data.lot.as_str() {
+            "boardgameexpansion" => data
+                .expansion_links(true)
+                .map(|l| (l.id, l.value.clone()))
+                .collect_vec(),
+            _ => match data.expansion_links(false).next() {
+                Some(_) => vec![(data.id, data.title())],
+                None => vec![],
+            },
+        }
+        .into_iter()
+        .map(|(id, name)| CommitMediaInput {
+            name,
+            unique: UniqueMediaIdentifier {
+                lot: MediaLot::BoardGame,
+                identifier: id.to_string(),
+                source: MediaSource::BoardGameGeek,
+            },
+        })
+        .collect();
+        Ok(MetadataDetails {
+            people,
+            groups,
+            title: data.title(),
+            lot: MediaLot::BoardGame,
+            identifier: data.id.to_string(),
+            source: MediaSource::BoardGameGeek,
+            publish_year: data.publish_year(),
+            description: get_description(&data.description),
+            source_url: Some(format!(
+                "https://boardgamegeek.com/{}/{}",
+                data.lot, data.id
+            )),
+            genres: data
+                .links_of(CATEGORY_LINK)
+                .map(|l| l.value.clone())
+                .collect(),
+            url_images: data
+                .image
+                .clone()
+                .map(|image| MetadataImageForMediaDetails { image })
+                .into_iter()
+                .collect(),
+            provider_rating: data
+                .statistics
+                .as_ref()
+                .and_then(|s| s.ratings.as_ref())
+                .and_then(|r| get_value::<Decimal>(&r.average))
+                .filter(|&av| av != dec!(0))
+                .map(|av| av * dec!(10)),
+            board_game_specifics: Some(BoardGameSpecifics {
+                min_players: get_value(&data.minplayers),
+                max_players: get_value(&data.maxplayers),
+                play_time: get_value(&data.playingtime).filter(|&t| t != 0),
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<MetadataGroupSearchResponse> {
+        let (items, details) = self.search(query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|i| MetadataGroupSearchItem {
+                name: i.title(),
+                identifier: i.id.to_string(),
+                parts: Some(i.expansion_links(false).count() + 1),
+                image: i.thumbnail.clone().or(i.image.clone()),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let data = self.thing(identifier).await?;
+        let items = [self.partial_game(data.id.to_string(), data.title())]
+            .into_iter()
+            .chain(
+                data.expansion_links(false)
+                    .map(|l| self.partial_game(l.id.to_string(), l.value.clone())),
+            )
+            .collect_vec();
+        let group = MetadataGroupWithoutId {
+            title: data.title(),
+            lot: MediaLot::BoardGame,
+            identifier: data.id.to_string(),
+            source: MediaSource::BoardGameGeek,
+            parts: items.len().try_into().unwrap(),
+            description: get_description(&data.description),
+            source_url: Some(format!(
+                "https://boardgamegeek.com/{}/{}",
+                data.lot, data.id
+            )),
+            images: data.image.map(|url| {
+                vec![MetadataImage {
+                    url: StoredUrl::Url(url),
+                }]
+            }),
+            ..Default::default()
+        };
+        Ok((group, items))
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let data: GeekItemResponse = self
+            .get_geekdo(
+                "geekitems",
+                json!({ "nosession": 1, "objectid": identifier, "objecttype": "person" }),
+            )
+            .await?;
+        let linked: GeekLinkedItemsResponse = self
+            .get_geekdo(
+                "geekitem/linkeditems",
+                json!({
+                    "pageid": 1,
+                    "nosession": 1,
+                    "sort": "rank",
+                    "objectid": identifier,
+                    "objecttype": "person",
+                    "subtype": DESIGNER_LINK,
+                    "linkdata_index": "boardgame",
+                    "showcount": LINKED_ITEMS_LIMIT,
+                }),
+            )
+            .await?;
+        let related_metadata = linked
+            .items
+            .into_iter()
+            .map(|i| MetadataPersonRelated {
+                role: "Designer".to_owned(),
+                metadata: self.partial_game(i.objectid, i.name),
+                ..Default::default()
+            })
+            .collect();
+        Ok(PersonDetails {
+            related_metadata,
+            name: data.item.name,
+            identifier: identifier.to_owned(),
+            source: MediaSource::BoardGameGeek,
+            source_specifics: source_specifics.to_owned(),
+            images: data.item.imageurl.map(|i| vec![i]),
+            description: data.item.description.filter(|d| !d.is_empty()),
+            source_url: data
+                .item
+                .href
+                .map(|h| format!("https://boardgamegeek.com{}", h)),
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,509 @@
+use anyhow::{anyhow, bail, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
+use common_models::{PersonSourceSpecifics, SearchDetails, StoredUrl};
+use common_utils::{convert_date_to_year, convert_string_to_date, PAGE_SIZE};
+use convert_case::{Case, Casing};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupPersonRelated, MetadataGroupSearchResponse, MetadataPersonRelated,
+    PeopleSearchResponse, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    ComicSpecifics, CommitMediaInput, MetadataDetails, MetadataGroupSearchItem, MetadataImage,
+    MetadataImageForMediaDetails, MetadataSearchItem, PartialMetadataPerson,
+    PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
+};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use traits::MediaProvider;
+
+static URL: &str = "https://comicvine.gamespot.com/api";
+/// Comic Vine prefixes the identifier of each resource with its type.
+static ISSUE_PREFIX: &str = "4000";
+static PERSON_PREFIX: &str = "4040";
+static VOLUME_PREFIX: &str = "4050";
+/// The maximum number of entities Comic Vine returns in a single list request.
+const LIST_LIMIT: i32 = 100;
+const ISSUE_FIELDS: &str = "id,name,issue_number,volume,image,cover_date,store_date";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvResponse<T> {
+    error: String,
+    status_code: i32,
+    number_of_total_results: Option<i32>,
+    results: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvImage {
+    original_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvReference {
+    id: i32,
+    name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvCredit {
+    id: i32,
+    name: String,
+    role: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvIssue {
+    id: i32,
+    name: Option<String>,
+    deck: Option<String>,
+    image: Option<CvImage>,
+    cover_date: Option<String>,
+    store_date: Option<String>,
+    description: Option<String>,
+    issue_number: Option<String>,
+    volume: Option<CvReference>,
+    site_detail_url: Option<String>,
+    person_credits: Option<Vec<CvCredit>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvVolume {
+    id: i32,
+    name: String,
+    deck: Option<String>,
+    image: Option<CvImage>,
+    description: Option<String>,
+    count_of_issues: Option<i32>,
+    site_detail_url: Option<String>,
+    publisher: Option<CvReference>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvDeath {
+    date: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct CvPerson {
+    id: i32,
+    name: String,
+    gender: Option<i32>,
+    deck: Option<String>,
+    birth: Option<String>,
+    death: Option<CvDeath>,
+    image: Option<CvImage>,
+    aliases: Option<String>,
+    country: Option<String>,
+    website: Option<String>,
+    hometown: Option<String>,
+    description: Option<String>,
+    site_detail_url: Option<String>,
+    issue_credits: Option<Vec<CvReference>>,
+    volume_credits: Option<Vec<CvReference>>,
+}
+
+fn get_issue_title(issue: &CvIssue) -> String {
+    let volume = issue
+        .volume
+        .as_ref()
+        .and_then(|v| v.name.clone())
+        .unwrap_or_default();
+    let title = match &issue.issue_number {
+        Some(number) => format!("{} #{}", volume, number),
+        None => volume,
+    };
+    match issue.name.as_ref().filter(|n| !n.is_empty()) {
+        Some(name) if title.is_empty() => name.to_owned(),
+        Some(name) => format!("{}: {}", title, name),
+        None => title,
+    }
+}
+
+/// New issues are announced with the date they go on sale, while the cover date is
+/// usually a few months in the future.
+fn get_issue_publish_date(issue: &CvIssue) -> Option<String> {
+    issue
+        .store_date
+        .clone()
+        .or_else(|| issue.cover_date.clone())
+}
+
+fn get_image(image: &Option<CvImage>) -> Option<String> {
+    image.as_ref().and_then(|i| i.original_url.clone())
+}
+
+/// Dates of people are returned with a time component which is discarded.
+fn get_date(date: &str) -> Option<NaiveDate> {
+    date.get(..10).and_then(convert_string_to_date)
+}
+
+fn get_summary(deck: Option<String>, description: Option<String>) -> Option<String> {
+    description.or(deck).filter(|d| !d.is_empty())
+}
+
+pub struct ComicVineService {
+    api_key: String,
+    client: ClientWithMiddleware,
+}
+
+impl ComicVineService {
+    pub async fn new(config: &config::ComicVineConfig) -> Self {
+        let client = get_provider_http_client(MediaSource::ComicVine, None);
+        Self {
+            client,
+            api_key: config.api_key.clone(),
+        }
+    }
+}
+
+impl ComicVineService {
+    fn request(&self, path: &str, query: Value) -> RequestBuilder {
+        let mut query = query;
+        query["format"] = json!("json");
+        query["api_key"] = json!(self.api_key);
+        self.client.get(format!("{}/{}/", URL, path)).query(&query)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: Value) -> Result<(T, i32)> {
+        self.send(self.request(path, query)).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<(T, i32)> {
+        let data: CvResponse<T> = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        if data.status_code != 1 {
+            bail!("Comic Vine returned an error: {}", data.error);
+        }
+        Ok((
+            data.results,
+            data.number_of_total_results.unwrap_or_default(),
+        ))
+    }
+
+    async fn search<T: DeserializeOwned>(
+        &self,
+        resource: &str,
+        query: &str,
+        page: Option<i32>,
+    ) -> Result<(Vec<T>, SearchDetails)> {
+        let page = page.unwrap_or(1);
+        let (items, total) = self
+            .send(
+                self.request(
+                    "search",
+                    json!({
+                        "page": page,
+                        "query": query,
+                        "limit": PAGE_SIZE,
+                        "resources": resource,
+                    }),
+                )
+                .with_extension(CacheableProviderRequest),
+            )
+            .await?;
+        let next_page = (page * PAGE_SIZE < total).then_some(page + 1);
+        Ok((items, SearchDetails { total, next_page }))
+    }
+
+    async fn issue_list(&self, filter: String) -> Result<Vec<CvIssue>> {
+        let mut issues = vec![];
+        loop {
+            let (items, total): (Vec<CvIssue>, i32) = self
+                .get(
+                    "issues",
+                    json!({
+                        "filter": filter,
+                        "limit": LIST_LIMIT,
+                        "sort": "cover_date:asc",
+                        "field_list": ISSUE_FIELDS,
+                        "offset": issues.len(),
+                    }),
+                )
+                .await?;
+            let is_empty = items.is_empty();
+            issues.extend(items);
+            if is_empty || issues.len() >= total.try_into().unwrap_or_default() {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    fn partial_issue(&self, issue: CvIssue) -> PartialMetadataWithoutId {
+        PartialMetadataWithoutId {
+            lot: MediaLot::Comic,
+            title: get_issue_title(&issue),
+            image: get_image(&issue.image),
+            identifier: issue.id.to_string(),
+            source: MediaSource::ComicVine,
+            ..Default::default()
+        }
+    }
+
+    fn partial_volume(&self, volume: CvReference) -> MetadataGroupWithoutId {
+        MetadataGroupWithoutId {
+            lot: MediaLot::Comic,
+            identifier: volume.id.to_string(),
+            source: MediaSource::ComicVine,
+            title: volume.name.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl MediaProvider for ComicVineService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (items, details) = self.search::<CvIssue>("issue", query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|i| MetadataSearchItem {
+                title: get_issue_title(&i),
+                image: get_image(&i.image),
+                identifier: i.id.to_string(),
+                publish_year: get_issue_publish_date(&i).and_then(|d| convert_date_to_year(&d)),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let (data, _): (CvIssue, _) = self
+            .get(&format!("issue/{}-{}", ISSUE_PREFIX, identifier), json!({}))
+            .await?;
+        let volume = match &data.volume {
+            Some(v) => {
+                let (volume, _): (CvVolume, _) = self
+                    .get(
+                        &format!("volume/{}-{}", VOLUME_PREFIX, v.id),
+                        json!({ "field_list": "id,name,publisher" }),
+                    )
+                    .await?;
+                Some(volume)
+            }
+            None => None,
+        };
+        let people = data
+            .person_credits
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|c| {
+                let roles = c.role.clone().unwrap_or_else(|| "Creator".to_owned());
+                roles
+                    .split(',')
+                    .map(|r| PartialMetadataPerson {
+                        name: c.name.clone(),
+                        identifier: c.id.to_string(),
+                        source: MediaSource::ComicVine,
+                        role: r.trim().to_case(Case::Title),
+                        ..Default::default()
+                    })
+                    .collect_vec()
+            })
+            .unique()
+            .collect();
+        let publish_date = get_issue_publish_date(&data);
+        Ok(MetadataDetails {
+            people,
+            lot: MediaLot::Comic,
+            title: get_issue_title(&data),
+            identifier: data.id.to_string(),
+            source: MediaSource::ComicVine,
+            source_url: data.site_detail_url.clone(),
+            publish_year: publish_date.as_ref().and_then(|d| convert_date_to_year(d)),
+            publish_date: publish_date.and_then(|d| convert_string_to_date(&d)),
+            url_images: get_image(&data.image)
+                .map(|image| MetadataImageForMediaDetails { image })
+                .into_iter()
+                .collect(),
+            comic_specifics: Some(ComicSpecifics {
+                issue_number: data.issue_number.clone(),
+                volume: volume.as_ref().map(|v| v.name.clone()),
+                publisher: volume
+                    .as_ref()
+                    .and_then(|v| v.publisher.clone())
+                    .and_then(|p| p.name),
+            }),
+            groups: volume
+                .into_iter()
+                .map(|v| CommitMediaInput {
+                    name: v.name,
+                    unique: UniqueMediaIdentifier {
+                        lot: MediaLot::Comic,
+                        identifier: v.id.to_string(),
+                        source: MediaSource::ComicVine,
+                    },
+                })
+                .collect(),
+            description: get_summary(data.deck, data.description),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<MetadataGroupSearchResponse> {
+        let (items, details) = self.search::<CvVolume>("volume", query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|v| MetadataGroupSearchItem {
+                name: v.name,
+                image: get_image(&v.image),
+                identifier: v.id.to_string(),
+                parts: v.count_of_issues.and_then(|c| c.try_into().ok()),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let (data, _): (CvVolume, _) = self
+            .get(
+                &format!("volume/{}-{}", VOLUME_PREFIX, identifier),
+                json!({}),
+            )
+            .await?;
+        let items = self
+            .issue_list(format!("volume:{}", data.id))
+            .await?
+            .into_iter()
+            .map(|i| self.partial_issue(i))
+            .collect_vec();
+        let group = MetadataGroupWithoutId {
+            lot: MediaLot::Comic,
+            title: data.name,
+            identifier: data.id.to_string(),
+            source: MediaSource::ComicVine,
+            source_url: data.site_detail_url,
+            parts: items.len().try_into().unwrap(),
+            description: get_summary(data.deck, data.description),
+            images: get_image(&data.image).map(|url| {
+                vec![MetadataImage {
+                    url: StoredUrl::Url(url),
+                }]
+            }),
+            ..Default::default()
+        };
+        Ok((group, items))
+    }
+
+    async fn people_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+        _display_nsfw: bool,
+    ) -> Result<PeopleSearchResponse> {
+        let (items, details) = self.search::<CvPerson>("person", query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|p| PeopleSearchItem {
+                name: p.name,
+                image: get_image(&p.image),
+                identifier: p.id.to_string(),
+                birth_year: p.birth.and_then(|b| get_date(&b)).map(|d| d.year()),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let (data, _): (CvPerson, _) = self
+            .get(
+                &format!("person/{}-{}", PERSON_PREFIX, identifier),
+                json!({}),
+            )
+            .await?;
+        // Issue credits only contain the identifiers, so the details of the most recent
+        // ones are fetched in a single request.
+        let issue_ids = data
+            .issue_credits
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .take(LIST_LIMIT.try_into().unwrap())
+            .map(|i| i.id.to_string())
+            .collect_vec();
+        let related_metadata = match issue_ids.is_empty() {
+            true => vec![],
+            false => self
+                .issue_list(format!("id:{}", issue_ids.join("|")))
+                .await?
+                .into_iter()
+                .map(|i| MetadataPersonRelated {
+                    role: "Creator".to_owned(),
+                    metadata: self.partial_issue(i),
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let related_metadata_groups = data
+            .volume_credits
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| MetadataGroupPersonRelated {
+                role: "Creator".to_owned(),
+                metadata_group: self.partial_volume(v),
+            })
+            .collect();
+        Ok(PersonDetails {
+            related_metadata,
+            related_metadata_groups,
+            name: data.name,
+            website: data.website,
+            identifier: data.id.to_string(),
+            source: MediaSource::ComicVine,
+            source_url: data.site_detail_url,
+            source_specifics: source_specifics.to_owned(),
+            place: data.hometown.or(data.country),
+            images: get_image(&data.image).map(|i| vec![i]),
+            description: get_summary(data.deck, data.description),
+            birth_date: data.birth.and_then(|b| get_date(&b)),
+            death_date: data.death.and_then(|d| d.date).and_then(|d| get_date(&d)),
+            gender: match data.gender {
+                Some(1) => Some("Male".to_owned()),
+                Some(2) => Some("Female".to_owned()),
+                _ => None,
+            },
+            alternate_names: data
+                .aliases
+                .map(|a| {
+                    a.lines()
+                        .map(|l| l.trim().to_owned())
+                        .filter(|l| !l.is_empty())
+                        .unique()
+                        .collect_vec()
+                })
+                .filter(|a| !a.is_empty()),
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use common_models::SearchDetails;
 use common_utils::{convert_date_to_year, PAGE_SIZE};
@@ -8,13 +8,11 @@ use dependent_models::SearchResults;
 use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
-    BookSpecifics, MetadataDetails, MetadataFreeCreator, MetadataImageForMediaDetails,
-    MetadataSearchItem,
-};
-use reqwest::{
-    header::{HeaderName, HeaderValue},
-    Client,
+    BookSpecifics, MetadataDetails, MetadataExternalIdentifiers, MetadataFreeCreator,
+    MetadataImageForMediaDetails, MetadataSearchItem,
 };
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use traits::MediaProvider;
@@ -23,16 +21,19 @@ static URL: &str = "https://www.googleapis.com/books/v1/volumes";
 
 #[derive(Debug, Clone)]
 pub struct GoogleBooksService {
-    client: Client,
+    client: ClientWithMiddleware,
     pass_raw_query: bool,
 }
 
 impl GoogleBooksService {
     pub async fn new(config: &config::GoogleBooksConfig) -> Self {
-        let client = get_base_http_client(Some(vec![(
-            HeaderName::from_static("x-goog-api-key"),
-            HeaderValue::from_str(&config.api_key).unwrap(),
-        )]));
+        let client = get_provider_http_client(
+            MediaSource::GoogleBooks,
+            Some(vec![(
+                HeaderName::from_static("x-goog-api-key"),
+                HeaderValue::from_str(&config.api_key).unwrap(),
+            )]),
+        );
         Self {
             client,
             pass_raw_query: config.pass_raw_query,
@@ -51,6 +52,13 @@ struct ImageLinks {
     thumbnail: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IndustryIdentifier {
+    r#type: String,
+    identifier: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct ItemVolumeInfo {
@@ -64,6 +72,7 @@ struct ItemVolumeInfo {
     main_category: Option<String>,
     categories: Option<Vec<String>>,
     page_count: Option<i32>,
+    industry_identifiers: Option<Vec<IndustryIdentifier>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -105,6 +114,7 @@ impl MediaProvider for GoogleBooksService {
         let rsp = self
             .client
             .get(URL)
+            .with_extension(CacheableProviderRequest)
             .query(&serde_json::json!({
                 "q": match self.pass_raw_query {
                     true => query.to_owned(),
@@ -210,6 +220,13 @@ impl GoogleBooksService {
         if let Some(g) = item.main_category {
             genres.push(g);
         }
+        let isbns = item
+            .industry_identifiers
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|i| i.r#type.starts_with("ISBN"))
+            .map(|i| i.identifier)
+            .collect_vec();
         MetadataDetails {
             lot: MediaLot::Book,
             identifier: id.clone(),
@@ -229,6 +246,10 @@ impl GoogleBooksService {
                 "https://www.google.co.in/books/edition/{}/{}",
                 item.title, id
             )),
+            external_identifiers: Some(MetadataExternalIdentifiers {
+                isbns,
+                ..Default::default()
+            }),
             ..Default::default()
         }
     }
@@ -238,6 +259,7 @@ impl GoogleBooksService {
         let resp = self
             .client
             .get(URL)
+            .with_extension(CacheableProviderRequest)
             .query(&serde_json::json!({ "q": format!("isbn:{}", isbn) }))
             .send()
             .await
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use common_models::{PersonSourceSpecifics, SearchDetails};
@@ -10,16 +10,15 @@ use dependent_models::{
     SearchResults,
 };
 use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
 use media_models::{
-    BookSpecifics, CommitMediaInput, MetadataDetails, MetadataGroupSearchItem,
-    MetadataImageForMediaDetails, MetadataSearchItem, PartialMetadataPerson,
-    PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
+    BookSpecifics, CommitMediaInput, MetadataDetails, MetadataExternalIdentifiers,
+    MetadataGroupSearchItem, MetadataImageForMediaDetails, MetadataSearchItem,
+    PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
 };
 use nest_struct::nest_struct;
-use reqwest::{
-    header::{HeaderValue, AUTHORIZATION},
-    Client,
-};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use traits::MediaProvider;
@@ -90,7 +89,7 @@ struct Item<TId> {
     release_date: Option<NaiveDate>,
     images: Option<Vec<ImageOrLink>>,
     alternate_names: Option<Vec<String>>,
-    editions: Option<Vec<nest! { book: Option<Item<TId>> }>>,
+    editions: Option<Vec<nest! { book: Option<Item<TId>>, isbn_13: Option<String> }>>,
     recommendations: Option<Vec<nest! { item_book: Option<Item<TId>> }>>,
     cached_tags: Option<
         nest! {
@@ -128,7 +127,7 @@ async fn get_search_response(
     query: &str,
     page: i32,
     query_type: &str,
-    client: &Client,
+    client: &ClientWithMiddleware,
 ) -> Result<SearchSearchResults> {
     let body = format!(
         r#"
@@ -144,6 +143,7 @@ query {{
     );
     let data = client
         .post(URL)
+        .with_extension(CacheableProviderRequest)
         .json(&serde_json::json!({"query": body}))
         .send()
         .await?
@@ -162,15 +162,18 @@ fn query_type_from_specifics(source_specifics: &Option<PersonSourceSpecifics>) -
 }
 
 pub struct HardcoverService {
-    client: Client,
+    client: ClientWithMiddleware,
 }
 
 impl HardcoverService {
     pub async fn new(config: &config::HardcoverConfig) -> Self {
-        let client = get_base_http_client(Some(vec![(
-            AUTHORIZATION,
-            HeaderValue::from_str(&config.api_key).unwrap(),
-        )]));
+        let client = get_provider_http_client(
+            MediaSource::Hardcover,
+            Some(vec![(
+                AUTHORIZATION,
+                HeaderValue::from_str(&config.api_key).unwrap(),
+            )]),
+        );
         Self { client }
     }
 }
@@ -195,6 +198,7 @@ query {{
     image {{ url }}
     images {{ url }}
     book_series {{ series {{ id name }} }}
+    editions(where: {{ isbn_13: {{ _is_null: false }} }}) {{ isbn_13 }}
     contributions {{ contribution author_id author {{ name }} }}
     recommendations(
       where: {{
@@ -241,6 +245,16 @@ query {{
                 pages: data.pages,
                 is_compilation: data.compilation,
             }),
+            external_identifiers: Some(MetadataExternalIdentifiers {
+                isbns: data
+                    .editions
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|e| e.isbn_13)
+                    .unique()
+                    .collect(),
+                ..Default::default()
+            }),
             source_url: data
                 .slug
                 .map(|s| format!("https://hardcover.app/books/{s}")),
@@ -626,6 +640,7 @@ impl HardcoverService {
             let rsp = self
                 .client
                 .post(URL)
+                .with_extension(CacheableProviderRequest)
                 .json(&serde_json::json!({ "query": body }))
                 .send()
                 .await
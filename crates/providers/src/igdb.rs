@@ -1,7 +1,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::Datelike;
 use common_models::{
@@ -24,6 +24,7 @@ use reqwest::{
     header::{HeaderName, HeaderValue, AUTHORIZATION},
     Client,
 };
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use rust_iso3166::from_numeric;
 use sea_orm::prelude::DateTimeUtc;
@@ -36,6 +37,10 @@ use traits::MediaProvider;
 static URL: &str = "https://api.igdb.com/v4";
 static IMAGE_URL: &str = "https://images.igdb.com/igdb/image/upload";
 static AUTH_URL: &str = "https://id.twitch.tv/oauth2/token";
+/// The category of external games which are on Steam.
+const STEAM_EXTERNAL_GAME_CATEGORY: i32 = 1;
+/// The maximum number of records IGDB returns for a single request.
+const MAX_LIMIT: usize = 500;
 
 static GAME_FIELDS: &str = "
 fields
@@ -198,6 +203,7 @@ offset: {offset};
         );
         let rsp = client
             .post(format!("{}/collections", URL))
+            .with_extension(CacheableProviderRequest)
             .body(req_body)
             .send()
             .await
@@ -301,6 +307,7 @@ offset: {offset};
         );
         let rsp = client
             .post(format!("{}/companies", URL))
+            .with_extension(CacheableProviderRequest)
             .body(req_body)
             .send()
             .await
@@ -456,6 +463,7 @@ where id = {id};
             format!(r#"fields id; where version_parent = null; search "{query}"; limit: 500;"#);
         let rsp = client
             .post(format!("{}/games", URL))
+            .with_extension(CacheableProviderRequest)
             .body(count_req_body)
             .send()
             .await
@@ -478,6 +486,7 @@ offset: {offset};
         );
         let rsp = client
             .post(format!("{}/games", URL))
+            .with_extension(CacheableProviderRequest)
             .body(req_body)
             .send()
             .await
@@ -509,6 +518,41 @@ offset: {offset};
 }
 
 impl IgdbService {
+    /// Find the IGDB identifiers of the games with the given Steam app IDs. Games
+    /// which could not be found are not present in the result.
+    pub async fn get_steam_game_ids(&self, app_ids: &[i64]) -> Result<HashMap<i64, String>> {
+        #[derive(Deserialize, Serialize, Debug)]
+        struct ExternalGame {
+            uid: String,
+            game: Option<i64>,
+        }
+        let client = self.get_client_config().await?;
+        let mut ids = HashMap::new();
+        for chunk in app_ids.chunks(MAX_LIMIT) {
+            let req_body = format!(
+                r#"fields game, uid; where category = {category} & uid = ({uids}); limit {limit};"#,
+                category = STEAM_EXTERNAL_GAME_CATEGORY,
+                uids = chunk.iter().map(|id| format!(r#""{}""#, id)).join(","),
+                limit = MAX_LIMIT
+            );
+            let games: Vec<ExternalGame> = client
+                .post(format!("{}/external_games", URL))
+                .with_extension(CacheableProviderRequest)
+                .body(req_body)
+                .send()
+                .await
+                .map_err(|e| anyhow!(e))?
+                .json()
+                .await
+                .map_err(|e| anyhow!(e))?;
+            ids.extend(games.into_iter().filter_map(|g| {
+                let app_id = g.uid.parse().ok()?;
+                Some((app_id, g.game?.to_string()))
+            }));
+        }
+        Ok(ids)
+    }
+
     async fn get_access_token(&self) -> String {
         let client = Client::new();
         #[derive(Deserialize, Serialize, Default, Debug)]
@@ -534,7 +578,7 @@ impl IgdbService {
         format!("{} {}", access.token_type, access.access_token)
     }
 
-    async fn get_client_config(&self) -> Result<Client> {
+    async fn get_client_config(&self) -> Result<ClientWithMiddleware> {
         let cc = &self.supporting_service.cache_service;
         let maybe_settings = cc
             .get_value::<IgdbSettings>(ApplicationCacheKey::IgdbSettings)
@@ -551,14 +595,19 @@ impl IgdbService {
             .ok();
             access_token
         };
-        Ok(get_base_http_client(Some(vec![
-            (
-                HeaderName::from_static("client-id"),
-                HeaderValue::from_str(&self.supporting_service.config.video_games.twitch.client_id)
+        Ok(get_provider_http_client(
+            MediaSource::Igdb,
+            Some(vec![
+                (
+                    HeaderName::from_static("client-id"),
+                    HeaderValue::from_str(
+                        &self.supporting_service.config.video_games.twitch.client_id,
+                    )
                     .unwrap(),
-            ),
-            (AUTHORIZATION, HeaderValue::from_str(&access_token).unwrap()),
-        ])))
+                ),
+                (AUTHORIZATION, HeaderValue::from_str(&access_token).unwrap()),
+            ]),
+        ))
     }
 
     fn igdb_response_to_search_response(&self, item: IgdbItemResponse) -> MetadataDetails {
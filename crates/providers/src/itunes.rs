@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::Datelike;
 use common_models::{NamedObject, SearchDetails};
@@ -11,7 +11,7 @@ use media_models::{
     MetadataDetails, MetadataFreeCreator, MetadataImageForMediaDetails, MetadataSearchItem,
     PodcastEpisode, PodcastSpecifics,
 };
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use sea_orm::prelude::ChronoDateTimeUtc;
 use serde::{Deserialize, Serialize};
 use traits::MediaProvider;
@@ -20,13 +20,13 @@ static URL: &str = "https://itunes.apple.com";
 
 #[derive(Debug, Clone)]
 pub struct ITunesService {
-    client: Client,
+    client: ClientWithMiddleware,
     language: String,
 }
 
 impl ITunesService {
     pub async fn new(config: &config::ITunesConfig) -> Self {
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::Itunes, None);
         Self {
             client,
             language: config.locale.clone(),
@@ -176,6 +176,7 @@ impl MediaProvider for ITunesService {
         let rsp = self
             .client
             .get(format!("{}/search", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&serde_json::json!({
                 "term": query,
                 "media": "podcast",
@@ -1,5 +1,7 @@
 pub mod anilist;
 pub mod audible;
+pub mod board_game_geek;
+pub mod comic_vine;
 pub mod google_books;
 pub mod hardcover;
 pub mod igdb;
@@ -7,7 +9,14 @@ pub mod itunes;
 pub mod listennotes;
 pub mod mal;
 pub mod manga_updates;
+pub mod music_brainz;
 pub mod openlibrary;
+pub mod podcast_index;
+pub mod rawg;
+pub mod remote;
+pub mod rss;
 pub mod tmdb;
+pub mod tvdb;
 pub mod vndb;
+pub mod web_page;
 pub mod youtube_music;
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, env, sync::Arc};
 
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::Datelike;
 use common_models::{ApplicationCacheKey, SearchDetails};
@@ -13,10 +13,8 @@ use media_models::{
     MetadataDetails, MetadataFreeCreator, MetadataImageForMediaDetails, MetadataSearchItem,
     PartialMetadataWithoutId, PodcastEpisode, PodcastSpecifics,
 };
-use reqwest::{
-    header::{HeaderName, HeaderValue},
-    Client,
-};
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
@@ -29,7 +27,7 @@ static URL: &str = "https://listen-api.listennotes.com/api/v2";
 
 pub struct ListennotesService {
     url: String,
-    client: Client,
+    client: ClientWithMiddleware,
     supporting_service: Arc<SupportingService>,
 }
 
@@ -39,10 +37,13 @@ impl ListennotesService {
             .unwrap_or_else(|_| URL.to_owned())
             .as_str()
             .to_owned();
-        let client = get_base_http_client(Some(vec![(
-            HeaderName::from_static("x-listenapi-key"),
-            HeaderValue::from_str(&ss.config.podcasts.listennotes.api_token).unwrap(),
-        )]));
+        let client = get_provider_http_client(
+            MediaSource::Listennotes,
+            Some(vec![(
+                HeaderName::from_static("x-listenapi-key"),
+                HeaderValue::from_str(&ss.config.podcasts.listennotes.api_token).unwrap(),
+            )]),
+        );
         Self {
             url,
             client,
@@ -143,6 +144,7 @@ impl MediaProvider for ListennotesService {
         let rsp = self
             .client
             .get(format!("{}/search", self.url))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "type": "podcast",
                 "q": query.to_owned(),
@@ -195,6 +197,7 @@ impl ListennotesService {
             let rsp = self
                 .client
                 .get(format!("{}/genres", self.url))
+                .with_extension(CacheableProviderRequest)
                 .send()
                 .await
                 .unwrap();
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use common_models::{NamedObject, SearchDetails};
 use common_utils::{convert_date_to_year, convert_string_to_date, PAGE_SIZE};
@@ -11,10 +11,8 @@ use media_models::{
     MetadataSearchItem, PartialMetadataWithoutId,
 };
 use rand::{rng, seq::SliceRandom};
-use reqwest::{
-    header::{HeaderName, HeaderValue},
-    Client,
-};
+use reqwest::header::{HeaderName, HeaderValue};
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -24,7 +22,7 @@ static URL: &str = "https://api.myanimelist.net/v2";
 
 #[derive(Debug, Clone)]
 pub struct MalService {
-    client: Client,
+    client: ClientWithMiddleware,
 }
 
 #[derive(Debug, Clone)]
@@ -109,15 +107,18 @@ impl MediaProvider for MalMangaService {
     }
 }
 
-async fn get_client_config(client_id: &str) -> Client {
-    get_base_http_client(Some(vec![(
-        HeaderName::from_static("x-mal-client-id"),
-        HeaderValue::from_str(client_id).unwrap(),
-    )]))
+async fn get_client_config(client_id: &str) -> ClientWithMiddleware {
+    get_provider_http_client(
+        MediaSource::Mal,
+        Some(vec![(
+            HeaderName::from_static("x-mal-client-id"),
+            HeaderValue::from_str(client_id).unwrap(),
+        )]),
+    )
 }
 
 async fn search(
-    client: &Client,
+    client: &ClientWithMiddleware,
     media_type: &str,
     q: &str,
     page: Option<i32>,
@@ -135,6 +136,7 @@ async fn search(
     }
     let search: SearchResponse = client
         .get(format!("{}/{}", URL, media_type))
+        .with_extension(CacheableProviderRequest)
         .query(&json!({ "q": q, "limit": PAGE_SIZE, "offset": offset, "fields": "start_date" }))
         .send()
         .await
@@ -185,7 +187,11 @@ struct ItemData {
     node: ItemNode,
 }
 
-async fn details(client: &Client, media_type: &str, id: &str) -> Result<MetadataDetails> {
+async fn details(
+    client: &ClientWithMiddleware,
+    media_type: &str,
+    id: &str,
+) -> Result<MetadataDetails> {
     let details: ItemNode = client
         .get(format!("{}/{}/{}", URL, media_type, id))
         .query(&json!({ "fields": "start_date,end_date,synopsis,genres,status,num_episodes,num_volumes,num_chapters,recommendations,related_manga,related_anime,mean,nsfw" }))
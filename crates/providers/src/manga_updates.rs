@@ -1,19 +1,17 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use common_models::{PersonSourceSpecifics, SearchDetails};
 use common_utils::PAGE_SIZE;
-use dependent_models::{
-    PersonDetails, MetadataPersonRelated, PeopleSearchResponse, SearchResults,
-};
+use dependent_models::{MetadataPersonRelated, PeopleSearchResponse, PersonDetails, SearchResults};
 use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
     MangaSpecifics, MetadataDetails, MetadataImageForMediaDetails, MetadataSearchItem,
     PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
 };
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use traits::MediaProvider;
@@ -22,12 +20,12 @@ static URL: &str = "https://api.mangaupdates.com/v1";
 
 #[derive(Debug, Clone)]
 pub struct MangaUpdatesService {
-    client: Client,
+    client: ClientWithMiddleware,
 }
 
 impl MangaUpdatesService {
     pub async fn new(_config: &config::MangaUpdatesConfig) -> Self {
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::MangaUpdates, None);
         Self { client }
     }
 }
@@ -167,6 +165,7 @@ impl MediaProvider for MangaUpdatesService {
         let data: MetadataSearchResponse<PersonItemResponse> = self
             .client
             .post(format!("{}/authors/search", URL))
+            .with_extension(CacheableProviderRequest)
             .json(&serde_json::json!({
                 "search": query,
                 "perpage": PAGE_SIZE,
@@ -364,6 +363,7 @@ impl MediaProvider for MangaUpdatesService {
         let search: MetadataSearchResponse<MetadataItemResponse> = self
             .client
             .post(format!("{}/series/search", URL))
+            .with_extension(CacheableProviderRequest)
             .json(&serde_json::json!({
                 "search": query,
                 "perpage": PAGE_SIZE,
@@ -0,0 +1,564 @@
+use anyhow::{anyhow, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use common_models::{NamedObject, PersonSourceSpecifics, SearchDetails, StoredUrl};
+use common_utils::{convert_string_to_date, APPLICATION_JSON_HEADER, PAGE_SIZE};
+use convert_case::{Case, Casing};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupPersonRelated, MetadataGroupSearchResponse, MetadataPersonRelated,
+    PeopleSearchResponse, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    CommitMediaInput, MetadataDetails, MetadataGroupSearchItem, MetadataImage,
+    MetadataImageForMediaDetails, MetadataSearchItem, MusicSpecifics, PartialMetadataPerson,
+    PartialMetadataWithoutId, PeopleSearchItem, UniqueMediaIdentifier,
+};
+use reqwest::header::ACCEPT;
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use traits::MediaProvider;
+
+static URL: &str = "https://musicbrainz.org/ws/2";
+static SITE_URL: &str = "https://musicbrainz.org";
+static COVER_ART_URL: &str = "https://coverartarchive.org";
+static VARIOUS_ARTISTS_ID: &str = "89ad4ac3-39f7-470e-963a-56509c546377";
+/// The maximum number of entities MusicBrainz returns when browsing.
+const BROWSE_LIMIT: i32 = 100;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbEntity {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbArtistCredit {
+    artist: MbEntity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbUrl {
+    resource: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbRelation {
+    r#type: String,
+    url: Option<MbUrl>,
+    artist: Option<MbEntity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct MbReleaseGroup {
+    id: String,
+    #[serde(default)]
+    title: String,
+    disambiguation: Option<String>,
+    releases: Option<Vec<MbRelease>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbTrack {
+    recording: MbRecording,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbMedium {
+    tracks: Option<Vec<MbTrack>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbLabelInfo {
+    label: Option<MbEntity>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct MbRelease {
+    id: String,
+    date: Option<String>,
+    status: Option<String>,
+    media: Option<Vec<MbMedium>>,
+    label_info: Option<Vec<MbLabelInfo>>,
+    release_group: Option<MbReleaseGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct MbRecording {
+    id: String,
+    title: String,
+    length: Option<i32>,
+    first_release_date: Option<String>,
+    genres: Option<Vec<NamedObject>>,
+    releases: Option<Vec<MbRelease>>,
+    relations: Option<Vec<MbRelation>>,
+    artist_credit: Option<Vec<MbArtistCredit>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbArea {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct MbLifeSpan {
+    end: Option<String>,
+    begin: Option<String>,
+}
+
+/// Artists and labels share most of their fields, so they are deserialized into the
+/// same struct.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "kebab-case")]
+struct MbPerson {
+    id: String,
+    name: String,
+    gender: Option<String>,
+    area: Option<MbArea>,
+    begin_area: Option<MbArea>,
+    disambiguation: Option<String>,
+    life_span: Option<MbLifeSpan>,
+    aliases: Option<Vec<NamedObject>>,
+    relations: Option<Vec<MbRelation>>,
+    release_groups: Option<Vec<MbReleaseGroup>>,
+}
+
+fn get_release_group_image(id: &str) -> String {
+    format!("{}/release-group/{}/front-500", COVER_ART_URL, id)
+}
+
+fn get_year(date: &Option<String>) -> Option<i32> {
+    date.as_ref()
+        .and_then(|d| d.get(..4))
+        .and_then(|y| y.parse().ok())
+}
+
+fn is_label(source_specifics: &Option<PersonSourceSpecifics>) -> bool {
+    source_specifics
+        .as_ref()
+        .and_then(|s| s.is_music_brainz_label)
+        .unwrap_or(false)
+}
+
+fn release_groups_of(releases: &Option<Vec<MbRelease>>) -> Vec<MbReleaseGroup> {
+    releases
+        .iter()
+        .flatten()
+        .filter_map(|r| r.release_group.clone())
+        .unique_by(|g| g.id.clone())
+        .collect()
+}
+
+pub struct MusicBrainzService {
+    client: ClientWithMiddleware,
+}
+
+impl MusicBrainzService {
+    pub async fn new() -> Self {
+        let client = get_provider_http_client(
+            MediaSource::MusicBrainz,
+            Some(vec![(ACCEPT, APPLICATION_JSON_HEADER.clone())]),
+        );
+        Self { client }
+    }
+}
+
+impl MusicBrainzService {
+    fn request(&self, path: &str, query: Value) -> RequestBuilder {
+        self.client.get(format!("{}/{}", URL, path)).query(&query)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: Value) -> Result<T> {
+        self.send(self.request(path, query)).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let data = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        Ok(data)
+    }
+
+    async fn search<T: DeserializeOwned>(
+        &self,
+        entity: &str,
+        query: &str,
+        page: Option<i32>,
+    ) -> Result<(Vec<T>, SearchDetails)> {
+        let page = page.unwrap_or(1);
+        let mut data: Value = self
+            .send(
+                self.request(
+                    entity,
+                    json!({
+                        "query": query,
+                        "limit": PAGE_SIZE,
+                        "offset": (page - 1) * PAGE_SIZE,
+                    }),
+                )
+                .with_extension(CacheableProviderRequest),
+            )
+            .await?;
+        let total = data["count"].as_i64().unwrap_or_default().try_into()?;
+        let items = serde_json::from_value(data[format!("{}s", entity).as_str()].take())?;
+        let next_page = (page * PAGE_SIZE < total).then_some(page + 1);
+        Ok((items, SearchDetails { total, next_page }))
+    }
+
+    async fn browse<T: DeserializeOwned>(&self, entity: &str, query: Value) -> Result<Vec<T>> {
+        let mut query = query;
+        query["limit"] = json!(BROWSE_LIMIT);
+        let mut data: Value = self.get(entity, query).await?;
+        let items = serde_json::from_value(data[format!("{}s", entity).as_str()].take())?;
+        Ok(items)
+    }
+
+    fn partial_recording(&self, recording: MbRecording) -> PartialMetadataWithoutId {
+        let image = release_groups_of(&recording.releases)
+            .first()
+            .map(|g| get_release_group_image(&g.id));
+        PartialMetadataWithoutId {
+            image,
+            lot: MediaLot::Music,
+            title: recording.title,
+            identifier: recording.id,
+            source: MediaSource::MusicBrainz,
+            ..Default::default()
+        }
+    }
+
+    fn partial_release_group(&self, group: MbReleaseGroup) -> MetadataGroupWithoutId {
+        MetadataGroupWithoutId {
+            lot: MediaLot::Music,
+            title: group.title,
+            source: MediaSource::MusicBrainz,
+            source_url: Some(format!("{}/release-group/{}", SITE_URL, group.id)),
+            images: Some(vec![MetadataImage {
+                url: StoredUrl::Url(get_release_group_image(&group.id)),
+            }]),
+            identifier: group.id,
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl MediaProvider for MusicBrainzService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (items, details) = self.search::<MbRecording>("recording", query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|r| MetadataSearchItem {
+                publish_year: get_year(&r.first_release_date),
+                image: release_groups_of(&r.releases)
+                    .first()
+                    .map(|g| get_release_group_image(&g.id)),
+                title: r.title,
+                identifier: r.id,
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let data: MbRecording = self
+            .get(
+                &format!("recording/{}", identifier),
+                json!({ "inc": "artist-credits+releases+release-groups+genres+artist-rels" }),
+            )
+            .await?;
+        let release_groups = release_groups_of(&data.releases);
+        let artists = data.artist_credit.unwrap_or_default();
+        let mut people = artists
+            .iter()
+            .map(|a| PartialMetadataPerson {
+                name: a.artist.name.clone(),
+                role: "Artist".to_owned(),
+                identifier: a.artist.id.clone(),
+                source: MediaSource::MusicBrainz,
+                ..Default::default()
+            })
+            .collect_vec();
+        people.extend(
+            data.relations
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|r| {
+                    r.artist.map(|a| PartialMetadataPerson {
+                        name: a.name,
+                        identifier: a.id,
+                        source: MediaSource::MusicBrainz,
+                        role: r.r#type.to_case(Case::Title),
+                        ..Default::default()
+                    })
+                }),
+        );
+        let release = data
+            .releases
+            .iter()
+            .flatten()
+            .find(|r| r.status.as_deref() == Some("Official"))
+            .or_else(|| data.releases.iter().flatten().next());
+        if let Some(release) = release {
+            let release: MbRelease = self
+                .get(
+                    &format!("release/{}", release.id),
+                    json!({ "inc": "labels" }),
+                )
+                .await?;
+            people.extend(
+                release
+                    .label_info
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|l| l.label)
+                    .map(|l| PartialMetadataPerson {
+                        name: l.name,
+                        identifier: l.id,
+                        role: "Label".to_owned(),
+                        source: MediaSource::MusicBrainz,
+                        source_specifics: Some(PersonSourceSpecifics {
+                            is_music_brainz_label: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+            );
+        }
+        Ok(MetadataDetails {
+            lot: MediaLot::Music,
+            title: data.title,
+            identifier: data.id.clone(),
+            source: MediaSource::MusicBrainz,
+            people: people.into_iter().unique().collect(),
+            publish_year: get_year(&data.first_release_date),
+            source_url: Some(format!("{}/recording/{}", SITE_URL, data.id)),
+            publish_date: data
+                .first_release_date
+                .and_then(|d| convert_string_to_date(&d)),
+            genres: data
+                .genres
+                .unwrap_or_default()
+                .into_iter()
+                .map(|g| g.name.to_case(Case::Title))
+                .unique()
+                .collect(),
+            url_images: release_groups
+                .first()
+                .map(|g| MetadataImageForMediaDetails {
+                    image: get_release_group_image(&g.id),
+                })
+                .into_iter()
+                .collect(),
+            music_specifics: Some(MusicSpecifics {
+                duration: data.length.map(|l| l / 1000),
+                by_various_artists: Some(artists.iter().any(|a| a.artist.id == VARIOUS_ARTISTS_ID)),
+                ..Default::default()
+            }),
+            groups: release_groups
+                .into_iter()
+                .map(|g| CommitMediaInput {
+                    name: g.title,
+                    unique: UniqueMediaIdentifier {
+                        identifier: g.id,
+                        lot: MediaLot::Music,
+                        source: MediaSource::MusicBrainz,
+                    },
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<MetadataGroupSearchResponse> {
+        let (items, details) = self
+            .search::<MbReleaseGroup>("release-group", query, page)
+            .await?;
+        let items = items
+            .into_iter()
+            .map(|g| MetadataGroupSearchItem {
+                image: Some(get_release_group_image(&g.id)),
+                name: g.title,
+                identifier: g.id,
+                parts: None,
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let data: MbReleaseGroup = self
+            .get(
+                &format!("release-group/{}", identifier),
+                json!({ "inc": "releases" }),
+            )
+            .await?;
+        let release = data
+            .releases
+            .iter()
+            .flatten()
+            .filter(|r| r.status.as_deref() == Some("Official"))
+            .min_by_key(|r| r.date.clone().filter(|d| !d.is_empty()))
+            .or_else(|| data.releases.iter().flatten().next());
+        let mut items = vec![];
+        if let Some(release) = release {
+            let release: MbRelease = self
+                .get(
+                    &format!("release/{}", release.id),
+                    json!({ "inc": "recordings" }),
+                )
+                .await?;
+            items.extend(
+                release
+                    .media
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flat_map(|m| m.tracks.unwrap_or_default())
+                    .map(|t| PartialMetadataWithoutId {
+                        lot: MediaLot::Music,
+                        title: t.recording.title,
+                        identifier: t.recording.id,
+                        source: MediaSource::MusicBrainz,
+                        image: Some(get_release_group_image(&data.id)),
+                        ..Default::default()
+                    })
+                    .unique_by(|p| p.identifier.clone()),
+            );
+        }
+        let description = data.disambiguation.clone().filter(|d| !d.is_empty());
+        let group = MetadataGroupWithoutId {
+            description,
+            parts: items.len().try_into().unwrap(),
+            ..self.partial_release_group(data)
+        };
+        Ok((group, items))
+    }
+
+    async fn people_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        source_specifics: &Option<PersonSourceSpecifics>,
+        _display_nsfw: bool,
+    ) -> Result<PeopleSearchResponse> {
+        let entity = if is_label(source_specifics) {
+            "label"
+        } else {
+            "artist"
+        };
+        let (items, details) = self.search::<MbPerson>(entity, query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|p| PeopleSearchItem {
+                name: p.name,
+                identifier: p.id,
+                birth_year: get_year(&p.life_span.and_then(|l| l.begin)),
+                ..Default::default()
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let is_label = is_label(source_specifics);
+        let (entity, inc) = if is_label {
+            ("label", "aliases+url-rels")
+        } else {
+            ("artist", "aliases+release-groups+url-rels")
+        };
+        let data: MbPerson = self
+            .get(&format!("{}/{}", entity, identifier), json!({ "inc": inc }))
+            .await?;
+        let (related_metadata, release_groups, role) = if is_label {
+            let releases: Vec<MbRelease> = self
+                .browse(
+                    "release",
+                    json!({ "label": identifier, "inc": "release-groups" }),
+                )
+                .await?;
+            (vec![], release_groups_of(&Some(releases)), "Label")
+        } else {
+            let recordings: Vec<MbRecording> = self
+                .browse("recording", json!({ "artist": identifier }))
+                .await?;
+            let related_metadata = recordings
+                .into_iter()
+                .map(|r| MetadataPersonRelated {
+                    role: "Artist".to_owned(),
+                    metadata: self.partial_recording(r),
+                    ..Default::default()
+                })
+                .collect();
+            (
+                related_metadata,
+                data.release_groups.clone().unwrap_or_default(),
+                "Artist",
+            )
+        };
+        let related_metadata_groups = release_groups
+            .into_iter()
+            .map(|g| MetadataGroupPersonRelated {
+                role: role.to_owned(),
+                metadata_group: self.partial_release_group(g),
+            })
+            .collect();
+        let life_span = data.life_span.unwrap_or(MbLifeSpan {
+            end: None,
+            begin: None,
+        });
+        Ok(PersonDetails {
+            related_metadata,
+            related_metadata_groups,
+            name: data.name,
+            gender: data.gender,
+            source: MediaSource::MusicBrainz,
+            identifier: data.id.clone(),
+            source_specifics: source_specifics.to_owned(),
+            place: data.begin_area.or(data.area).map(|a| a.name),
+            source_url: Some(format!("{}/{}/{}", SITE_URL, entity, data.id)),
+            description: data.disambiguation.filter(|d| !d.is_empty()),
+            birth_date: life_span.begin.and_then(|d| convert_string_to_date(&d)),
+            death_date: life_span.end.and_then(|d| convert_string_to_date(&d)),
+            website: data
+                .relations
+                .unwrap_or_default()
+                .into_iter()
+                .find(|r| r.r#type == "official homepage")
+                .and_then(|r| r.url)
+                .map(|u| u.resource),
+            alternate_names: data
+                .aliases
+                .map(|a| a.into_iter().map(|a| a.name).unique().collect_vec())
+                .filter(|a| !a.is_empty()),
+            ..Default::default()
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::{Datelike, NaiveDate};
 use common_models::{PersonSourceSpecifics, SearchDetails};
@@ -9,10 +9,10 @@ use dependent_models::{MetadataPersonRelated, PeopleSearchResponse, PersonDetail
 use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
-    BookSpecifics, MetadataDetails, MetadataImageForMediaDetails, MetadataSearchItem,
-    PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
+    BookSpecifics, MetadataDetails, MetadataExternalIdentifiers, MetadataImageForMediaDetails,
+    MetadataSearchItem, PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem,
 };
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -33,6 +33,8 @@ struct OpenlibraryEdition {
     publish_date: Option<String>,
     number_of_pages: Option<i32>,
     covers: Option<Vec<i64>>,
+    isbn_13: Option<Vec<String>>,
+    isbn_10: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -73,7 +75,7 @@ struct OpenlibraryKey {
 pub struct OpenlibraryService {
     image_url: String,
     image_size: String,
-    client: Client,
+    client: ClientWithMiddleware,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -91,7 +93,7 @@ struct OpenAuthorLibrarySearchResponse {
 
 impl OpenlibraryService {
     pub async fn new(config: &config::OpenlibraryConfig) -> Self {
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::Openlibrary, None);
         Self {
             image_url: IMAGE_BASE_URL.to_owned(),
             image_size: config.cover_image_size.to_string(),
@@ -168,6 +170,7 @@ impl MediaProvider for OpenlibraryService {
         let rsp = self
             .client
             .get(format!("{}/search/authors.json", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "q": query.to_owned(),
                 "offset": (page - 1) * PAGE_SIZE,
@@ -311,10 +314,22 @@ impl MediaProvider for OpenlibraryService {
             .filter_map(|f| f.number_of_pages)
             .collect_vec();
         let num_pages = if all_pages.is_empty() {
-            0
+            None
         } else {
-            all_pages.iter().sum::<i32>() / all_pages.len() as i32
+            Some(all_pages.iter().sum::<i32>() / all_pages.len() as i32)
         };
+        let isbns = entries
+            .iter()
+            .flat_map(|e| {
+                e.isbn_13
+                    .iter()
+                    .chain(e.isbn_10.iter())
+                    .flatten()
+                    .cloned()
+                    .collect_vec()
+            })
+            .unique()
+            .collect_vec();
         let first_release_date = entries
             .iter()
             .filter_map(|f| f.publish_date.clone())
@@ -453,7 +468,11 @@ impl MediaProvider for OpenlibraryService {
                 identifier, data.title
             )),
             book_specifics: Some(BookSpecifics {
-                pages: Some(num_pages),
+                pages: num_pages,
+                ..Default::default()
+            }),
+            external_identifiers: Some(MetadataExternalIdentifiers {
+                isbns,
                 ..Default::default()
             }),
             ..Default::default()
@@ -478,6 +497,7 @@ impl MediaProvider for OpenlibraryService {
         let rsp = self
             .client
             .get(format!("{}/search.json", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "q": query.to_owned(),
                 "fields": fields,
@@ -562,6 +582,7 @@ impl OpenlibraryService {
     pub async fn id_from_isbn(&self, isbn: &str) -> Option<String> {
         self.client
             .get(format!("{}/isbn/{}.json", URL, isbn))
+            .with_extension(CacheableProviderRequest)
             .send()
             .await
             .ok()?
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Utc};
+use common_models::SearchDetails;
+use common_utils::PAGE_SIZE;
+use data_encoding::HEXLOWER;
+use dependent_models::SearchResults;
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    MetadataDetails, MetadataFreeCreator, MetadataImageForMediaDetails, MetadataSearchItem,
+    PodcastEpisode, PodcastSpecifics,
+};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha1::{Digest, Sha1};
+use traits::MediaProvider;
+
+static URL: &str = "https://api.podcastindex.org/api/1.0";
+
+pub struct PodcastIndexService {
+    api_key: String,
+    api_secret: String,
+    client: ClientWithMiddleware,
+}
+
+impl PodcastIndexService {
+    pub async fn new(config: &config::PodcastIndexConfig) -> Self {
+        let client = get_provider_http_client(MediaSource::PodcastIndex, None);
+        Self {
+            client,
+            api_key: config.api_key.clone(),
+            api_secret: config.api_secret.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Feed {
+    id: i64,
+    title: String,
+    link: Option<String>,
+    image: Option<String>,
+    author: Option<String>,
+    artwork: Option<String>,
+    explicit: Option<bool>,
+    description: Option<String>,
+    categories: Option<HashMap<String, String>>,
+}
+
+impl Feed {
+    fn get_image(&self) -> Option<String> {
+        self.artwork
+            .clone()
+            .or_else(|| self.image.clone())
+            .filter(|i| !i.is_empty())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Episode {
+    id: i64,
+    title: String,
+    image: Option<String>,
+    duration: Option<i32>,
+    date_published: i64,
+    description: Option<String>,
+}
+
+impl PodcastIndexService {
+    // Every request needs to be signed with the current time, the key and the secret.
+    fn get(&self, path: &str) -> RequestBuilder {
+        let date = Utc::now().timestamp().to_string();
+        let hash = Sha1::digest(format!("{}{}{}", self.api_key, self.api_secret, date));
+        self.client
+            .get(format!("{}/{}", URL, path))
+            .header("X-Auth-Date", date)
+            .header("X-Auth-Key", &self.api_key)
+            .header("Authorization", HEXLOWER.encode(&hash))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for PodcastIndexService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct PodcastResponse {
+            feed: Feed,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct EpisodesResponse {
+            items: Vec<Episode>,
+        }
+        let podcast: PodcastResponse = self
+            .get("podcasts/byfeedid")
+            .query(&json!({ "id": identifier }))
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let episodes: EpisodesResponse = self
+            .get("episodes/byfeedid")
+            .query(&json!({ "id": identifier, "max": 1000, "fulltext": true }))
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let episodes = episodes
+            .items
+            .into_iter()
+            .filter_map(|e| {
+                let publish_date = DateTime::from_timestamp(e.date_published, 0)?.date_naive();
+                Some(PodcastEpisode {
+                    publish_date,
+                    title: e.title,
+                    overview: e.description,
+                    id: e.id.to_string(),
+                    runtime: e.duration.map(|d| d / 60), // the api responds in seconds
+                    thumbnail: e.image.filter(|i| !i.is_empty()),
+                    ..Default::default()
+                })
+            })
+            .sorted_by_key(|e| e.publish_date)
+            .enumerate()
+            .map(|(idx, episode)| PodcastEpisode {
+                number: idx as i32 + 1,
+                ..episode
+            })
+            .collect_vec();
+        let feed = podcast.feed;
+        let publish_date = episodes.first().map(|e| e.publish_date);
+        Ok(MetadataDetails {
+            publish_date,
+            lot: MediaLot::Podcast,
+            is_nsfw: feed.explicit,
+            url_images: Vec::from_iter(
+                feed.get_image()
+                    .map(|image| MetadataImageForMediaDetails { image }),
+            ),
+            source: MediaSource::PodcastIndex,
+            identifier: feed.id.to_string(),
+            publish_year: publish_date.map(|d| d.year()),
+            source_url: Some(format!("https://podcastindex.org/podcast/{}", feed.id)),
+            creators: Vec::from_iter(feed.author.map(|name| MetadataFreeCreator {
+                name,
+                role: "Publishing".to_owned(),
+                ..Default::default()
+            })),
+            genres: feed
+                .categories
+                .unwrap_or_default()
+                .into_values()
+                .sorted()
+                .collect(),
+            title: feed.title,
+            description: feed.description,
+            podcast_specifics: Some(PodcastSpecifics {
+                total_episodes: episodes.len(),
+                episodes,
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct SearchResponse {
+            feeds: Vec<Feed>,
+        }
+        let page = page.unwrap_or(1);
+        // The API does not support offsets, so we fetch one more than needed to know
+        // whether there is a next page.
+        let search: SearchResponse = self
+            .get("search/byterm")
+            .with_extension(CacheableProviderRequest)
+            .query(&json!({
+                "q": query,
+                "clean": !display_nsfw,
+                "max": page * PAGE_SIZE + 1,
+            }))
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let total = search.feeds.len() as i32;
+        let next_page = (total > page * PAGE_SIZE).then(|| page + 1);
+        let items = search
+            .feeds
+            .into_iter()
+            .skip(((page - 1) * PAGE_SIZE) as usize)
+            .take(PAGE_SIZE as usize)
+            .map(|f| MetadataSearchItem {
+                image: f.get_image(),
+                identifier: f.id.to_string(),
+                title: f.title,
+                publish_year: None,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails { total, next_page },
+            items,
+        })
+    }
+}
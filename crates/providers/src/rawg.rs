@@ -0,0 +1,372 @@
+use anyhow::{anyhow, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
+use common_models::{SearchDetails, StoredUrl};
+use common_utils::PAGE_SIZE;
+use config::RawgConfig;
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{MetadataGroupSearchResponse, SearchResults};
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    CommitMediaInput, MetadataDetails, MetadataFreeCreator, MetadataGroupSearchItem, MetadataImage,
+    MetadataImageForMediaDetails, MetadataSearchItem, PartialMetadataWithoutId,
+    UniqueMediaIdentifier, VideoGameSpecifics,
+};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use traits::MediaProvider;
+
+static URL: &str = "https://api.rawg.io/api";
+/// The maximum number of games of a series that can be fetched in a single request.
+const SERIES_PAGE_SIZE: i32 = 40;
+/// The identifier of the Steam store on RAWG.
+const STEAM_STORE_ID: i32 = 1;
+/// The number of search results that are compared to the name of a Steam game.
+const STEAM_GAME_SEARCH_PAGE_SIZE: i32 = 10;
+/// The minimum similarity between the names of a Steam game and a game on RAWG for
+/// them to be considered the same game.
+const MIN_STEAM_GAME_NAME_SIMILARITY: f64 = 0.85;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgNamedItem {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgPlatform {
+    platform: RawgNamedItem,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgEsrbRating {
+    slug: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgScreenshot {
+    image: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgGame {
+    id: i64,
+    slug: String,
+    name: String,
+    metacritic: Option<i32>,
+    rating: Option<Decimal>,
+    released: Option<NaiveDate>,
+    description_raw: Option<String>,
+    background_image: Option<String>,
+    game_series_count: Option<i32>,
+    esrb_rating: Option<RawgEsrbRating>,
+    #[serde(default)]
+    genres: Vec<RawgNamedItem>,
+    #[serde(default)]
+    publishers: Vec<RawgNamedItem>,
+    #[serde(default)]
+    developers: Vec<RawgNamedItem>,
+    platforms: Option<Vec<RawgPlatform>>,
+}
+
+impl RawgGame {
+    fn source_url(&self) -> String {
+        format!("https://rawg.io/games/{}", self.slug)
+    }
+
+    fn partial(&self) -> PartialMetadataWithoutId {
+        PartialMetadataWithoutId {
+            title: self.name.clone(),
+            lot: MediaLot::VideoGame,
+            source: MediaSource::Rawg,
+            identifier: self.id.to_string(),
+            image: self.background_image.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct RawgListResponse<T> {
+    count: i32,
+    next: Option<String>,
+    results: Vec<T>,
+}
+
+/// A provider for video games that only needs an API key, unlike IGDB which needs
+/// Twitch credentials. RAWG does not expose franchises directly, so the games of a
+/// series are grouped together and identified by the earliest game in it.
+pub struct RawgService {
+    api_key: String,
+    client: ClientWithMiddleware,
+}
+
+impl RawgService {
+    pub async fn new(config: &RawgConfig) -> Self {
+        let client = get_provider_http_client(MediaSource::Rawg, None);
+        Self {
+            client,
+            api_key: config.api_key.clone(),
+        }
+    }
+}
+
+impl RawgService {
+    fn request(&self, path: &str, query: Value) -> RequestBuilder {
+        self.client
+            .get(format!("{}/{}", URL, path))
+            .query(&json!({ "key": self.api_key }))
+            .query(&query)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: Value) -> Result<T> {
+        self.send(self.request(path, query)).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let data = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        Ok(data)
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+    ) -> Result<(Vec<RawgGame>, SearchDetails)> {
+        let page = page.unwrap_or(1);
+        let data: RawgListResponse<RawgGame> = self
+            .send(
+                self.request(
+                    "games",
+                    json!({ "search": query, "page": page, "page_size": PAGE_SIZE }),
+                )
+                .with_extension(CacheableProviderRequest),
+            )
+            .await?;
+        let details = SearchDetails {
+            total: data.count,
+            next_page: data.next.map(|_| page + 1),
+        };
+        Ok((data.results, details))
+    }
+
+    /// RAWG can not be queried by Steam app IDs, so the game is looked up by its
+    /// name among the games available on Steam. The names often differ slightly (for
+    /// example in punctuation or trademark symbols), so the most similar result is
+    /// used if it is close enough.
+    pub async fn find_steam_game(&self, name: &str) -> Result<Option<String>> {
+        let data: RawgListResponse<RawgGame> = self
+            .send(
+                self.request(
+                    "games",
+                    json!({
+                        "search": name,
+                        "page_size": STEAM_GAME_SEARCH_PAGE_SIZE,
+                        "stores": STEAM_STORE_ID,
+                    }),
+                )
+                .with_extension(CacheableProviderRequest),
+            )
+            .await?;
+        let name = normalize_game_name(name);
+        Ok(data
+            .results
+            .into_iter()
+            .map(|g| {
+                let similarity =
+                    strsim::normalized_levenshtein(&name, &normalize_game_name(&g.name));
+                (g, similarity)
+            })
+            .filter(|(_, similarity)| *similarity >= MIN_STEAM_GAME_NAME_SIMILARITY)
+            // `max_by` returns the last of equally similar games, but the results are
+            // sorted by relevance
+            .rev()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(g, _)| g.id.to_string()))
+    }
+
+    async fn game(&self, identifier: &str) -> Result<RawgGame> {
+        self.get(&format!("games/{}", identifier), json!({})).await
+    }
+
+    /// The other games that are part of the same series as this game.
+    async fn series(&self, game: &RawgGame) -> Result<Vec<RawgGame>> {
+        if game.game_series_count.unwrap_or_default() == 0 {
+            return Ok(vec![]);
+        }
+        let data: RawgListResponse<RawgGame> = self
+            .get(
+                &format!("games/{}/game-series", game.id),
+                json!({ "page_size": SERIES_PAGE_SIZE }),
+            )
+            .await?;
+        Ok(data.results)
+    }
+}
+
+fn normalize_game_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[async_trait]
+impl MediaProvider for RawgService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (items, details) = self.search(query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|g| MetadataSearchItem {
+                image: g.background_image,
+                identifier: g.id.to_string(),
+                publish_year: g.released.map(|d| d.year()),
+                title: g.name,
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let data = self.game(identifier).await?;
+        let screenshots: RawgListResponse<RawgScreenshot> = self
+            .get(&format!("games/{}/screenshots", identifier), json!({}))
+            .await?;
+        let series = self.series(&data).await?;
+        let groups = Vec::from_iter(
+            series
+                .iter()
+                .chain([&data])
+                .min_by_key(|g| (g.released.is_none(), g.released, g.id))
+                .filter(|_| !series.is_empty())
+                .map(|g| CommitMediaInput {
+                    name: g.name.clone(),
+                    unique: UniqueMediaIdentifier {
+                        lot: MediaLot::VideoGame,
+                        source: MediaSource::Rawg,
+                        identifier: g.id.to_string(),
+                    },
+                }),
+        );
+        let creators = data
+            .developers
+            .iter()
+            .map(|d| (d, "Development"))
+            .chain(data.publishers.iter().map(|p| (p, "Publishing")))
+            .map(|(c, role)| MetadataFreeCreator {
+                name: c.name.clone(),
+                role: role.to_owned(),
+                ..Default::default()
+            })
+            .unique()
+            .collect();
+        let url_images = data
+            .background_image
+            .clone()
+            .into_iter()
+            .chain(screenshots.results.into_iter().map(|s| s.image))
+            .unique()
+            .map(|image| MetadataImageForMediaDetails { image })
+            .collect();
+        let provider_rating = data
+            .metacritic
+            .map(Decimal::from)
+            .or(data.rating.filter(|r| !r.is_zero()).map(|r| r * dec!(20)));
+        Ok(MetadataDetails {
+            groups,
+            creators,
+            url_images,
+            provider_rating,
+            lot: MediaLot::VideoGame,
+            source: MediaSource::Rawg,
+            source_url: Some(data.source_url()),
+            identifier: data.id.to_string(),
+            publish_date: data.released,
+            publish_year: data.released.map(|d| d.year()),
+            description: data.description_raw.filter(|d| !d.is_empty()),
+            is_nsfw: data.esrb_rating.map(|r| r.slug == "adults-only"),
+            genres: data.genres.into_iter().map(|g| g.name).unique().collect(),
+            suggestions: series.iter().map(RawgGame::partial).collect(),
+            video_game_specifics: Some(VideoGameSpecifics {
+                platforms: data
+                    .platforms
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|p| p.platform.name)
+                    .collect(),
+            }),
+            title: data.name,
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_group_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<MetadataGroupSearchResponse> {
+        let (items, details) = self.search(query, page).await?;
+        let items = items
+            .into_iter()
+            .map(|g| MetadataGroupSearchItem {
+                name: g.name,
+                parts: None,
+                image: g.background_image,
+                identifier: g.id.to_string(),
+            })
+            .collect();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let data = self.game(identifier).await?;
+        let items = [data.partial()]
+            .into_iter()
+            .chain(
+                self.series(&data)
+                    .await?
+                    .iter()
+                    .sorted_by_key(|g| (g.released.is_none(), g.released, g.id))
+                    .map(RawgGame::partial),
+            )
+            .collect_vec();
+        let group = MetadataGroupWithoutId {
+            lot: MediaLot::VideoGame,
+            source: MediaSource::Rawg,
+            identifier: data.id.to_string(),
+            source_url: Some(data.source_url()),
+            parts: items.len().try_into().unwrap(),
+            description: data.description_raw.filter(|d| !d.is_empty()),
+            images: data.background_image.map(|url| {
+                vec![MetadataImage {
+                    url: StoredUrl::Url(url),
+                }]
+            }),
+            title: data.name,
+            ..Default::default()
+        };
+        Ok((group, items))
+    }
+}
@@ -0,0 +1,336 @@
+use anyhow::{anyhow, bail, Result};
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use common_models::{PersonSourceSpecifics, SearchDetails};
+use common_utils::ryot_log;
+use config::RemoteProviderConfig;
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    MetadataGroupSearchResponse, PeopleSearchResponse, PersonDetails, SearchResults,
+};
+use enum_models::{MediaLot, MediaSource};
+use media_models::{MetadataDetails, MetadataSearchItem, PartialMetadataWithoutId};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use traits::MediaProvider;
+
+/// Adapts an external service implementing the remote provider protocol to the
+/// `MediaProvider` trait. Searches for metadata and groups are routed to the first
+/// provider configured for the lot. All identifiers are namespaced with the name of
+/// the provider they came from, so that an item is always fetched from the same
+/// provider even if the configuration changes.
+#[derive(Debug, Clone)]
+pub struct RemoteService {
+    client: ClientWithMiddleware,
+    lot: Option<MediaLot>,
+    providers: Vec<RemoteProviderConfig>,
+}
+
+impl RemoteService {
+    pub async fn new(config: &config::MediaConfig, lot: MediaLot) -> Self {
+        let client = get_provider_http_client(MediaSource::Remote, None);
+        Self {
+            client,
+            lot: Some(lot),
+            providers: config.remote_providers.clone(),
+        }
+    }
+
+    pub async fn new_non_media(config: &config::MediaConfig) -> Self {
+        let client = get_provider_http_client(MediaSource::Remote, None);
+        Self {
+            client,
+            lot: None,
+            providers: config.remote_providers.clone(),
+        }
+    }
+
+    fn get_lot(&self) -> Result<MediaLot> {
+        self.lot
+            .ok_or_else(|| anyhow!("A media type is required to use a remote provider"))
+    }
+
+    fn provider_for_lot(&self) -> Result<(&RemoteProviderConfig, MediaLot)> {
+        let lot = self.get_lot()?;
+        self.providers
+            .iter()
+            .find(|p| p.lots.contains(&lot))
+            .map(|p| (p, lot))
+            .ok_or_else(|| anyhow!("No remote provider is configured for {:?}", lot))
+    }
+
+    fn provider_for_identifier(&self, identifier: &str) -> Result<(&RemoteProviderConfig, String)> {
+        let Some((name, identifier)) = identifier.split_once(':') else {
+            bail!("Remote identifier {} is not namespaced", identifier);
+        };
+        self.providers
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| (p, identifier.to_owned()))
+            .ok_or_else(|| anyhow!("No remote provider named {} is configured", name))
+    }
+
+    fn request(
+        &self,
+        provider: &RemoteProviderConfig,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> RequestBuilder {
+        let url = format!("{}/{}", provider.url.trim_end_matches('/'), path);
+        let request = self.client.get(url).query(query);
+        match &provider.api_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        provider: &RemoteProviderConfig,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T> {
+        self.send(self.request(provider, path, query)).await
+    }
+
+    /// Searches are cached, unlike the details which should always be fresh.
+    async fn search<T: DeserializeOwned>(
+        &self,
+        provider: &RemoteProviderConfig,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T> {
+        let request = self
+            .request(provider, path, query)
+            .with_extension(CacheableProviderRequest);
+        self.send(request).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let data = request
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<T>()
+            .await?;
+        Ok(data)
+    }
+}
+
+fn namespace_identifier(provider: &RemoteProviderConfig, identifier: &str) -> String {
+    format!("{}:{}", provider.name, identifier)
+}
+
+fn namespace_partial_metadata(
+    provider: &RemoteProviderConfig,
+    metadata: &mut PartialMetadataWithoutId,
+) {
+    if metadata.source == MediaSource::Remote {
+        metadata.identifier = namespace_identifier(provider, &metadata.identifier);
+    }
+}
+
+fn lot_query(lot: MediaLot) -> String {
+    serde_json::to_value(lot)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct GroupDetailsResponse {
+    details: MetadataGroupWithoutId,
+    #[serde(default)]
+    items: Vec<PartialMetadataWithoutId>,
+}
+
+#[async_trait]
+impl MediaProvider for RemoteService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (provider, lot) = self.provider_for_lot()?;
+        let mut response: SearchResults<MetadataSearchItem> = self
+            .search(
+                provider,
+                "metadata/search",
+                &[
+                    ("lot", lot_query(lot)),
+                    ("query", query.to_owned()),
+                    ("page", page.unwrap_or(1).to_string()),
+                    ("display_nsfw", display_nsfw.to_string()),
+                ],
+            )
+            .await?;
+        for item in response.items.iter_mut() {
+            item.identifier = namespace_identifier(provider, &item.identifier);
+        }
+        Ok(response)
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let lot = self.get_lot()?;
+        let (provider, remote_identifier) = self.provider_for_identifier(identifier)?;
+        let mut details: MetadataDetails = self
+            .get(
+                provider,
+                "metadata/details",
+                &[("lot", lot_query(lot)), ("identifier", remote_identifier)],
+            )
+            .await?;
+        details.lot = lot;
+        details.source = MediaSource::Remote;
+        details.identifier = identifier.to_owned();
+        for person in details.people.iter_mut() {
+            if person.source == MediaSource::Remote {
+                person.identifier = namespace_identifier(provider, &person.identifier);
+            }
+        }
+        for group in details.groups.iter_mut() {
+            if group.unique.source == MediaSource::Remote {
+                group.unique.identifier = namespace_identifier(provider, &group.unique.identifier);
+            }
+        }
+        for suggestion in details.suggestions.iter_mut() {
+            namespace_partial_metadata(provider, suggestion);
+        }
+        Ok(details)
+    }
+
+    async fn people_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+        display_nsfw: bool,
+    ) -> Result<PeopleSearchResponse> {
+        let page = page.unwrap_or(1);
+        let mut response = PeopleSearchResponse {
+            details: SearchDetails::default(),
+            items: vec![],
+        };
+        let mut last_error = None;
+        let mut any_succeeded = false;
+        for provider in self.providers.iter() {
+            let result = self
+                .search::<PeopleSearchResponse>(
+                    provider,
+                    "people/search",
+                    &[
+                        ("query", query.to_owned()),
+                        ("page", page.to_string()),
+                        ("display_nsfw", display_nsfw.to_string()),
+                    ],
+                )
+                .await;
+            // One provider being unavailable should not hide the results of the others
+            let data = match result {
+                Ok(data) => {
+                    any_succeeded = true;
+                    data
+                }
+                Err(e) => {
+                    ryot_log!(
+                        warn,
+                        "Could not search people on remote provider {}: {:?}",
+                        provider.name,
+                        e
+                    );
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            response.details.total += data.details.total;
+            if data.details.next_page.is_some() {
+                response.details.next_page = Some(page + 1);
+            }
+            response
+                .items
+                .extend(data.items.into_iter().map(|mut item| {
+                    item.identifier = namespace_identifier(provider, &item.identifier);
+                    item
+                }));
+        }
+        match last_error {
+            Some(e) if !any_succeeded => Err(e),
+            _ => Ok(response),
+        }
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        let (provider, remote_identifier) = self.provider_for_identifier(identifier)?;
+        let mut details: PersonDetails = self
+            .get(
+                provider,
+                "people/details",
+                &[("identifier", remote_identifier)],
+            )
+            .await?;
+        details.source = MediaSource::Remote;
+        details.identifier = identifier.to_owned();
+        for related in details.related_metadata.iter_mut() {
+            namespace_partial_metadata(provider, &mut related.metadata);
+        }
+        for related in details.related_metadata_groups.iter_mut() {
+            let group = &mut related.metadata_group;
+            if group.source == MediaSource::Remote {
+                group.identifier = namespace_identifier(provider, &group.identifier);
+            }
+        }
+        Ok(details)
+    }
+
+    async fn metadata_group_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        display_nsfw: bool,
+    ) -> Result<MetadataGroupSearchResponse> {
+        let (provider, lot) = self.provider_for_lot()?;
+        let mut response: MetadataGroupSearchResponse = self
+            .search(
+                provider,
+                "groups/search",
+                &[
+                    ("lot", lot_query(lot)),
+                    ("query", query.to_owned()),
+                    ("page", page.unwrap_or(1).to_string()),
+                    ("display_nsfw", display_nsfw.to_string()),
+                ],
+            )
+            .await?;
+        for item in response.items.iter_mut() {
+            item.identifier = namespace_identifier(provider, &item.identifier);
+        }
+        Ok(response)
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        let lot = self.get_lot()?;
+        let (provider, remote_identifier) = self.provider_for_identifier(identifier)?;
+        let mut data: GroupDetailsResponse = self
+            .get(
+                provider,
+                "groups/details",
+                &[("lot", lot_query(lot)), ("identifier", remote_identifier)],
+            )
+            .await?;
+        data.details.lot = lot;
+        data.details.source = MediaSource::Remote;
+        data.details.identifier = identifier.to_owned();
+        for item in data.items.iter_mut() {
+            namespace_partial_metadata(provider, item);
+        }
+        Ok((data.details, data.items))
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::{anyhow, bail, Result};
+use application_utils::get_provider_http_client;
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate};
+use common_models::SearchDetails;
+use dependent_models::SearchResults;
+use enum_models::{MediaLot, MediaSource};
+use itertools::Itertools;
+use media_models::{
+    MetadataDetails, MetadataFreeCreator, MetadataImageForMediaDetails, MetadataSearchItem,
+    PodcastEpisode, PodcastSpecifics,
+};
+use reqwest_middleware::ClientWithMiddleware;
+use traits::MediaProvider;
+use xml::{
+    name::OwnedName,
+    reader::{ParserConfig, XmlEvent},
+};
+
+/// A provider for podcasts that are subscribed to directly using the URL of their
+/// RSS feed. The feed URL is used as the identifier of the podcast.
+pub struct RssService {
+    client: ClientWithMiddleware,
+}
+
+impl RssService {
+    pub async fn new() -> Self {
+        let client = get_provider_http_client(MediaSource::Rss, None);
+        Self { client }
+    }
+}
+
+#[derive(Debug, Default)]
+struct FeedItem {
+    guid: Option<String>,
+    title: Option<String>,
+    image: Option<String>,
+    duration: Option<String>,
+    summary: Option<String>,
+    pub_date: Option<String>,
+    description: Option<String>,
+    enclosure_url: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Feed {
+    link: Option<String>,
+    title: Option<String>,
+    image: Option<String>,
+    author: Option<String>,
+    summary: Option<String>,
+    explicit: Option<String>,
+    description: Option<String>,
+    categories: Vec<String>,
+    items: Vec<FeedItem>,
+}
+
+fn get_element_name(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{}:{}", prefix, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+fn parse_feed(data: &str) -> Result<Feed> {
+    let reader = ParserConfig::new()
+        .trim_whitespace(true)
+        .cdata_to_characters(true)
+        .create_reader(data.as_bytes());
+    let mut feed = Feed::default();
+    let mut stack: Vec<String> = vec![];
+    let mut text = String::new();
+    let mut item: Option<FeedItem> = None;
+    for event in reader {
+        match event.map_err(|e| anyhow!(e))? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => {
+                let name = get_element_name(&name);
+                let attribute = |key: &str| {
+                    attributes
+                        .iter()
+                        .find(|a| a.name.local_name == key)
+                        .map(|a| a.value.clone())
+                };
+                match (name.as_str(), item.as_mut()) {
+                    ("item", None) => item = Some(FeedItem::default()),
+                    ("itunes:image", Some(i)) => i.image = attribute("href"),
+                    ("enclosure", Some(i)) => i.enclosure_url = attribute("url"),
+                    ("itunes:image", None) => feed.image = attribute("href").or(feed.image),
+                    ("itunes:category", None) => feed.categories.extend(attribute("text")),
+                    _ => {}
+                }
+                stack.push(name);
+                text.clear();
+            }
+            XmlEvent::Characters(value) => text.push_str(&value),
+            XmlEvent::EndElement { .. } => {
+                let Some(name) = stack.pop() else {
+                    continue;
+                };
+                let value = Some(text.trim().to_owned()).filter(|t| !t.is_empty());
+                text.clear();
+                if name == "item" {
+                    feed.items.extend(item.take());
+                    continue;
+                }
+                if let Some(i) = item.as_mut() {
+                    if stack.last().map(String::as_str) != Some("item") {
+                        continue;
+                    }
+                    match name.as_str() {
+                        "guid" => i.guid = value,
+                        "title" => i.title = value,
+                        "pubDate" => i.pub_date = value,
+                        "description" => i.description = value,
+                        "itunes:summary" => i.summary = value,
+                        "itunes:duration" => i.duration = value,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match (stack.last().map(String::as_str), name.as_str()) {
+                    (Some("channel"), "link") => feed.link = value,
+                    (Some("channel"), "title") => feed.title = value,
+                    (Some("channel"), "itunes:author") => feed.author = value,
+                    (Some("channel"), "itunes:summary") => feed.summary = value,
+                    (Some("channel"), "description") => feed.description = value,
+                    (Some("channel"), "itunes:explicit") => feed.explicit = value,
+                    (Some("image"), "url") if feed.image.is_none() => feed.image = value,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    if feed.title.is_none() {
+        bail!("The URL does not point to a valid podcast feed");
+    }
+    Ok(feed)
+}
+
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    DateTime::parse_from_rfc2822(date)
+        .or_else(|_| DateTime::parse_from_rfc3339(date))
+        .ok()
+        .map(|d| d.date_naive())
+}
+
+/// Durations are either a number of seconds or in the format `HH:MM:SS` or `MM:SS`.
+fn parse_duration_in_seconds(duration: &str) -> Option<i32> {
+    duration.split(':').try_fold(0, |acc, part| {
+        part.trim().parse::<i32>().ok().map(|p| acc * 60 + p)
+    })
+}
+
+impl RssService {
+    async fn get_feed(&self, url: &str) -> Result<Feed> {
+        let data = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()
+            .map_err(|e| anyhow!(e))?
+            .text()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        parse_feed(&data)
+    }
+}
+
+#[async_trait]
+impl MediaProvider for RssService {
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        let feed = self.get_feed(identifier).await?;
+        let episodes = feed
+            .items
+            .into_iter()
+            .filter_map(|item| {
+                let publish_date = item.pub_date.as_deref().and_then(parse_date)?;
+                let id = item
+                    .guid
+                    .or_else(|| item.enclosure_url.clone())
+                    .or_else(|| item.title.clone())?;
+                Some(PodcastEpisode {
+                    id,
+                    publish_date,
+                    thumbnail: item.image,
+                    title: item.title.unwrap_or_default(),
+                    overview: item.description.or(item.summary),
+                    runtime: item
+                        .duration
+                        .as_deref()
+                        .and_then(parse_duration_in_seconds)
+                        .map(|d| d / 60),
+                    ..Default::default()
+                })
+            })
+            .sorted_by_key(|e| e.publish_date)
+            .enumerate()
+            .map(|(idx, episode)| PodcastEpisode {
+                number: idx as i32 + 1,
+                ..episode
+            })
+            .collect_vec();
+        let publish_date = episodes.first().map(|e| e.publish_date);
+        Ok(MetadataDetails {
+            publish_date,
+            lot: MediaLot::Podcast,
+            source: MediaSource::Rss,
+            identifier: identifier.to_owned(),
+            title: feed.title.unwrap_or_default(),
+            description: feed.description.or(feed.summary),
+            source_url: Some(feed.link.unwrap_or_else(|| identifier.to_owned())),
+            publish_year: publish_date.map(|d| d.year()),
+            is_nsfw: feed
+                .explicit
+                .map(|e| matches!(e.to_lowercase().as_str(), "yes" | "true" | "explicit")),
+            creators: Vec::from_iter(feed.author.map(|name| MetadataFreeCreator {
+                name,
+                role: "Publishing".to_owned(),
+                ..Default::default()
+            })),
+            genres: feed.categories.into_iter().unique().collect(),
+            url_images: Vec::from_iter(
+                feed.image
+                    .map(|image| MetadataImageForMediaDetails { image }),
+            ),
+            podcast_specifics: Some(PodcastSpecifics {
+                total_episodes: episodes.len(),
+                episodes,
+            }),
+            ..Default::default()
+        })
+    }
+
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let url = query.trim();
+        if page.unwrap_or(1) != 1 || !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Ok(SearchResults {
+                items: vec![],
+                details: SearchDetails::default(),
+            });
+        }
+        let feed = self.get_feed(url).await?;
+        let publish_year = feed
+            .items
+            .iter()
+            .filter_map(|i| i.pub_date.as_deref().and_then(parse_date))
+            .min()
+            .map(|d| d.year());
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: 1,
+                next_page: None,
+            },
+            items: vec![MetadataSearchItem {
+                publish_year,
+                image: feed.image,
+                identifier: url.to_owned(),
+                title: feed.title.unwrap_or_default(),
+            }],
+        })
+    }
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use common_models::{
@@ -21,14 +21,13 @@ use hashbag::HashBag;
 use itertools::Itertools;
 use media_models::{
     CommitMediaInput, MetadataDetails, MetadataExternalIdentifiers, MetadataGroupSearchItem,
-    MetadataImage, MetadataImageForMediaDetails, MetadataSearchItem, MetadataVideo,
-    MetadataVideoSource, MovieSpecifics, PartialMetadataPerson, PartialMetadataWithoutId,
-    PeopleSearchItem, ShowEpisode, ShowSeason, ShowSpecifics, UniqueMediaIdentifier, WatchProvider,
-};
-use reqwest::{
-    header::{HeaderValue, AUTHORIZATION},
-    Client,
+    MetadataImage, MetadataImageForMediaDetails, MetadataLocalizedDetails, MetadataSearchItem,
+    MetadataVideo, MetadataVideoSource, MovieSpecifics, PartialMetadataPerson,
+    PartialMetadataWithoutId, PeopleSearchItem, ShowEpisode, ShowSeason, ShowSpecifics,
+    UniqueMediaIdentifier, WatchProvider,
 };
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -146,6 +145,24 @@ struct TmdbWatchProviderResponse {
     results: HashMap<String, TmdbWatchProviderList>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct TmdbTranslationData {
+    name: Option<String>,
+    title: Option<String>,
+    overview: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct TmdbTranslation {
+    iso_639_1: String,
+    data: TmdbTranslationData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct TmdbTranslationsResponse {
+    translations: Vec<TmdbTranslation>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct TmdbFindByExternalSourceResponse {
     movie_results: Vec<TmdbEntry>,
@@ -167,7 +184,7 @@ struct TmdbNonMediaEntity {
 }
 
 pub struct TmdbService {
-    client: Client,
+    client: ClientWithMiddleware,
     language: String,
     settings: TmdbSettings,
     supporting_service: Arc<SupportingService>,
@@ -176,10 +193,13 @@ pub struct TmdbService {
 impl TmdbService {
     pub async fn new(ss: Arc<SupportingService>) -> Self {
         let access_token = &ss.config.movies_and_shows.tmdb.access_token;
-        let client: Client = get_base_http_client(Some(vec![(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {access_token}")).unwrap(),
-        )]));
+        let client: ClientWithMiddleware = get_provider_http_client(
+            MediaSource::Tmdb,
+            Some(vec![(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {access_token}")).unwrap(),
+            )]),
+        );
         let settings = get_settings(&client, &ss).await.unwrap();
         Self {
             client,
@@ -344,6 +364,50 @@ impl TmdbService {
         }
     }
 
+    async fn get_localized_details(
+        &self,
+        type_: &str,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        let rsp: TmdbTranslationsResponse = self
+            .client
+            .get(format!("{}/{}/{}/translations", URL, type_, identifier))
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let localized = languages
+            .iter()
+            .filter_map(|language| {
+                // A language can have translations for multiple countries (eg: pt-BR and pt-PT)
+                let translations = rsp
+                    .translations
+                    .iter()
+                    .filter(|t| &t.iso_639_1 == language)
+                    .collect_vec();
+                let title = translations.iter().find_map(|t| {
+                    t.data
+                        .title
+                        .clone()
+                        .or_else(|| t.data.name.clone())
+                        .filter(|t| !t.is_empty())
+                });
+                let description = translations
+                    .iter()
+                    .find_map(|t| t.data.overview.clone().filter(|o| !o.is_empty()));
+                (title.is_some() || description.is_some()).then(|| MetadataLocalizedDetails {
+                    title,
+                    description,
+                    language: language.to_owned(),
+                })
+            })
+            .collect();
+        Ok(localized)
+    }
+
     async fn get_external_identifiers(
         &self,
         type_: &str,
@@ -355,7 +419,11 @@ impl TmdbService {
             .send()
             .await
             .map_err(|e| anyhow!(e))?;
-        rsp.json().await.map_err(|e| anyhow!(e))
+        let mut identifiers: MetadataExternalIdentifiers =
+            rsp.json().await.map_err(|e| anyhow!(e))?;
+        identifiers.tmdb_id = identifier.parse().ok();
+        identifiers.imdb_id = identifiers.imdb_id.filter(|i| !i.is_empty());
+        Ok(identifiers)
     }
 }
 
@@ -399,6 +467,7 @@ impl MediaProvider for NonMediaTmdbService {
             .base
             .client
             .get(format!("{}/search/{}", URL, type_))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "page": page,
                 "language": language,
@@ -619,6 +688,7 @@ impl MediaProvider for TmdbMovieService {
             .base
             .client
             .get(format!("{}/search/movie", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "query": query.to_owned(),
                 "page": page,
@@ -821,6 +891,16 @@ impl MediaProvider for TmdbMovieService {
         })
     }
 
+    async fn metadata_localized_details(
+        &self,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        self.base
+            .get_localized_details("movie", identifier, languages)
+            .await
+    }
+
     async fn metadata_group_search(
         &self,
         query: &str,
@@ -832,6 +912,7 @@ impl MediaProvider for TmdbMovieService {
             .base
             .client
             .get(format!("{}/search/collection", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "query": query.to_owned(),
                 "page": page,
@@ -1208,6 +1289,7 @@ impl MediaProvider for TmdbShowService {
                                         overview: e.overview,
                                         episode_number: e.episode_number,
                                         poster_images,
+                                        ..Default::default()
                                     }
                                 })
                                 .collect(),
@@ -1219,6 +1301,16 @@ impl MediaProvider for TmdbShowService {
         })
     }
 
+    async fn metadata_localized_details(
+        &self,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        self.base
+            .get_localized_details("tv", identifier, languages)
+            .await
+    }
+
     async fn metadata_search(
         &self,
         query: &str,
@@ -1230,6 +1322,7 @@ impl MediaProvider for TmdbShowService {
             .base
             .client
             .get(format!("{}/search/tv", URL))
+            .with_extension(CacheableProviderRequest)
             .query(&json!({
                 "query": query.to_owned(),
                 "page": page,
@@ -1276,7 +1369,7 @@ fn replace_from_end(input_string: String, search_string: &str, replace_string: &
 }
 
 async fn get_settings(
-    client: &Client,
+    client: &ClientWithMiddleware,
     supporting_service: &Arc<SupportingService>,
 ) -> Result<TmdbSettings> {
     let cc = &supporting_service.cache_service;
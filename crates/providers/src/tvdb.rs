@@ -0,0 +1,841 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use anyhow::{anyhow, Result};
+use application_utils::{get_base_http_client, get_provider_http_client, CacheableProviderRequest};
+use async_trait::async_trait;
+use common_models::{ApplicationCacheKey, PersonSourceSpecifics, SearchDetails, StoredUrl};
+use common_utils::{convert_date_to_year, convert_string_to_date, PAGE_SIZE};
+use database_models::metadata_group::MetadataGroupWithoutId;
+use dependent_models::{
+    ApplicationCacheValue, MetadataPersonRelated, PeopleSearchResponse, PersonDetails,
+    SearchResults, TvdbSettings,
+};
+use enum_models::{MediaLot, MediaSource};
+use futures::future::join_all;
+use itertools::Itertools;
+use media_models::{
+    AnimeSpecifics, CommitMediaInput, MetadataDetails, MetadataExternalIdentifiers, MetadataImage,
+    MetadataImageForMediaDetails, MetadataSearchItem, MetadataVideo, MetadataVideoSource,
+    PartialMetadataPerson, PartialMetadataWithoutId, PeopleSearchItem, ShowEpisode, ShowSeason,
+    ShowSpecifics, UniqueMediaIdentifier,
+};
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest_middleware::{ClientWithMiddleware, RequestBuilder};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use supporting_service::SupportingService;
+use traits::{MediaProvider, TraceOk};
+
+static URL: &str = "https://api4.thetvdb.com/v4";
+static SITE_URL: &str = "https://thetvdb.com";
+
+/// Artwork types that are worth showing on the media page.
+const ARTWORK_TYPES: [i32; 2] = [2, 3];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TvdbLinks {
+    next: Option<String>,
+    total_items: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TvdbResponse<T> {
+    data: T,
+    links: Option<TvdbLinks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TvdbSearchItem {
+    tvdb_id: String,
+    name: String,
+    year: Option<String>,
+    image_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TvdbNamedItem {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbSeriesBase {
+    id: i32,
+    name: String,
+    image: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbCharacter {
+    name: Option<String>,
+    people_id: Option<i32>,
+    series_id: Option<i32>,
+    person_name: Option<String>,
+    people_type: Option<String>,
+    series: Option<TvdbSeriesBase>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbArtwork {
+    image: String,
+    r#type: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbSeasonType {
+    r#type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbSeason {
+    id: i32,
+    number: i32,
+    name: Option<String>,
+    image: Option<String>,
+    r#type: TvdbSeasonType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbList {
+    id: i32,
+    name: String,
+    is_official: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbTrailer {
+    url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbTranslation {
+    language: String,
+    name: Option<String>,
+    overview: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbTranslations {
+    name_translations: Option<Vec<TvdbTranslation>>,
+    overview_translations: Option<Vec<TvdbTranslation>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbRemoteId {
+    id: String,
+    source_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbSeries {
+    id: i32,
+    name: String,
+    slug: Option<String>,
+    year: Option<String>,
+    image: Option<String>,
+    overview: Option<String>,
+    first_aired: Option<String>,
+    original_language: Option<String>,
+    status: Option<TvdbNamedItem>,
+    lists: Option<Vec<TvdbList>>,
+    seasons: Option<Vec<TvdbSeason>>,
+    genres: Option<Vec<TvdbNamedItem>>,
+    trailers: Option<Vec<TvdbTrailer>>,
+    artworks: Option<Vec<TvdbArtwork>>,
+    characters: Option<Vec<TvdbCharacter>>,
+    remote_ids: Option<Vec<TvdbRemoteId>>,
+    translations: Option<TvdbTranslations>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct TvdbEpisode {
+    id: i32,
+    number: i32,
+    season_number: i32,
+    name: Option<String>,
+    aired: Option<String>,
+    image: Option<String>,
+    runtime: Option<i32>,
+    overview: Option<String>,
+    absolute_number: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TvdbEpisodesPage {
+    episodes: Vec<TvdbEpisode>,
+}
+
+fn get_youtube_key(url: &str) -> Option<String> {
+    url.split_once("v=")
+        .map(|(_, key)| key.split('&').next().unwrap_or(key).to_owned())
+}
+
+async fn get_access_token(ss: &Arc<SupportingService>) -> Result<String> {
+    let cc = &ss.cache_service;
+    if let Some(token) = cc
+        .get_value::<TvdbSettings>(ApplicationCacheKey::TvdbSettings)
+        .await
+    {
+        return Ok(token);
+    }
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TvdbLoginResponse {
+        token: String,
+    }
+    let config = &ss.config.movies_and_shows.tvdb;
+    let mut body = json!({ "apikey": config.api_key });
+    if !config.pin.is_empty() {
+        body["pin"] = json!(config.pin);
+    }
+    let data: TvdbResponse<TvdbLoginResponse> = get_base_http_client(None)
+        .post(format!("{}/login", URL))
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    cc.set_key(
+        ApplicationCacheKey::TvdbSettings,
+        ApplicationCacheValue::TvdbSettings(data.data.token.clone()),
+    )
+    .await
+    .ok();
+    Ok(data.data.token)
+}
+
+pub struct TvdbService {
+    client: ClientWithMiddleware,
+    language: String,
+}
+
+impl TvdbService {
+    pub async fn new(ss: Arc<SupportingService>) -> Result<Self> {
+        let access_token = get_access_token(&ss).await?;
+        let client = get_provider_http_client(
+            MediaSource::Tvdb,
+            Some(vec![(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {access_token}"))?,
+            )]),
+        );
+        Ok(Self {
+            client,
+            language: ss.config.movies_and_shows.tvdb.locale.clone(),
+        })
+    }
+}
+
+impl TvdbService {
+    fn request(&self, path: &str, query: serde_json::Value) -> RequestBuilder {
+        self.client.get(format!("{}/{}", URL, path)).query(&query)
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: serde_json::Value,
+    ) -> Result<TvdbResponse<T>> {
+        self.send(self.request(path, query)).await
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<TvdbResponse<T>> {
+        let data = request
+            .send()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .error_for_status()?
+            .json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        Ok(data)
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        typ: &str,
+    ) -> Result<(Vec<TvdbSearchItem>, SearchDetails)> {
+        let page = page.unwrap_or(1);
+        let rsp: TvdbResponse<Vec<TvdbSearchItem>> = self
+            .send(
+                self.request(
+                    "search",
+                    json!({
+                        "type": typ,
+                        "query": query,
+                        "limit": PAGE_SIZE,
+                        "offset": (page - 1) * PAGE_SIZE,
+                    }),
+                )
+                .with_extension(CacheableProviderRequest),
+            )
+            .await?;
+        let total = rsp
+            .links
+            .and_then(|l| l.total_items)
+            .unwrap_or(rsp.data.len().try_into().unwrap());
+        let next_page = (page * PAGE_SIZE < total).then_some(page + 1);
+        Ok((rsp.data, SearchDetails { total, next_page }))
+    }
+
+    async fn series_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        let (items, details) = self.search(query, page, "series").await?;
+        let items = items
+            .into_iter()
+            .map(|d| MetadataSearchItem {
+                title: d.name,
+                image: d.image_url,
+                identifier: d.tvdb_id,
+                publish_year: d.year.and_then(|y| y.parse().ok()),
+            })
+            .collect_vec();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn series_episodes(
+        &self,
+        series_id: &str,
+        season_type: &str,
+    ) -> Result<Vec<TvdbEpisode>> {
+        let mut episodes = vec![];
+        for page in 0.. {
+            let rsp: TvdbResponse<TvdbEpisodesPage> = self
+                .get(
+                    &format!(
+                        "series/{}/episodes/{}/{}",
+                        series_id, season_type, self.language
+                    ),
+                    json!({ "page": page }),
+                )
+                .await?;
+            episodes.extend(rsp.data.episodes);
+            if rsp.links.and_then(|l| l.next).is_none() {
+                break;
+            }
+        }
+        Ok(episodes)
+    }
+
+    fn translated(&self, translations: Option<Vec<TvdbTranslation>>) -> Option<TvdbTranslation> {
+        translations
+            .unwrap_or_default()
+            .into_iter()
+            .find(|t| t.language == self.language)
+    }
+
+    async fn series_details(&self, identifier: &str, lot: MediaLot) -> Result<MetadataDetails> {
+        let data: TvdbSeries = self
+            .get(
+                &format!("series/{}/extended", identifier),
+                json!({ "meta": "translations" }),
+            )
+            .await?
+            .data;
+        let episodes = self.series_episodes(identifier, "official").await?;
+        let translations = data.translations.clone();
+        let remote_ids = data.remote_ids.clone().unwrap_or_default();
+        let remote_id = |name: &str| {
+            remote_ids
+                .iter()
+                .find(|r| r.source_name == name)
+                .map(|r| r.id.clone())
+        };
+        let title = self
+            .translated(translations.clone().and_then(|t| t.name_translations))
+            .and_then(|t| t.name)
+            .unwrap_or(data.name);
+        let description = self
+            .translated(translations.and_then(|t| t.overview_translations))
+            .and_then(|t| t.overview)
+            .or(data.overview);
+        let mut images = Vec::from_iter(data.image);
+        images.extend(
+            data.artworks
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|a| ARTWORK_TYPES.contains(&a.r#type))
+                .map(|a| a.image),
+        );
+        let people = data
+            .characters
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|c| {
+                let role = c.people_type?;
+                Some(PartialMetadataPerson {
+                    source: MediaSource::Tvdb,
+                    identifier: c.people_id?.to_string(),
+                    name: c.person_name.unwrap_or_default(),
+                    character: c.name.filter(|_| role == "Actor"),
+                    role,
+                    ..Default::default()
+                })
+            })
+            .unique()
+            .collect_vec();
+        let groups = data
+            .lists
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|l| l.is_official.unwrap_or_default())
+            .map(|l| CommitMediaInput {
+                name: l.name,
+                unique: UniqueMediaIdentifier {
+                    lot,
+                    source: MediaSource::Tvdb,
+                    identifier: l.id.to_string(),
+                },
+            })
+            .collect_vec();
+        let videos = data
+            .trailers
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|t| get_youtube_key(&t.url))
+            .map(|key| MetadataVideo {
+                identifier: StoredUrl::Url(key),
+                source: MetadataVideoSource::Youtube,
+            })
+            .collect_vec();
+        let (show_specifics, anime_specifics) = match lot {
+            MediaLot::Anime => (
+                None,
+                Some(AnimeSpecifics {
+                    episodes: Some(
+                        episodes
+                            .iter()
+                            .filter(|e| e.season_number != 0)
+                            .count()
+                            .try_into()
+                            .unwrap(),
+                    ),
+                    ..Default::default()
+                }),
+            ),
+            _ => {
+                let seasons = data.seasons.unwrap_or_default();
+                let dvd_episodes = if seasons.iter().any(|s| s.r#type.r#type == "dvd") {
+                    self.series_episodes(identifier, "dvd").await?
+                } else {
+                    vec![]
+                };
+                (
+                    Some(self.show_specifics(seasons, episodes, dvd_episodes)),
+                    None,
+                )
+            }
+        };
+        Ok(MetadataDetails {
+            lot,
+            title,
+            groups,
+            people,
+            videos,
+            description,
+            show_specifics,
+            anime_specifics,
+            source: MediaSource::Tvdb,
+            identifier: identifier.to_owned(),
+            original_language: data.original_language,
+            production_status: data.status.map(|s| s.name),
+            publish_year: data
+                .year
+                .and_then(|y| y.parse().ok())
+                .or_else(|| convert_date_to_year(&data.first_aired.clone().unwrap_or_default())),
+            publish_date: convert_string_to_date(&data.first_aired.unwrap_or_default()),
+            source_url: Some(match data.slug {
+                Some(slug) => format!("{}/series/{}", SITE_URL, slug),
+                None => format!("{}/dereferrer/series/{}", SITE_URL, data.id),
+            }),
+            external_identifiers: Some(MetadataExternalIdentifiers {
+                tvdb_id: Some(data.id),
+                tmdb_id: remote_id("TheMovieDB.com").and_then(|id| id.parse().ok()),
+                imdb_id: remote_id("IMDB"),
+                ..Default::default()
+            }),
+            genres: data
+                .genres
+                .unwrap_or_default()
+                .into_iter()
+                .map(|g| g.name)
+                .unique()
+                .collect(),
+            url_images: images
+                .into_iter()
+                .unique()
+                .map(|image| MetadataImageForMediaDetails { image })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /// The seasons are grouped by the aired ordering. The numbers of the episodes in the
+    /// other orderings are stored with them so that a show can be tracked in any of them.
+    fn show_specifics(
+        &self,
+        seasons: Vec<TvdbSeason>,
+        episodes: Vec<TvdbEpisode>,
+        dvd_episodes: Vec<TvdbEpisode>,
+    ) -> ShowSpecifics {
+        let dvd_numbers: HashMap<i32, (i32, i32)> = dvd_episodes
+            .into_iter()
+            .map(|e| (e.id, (e.season_number, e.number)))
+            .collect();
+        let mut grouped: BTreeMap<i32, Vec<TvdbEpisode>> = BTreeMap::new();
+        for episode in episodes {
+            grouped
+                .entry(episode.season_number)
+                .or_default()
+                .push(episode);
+        }
+        let seasons = grouped
+            .into_iter()
+            .map(|(season_number, episodes)| {
+                let season = seasons
+                    .iter()
+                    .find(|s| s.number == season_number && s.r#type.r#type == "official");
+                let name = match season_number {
+                    0 => "Specials".to_owned(),
+                    _ => season
+                        .and_then(|s| s.name.clone())
+                        .unwrap_or_else(|| format!("Season {}", season_number)),
+                };
+                ShowSeason {
+                    name,
+                    season_number,
+                    id: season.map(|s| s.id).unwrap_or(season_number),
+                    poster_images: Vec::from_iter(season.and_then(|s| s.image.clone())),
+                    publish_date: episodes
+                        .iter()
+                        .filter_map(|e| convert_string_to_date(&e.aired.clone()?))
+                        .min(),
+                    episodes: episodes
+                        .into_iter()
+                        .map(|e| {
+                            let dvd_number = dvd_numbers.get(&e.id);
+                            ShowEpisode {
+                                id: e.id,
+                                runtime: e.runtime,
+                                overview: e.overview,
+                                episode_number: e.number,
+                                name: e.name.unwrap_or_default(),
+                                poster_images: Vec::from_iter(e.image),
+                                publish_date: convert_string_to_date(&e.aired.unwrap_or_default()),
+                                absolute_number: e.absolute_number.filter(|_| season_number != 0),
+                                dvd_season_number: dvd_number.map(|(s, _)| *s),
+                                dvd_episode_number: dvd_number.map(|(_, n)| *n),
+                            }
+                        })
+                        .sorted_by_key(|e| e.episode_number)
+                        .collect(),
+                    ..Default::default()
+                }
+            })
+            .collect_vec();
+        let seasons_without_specials = seasons
+            .iter()
+            .filter(|s| s.season_number != 0)
+            .collect_vec();
+        let total_runtime: i32 = seasons_without_specials
+            .iter()
+            .flat_map(|s| s.episodes.iter())
+            .map(|e| e.runtime.unwrap_or_default())
+            .sum();
+        let total_seasons = seasons_without_specials.len();
+        let total_episodes = seasons_without_specials
+            .iter()
+            .flat_map(|s| s.episodes.iter())
+            .count();
+        ShowSpecifics {
+            seasons,
+            runtime: (total_runtime != 0).then_some(total_runtime),
+            total_seasons: (total_seasons != 0).then_some(total_seasons),
+            total_episodes: (total_episodes != 0).then_some(total_episodes),
+        }
+    }
+
+    async fn list_details(
+        &self,
+        identifier: &str,
+        lot: MediaLot,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        #[serde(rename_all = "camelCase")]
+        struct TvdbListEntity {
+            order: Option<i32>,
+            series_id: Option<i32>,
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        #[serde(rename_all = "camelCase")]
+        struct TvdbListDetails {
+            id: i32,
+            name: String,
+            url: Option<String>,
+            image: Option<String>,
+            overview: Option<String>,
+            entities: Option<Vec<TvdbListEntity>>,
+        }
+        let data: TvdbListDetails = self
+            .get(&format!("lists/{}/extended", identifier), json!({}))
+            .await?
+            .data;
+        let promises = data
+            .entities
+            .unwrap_or_default()
+            .into_iter()
+            .sorted_by_key(|e| e.order)
+            .filter_map(|e| e.series_id)
+            .map(|series_id| async move {
+                self.get::<TvdbSeriesBase>(&format!("series/{}", series_id), json!({}))
+                    .await
+            });
+        let items = join_all(promises)
+            .await
+            .into_iter()
+            .filter_map(|series| series.trace_ok())
+            .map(|series| PartialMetadataWithoutId {
+                lot,
+                title: series.data.name,
+                image: series.data.image,
+                source: MediaSource::Tvdb,
+                identifier: series.data.id.to_string(),
+                ..Default::default()
+            })
+            .collect_vec();
+        Ok((
+            MetadataGroupWithoutId {
+                lot,
+                title: data.name,
+                source: MediaSource::Tvdb,
+                description: data.overview,
+                identifier: identifier.to_owned(),
+                parts: items.len().try_into().unwrap(),
+                source_url: Some(match data.url {
+                    Some(slug) => format!("{}/lists/{}", SITE_URL, slug),
+                    None => format!("{}/lists/{}", SITE_URL, data.id),
+                }),
+                images: Some(
+                    Vec::from_iter(data.image)
+                        .into_iter()
+                        .map(|i| MetadataImage {
+                            url: StoredUrl::Url(i),
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            items,
+        ))
+    }
+}
+
+pub struct NonMediaTvdbService {
+    base: TvdbService,
+}
+
+impl NonMediaTvdbService {
+    pub async fn new(ss: Arc<SupportingService>) -> Result<Self> {
+        Ok(Self {
+            base: TvdbService::new(ss).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl MediaProvider for NonMediaTvdbService {
+    async fn people_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _source_specifics: &Option<PersonSourceSpecifics>,
+        _display_nsfw: bool,
+    ) -> Result<PeopleSearchResponse> {
+        let (items, details) = self.base.search(query, page, "person").await?;
+        let items = items
+            .into_iter()
+            .map(|d| PeopleSearchItem {
+                name: d.name,
+                image: d.image_url,
+                identifier: d.tvdb_id,
+                birth_year: d.year.and_then(|y| y.parse().ok()),
+            })
+            .collect_vec();
+        Ok(SearchResults { details, items })
+    }
+
+    async fn person_details(
+        &self,
+        identifier: &str,
+        source_specifics: &Option<PersonSourceSpecifics>,
+    ) -> Result<PersonDetails> {
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        #[serde(rename_all = "camelCase")]
+        struct TvdbBiography {
+            language: String,
+            biography: Option<String>,
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        #[serde(rename_all = "camelCase")]
+        struct TvdbPerson {
+            id: i32,
+            name: String,
+            gender: Option<i32>,
+            image: Option<String>,
+            birth: Option<String>,
+            death: Option<String>,
+            birth_place: Option<String>,
+            aliases: Option<Vec<TvdbNamedItem>>,
+            characters: Option<Vec<TvdbCharacter>>,
+            biographies: Option<Vec<TvdbBiography>>,
+        }
+        let data: TvdbPerson = self
+            .base
+            .get(
+                &format!("people/{}/extended", identifier),
+                json!({ "meta": "translations" }),
+            )
+            .await?
+            .data;
+        let biographies = data.biographies.unwrap_or_default();
+        let description = biographies
+            .iter()
+            .find(|b| b.language == self.base.language)
+            .or_else(|| biographies.first())
+            .and_then(|b| b.biography.clone())
+            .filter(|b| !b.is_empty());
+        let related_metadata = data
+            .characters
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|c| {
+                let series_id = c.series_id?;
+                let role = c.people_type.unwrap_or_else(|| "Actor".to_owned());
+                Some(MetadataPersonRelated {
+                    character: c.name.filter(|_| role == "Actor"),
+                    role,
+                    metadata: PartialMetadataWithoutId {
+                        lot: MediaLot::Show,
+                        source: MediaSource::Tvdb,
+                        identifier: series_id.to_string(),
+                        image: c.series.as_ref().and_then(|s| s.image.clone()),
+                        title: c.series.map(|s| s.name).unwrap_or_default(),
+                        ..Default::default()
+                    },
+                })
+            })
+            .collect_vec();
+        Ok(PersonDetails {
+            description,
+            related_metadata,
+            name: data.name,
+            place: data.birth_place,
+            source: MediaSource::Tvdb,
+            identifier: data.id.to_string(),
+            images: Some(Vec::from_iter(data.image)),
+            source_specifics: source_specifics.to_owned(),
+            birth_date: convert_string_to_date(&data.birth.unwrap_or_default()),
+            death_date: convert_string_to_date(&data.death.unwrap_or_default()),
+            source_url: Some(format!("{}/people/{}", SITE_URL, data.id)),
+            gender: match data.gender {
+                Some(1) => Some("Male".to_owned()),
+                Some(2) => Some("Female".to_owned()),
+                _ => None,
+            },
+            alternate_names: data
+                .aliases
+                .map(|a| a.into_iter().map(|a| a.name).collect())
+                .filter(|a: &Vec<String>| !a.is_empty()),
+            ..Default::default()
+        })
+    }
+}
+
+pub struct TvdbShowService {
+    base: TvdbService,
+}
+
+impl TvdbShowService {
+    pub async fn new(ss: Arc<SupportingService>) -> Result<Self> {
+        Ok(Self {
+            base: TvdbService::new(ss).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl MediaProvider for TvdbShowService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        self.base.series_search(query, page).await
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        self.base.series_details(identifier, MediaLot::Show).await
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        self.base.list_details(identifier, MediaLot::Show).await
+    }
+}
+
+pub struct TvdbAnimeService {
+    base: TvdbService,
+}
+
+impl TvdbAnimeService {
+    pub async fn new(ss: Arc<SupportingService>) -> Result<Self> {
+        Ok(Self {
+            base: TvdbService::new(ss).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl MediaProvider for TvdbAnimeService {
+    async fn metadata_search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+    ) -> Result<SearchResults<MetadataSearchItem>> {
+        self.base.series_search(query, page).await
+    }
+
+    async fn metadata_details(&self, identifier: &str) -> Result<MetadataDetails> {
+        self.base.series_details(identifier, MediaLot::Anime).await
+    }
+
+    async fn metadata_group_details(
+        &self,
+        identifier: &str,
+    ) -> Result<(MetadataGroupWithoutId, Vec<PartialMetadataWithoutId>)> {
+        self.base.list_details(identifier, MediaLot::Anime).await
+    }
+}
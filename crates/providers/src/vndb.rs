@@ -1,16 +1,16 @@
 use anyhow::{anyhow, Result};
-use application_utils::get_base_http_client;
+use application_utils::{get_provider_http_client, CacheableProviderRequest};
 use async_trait::async_trait;
 use common_models::{NamedObject, PersonSourceSpecifics, SearchDetails};
 use common_utils::{convert_date_to_year, convert_string_to_date, PAGE_SIZE};
-use dependent_models::{PersonDetails, PeopleSearchResponse, SearchResults};
+use dependent_models::{PeopleSearchResponse, PersonDetails, SearchResults};
 use enum_models::{MediaLot, MediaSource};
 use itertools::Itertools;
 use media_models::{
     MetadataDetails, MetadataImageForMediaDetails, MetadataSearchItem, PartialMetadataPerson,
     PeopleSearchItem, VisualNovelSpecifics,
 };
-use reqwest::Client;
+use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use traits::MediaProvider;
@@ -25,12 +25,12 @@ const METADATA_FIELDS: &str = const_str::concat!(
 
 #[derive(Debug, Clone)]
 pub struct VndbService {
-    client: Client,
+    client: ClientWithMiddleware,
 }
 
 impl VndbService {
     pub async fn new(_config: &config::VisualNovelConfig) -> Self {
-        let client = get_base_http_client(None);
+        let client = get_provider_http_client(MediaSource::Vndb, None);
         Self { client }
     }
 }
@@ -81,6 +81,7 @@ impl MediaProvider for VndbService {
         let data = self
             .client
             .post(format!("{}/producer", URL))
+            .with_extension(CacheableProviderRequest)
             .json(&serde_json::json!({
                 "filters": format!(r#"["search", "=", "{}"]"#, query),
                 "count": true,
@@ -173,6 +174,7 @@ impl MediaProvider for VndbService {
         let rsp = self
             .client
             .post(format!("{}/vn", URL))
+            .with_extension(CacheableProviderRequest)
             .json(&serde_json::json!({
                 "filters": format!(r#"["search", "=", "{}"]"#, query),
                 "fields": METADATA_FIELDS_SMALL,
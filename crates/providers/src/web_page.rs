@@ -0,0 +1,403 @@
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Result};
+use common_utils::USER_AGENT_STR;
+use enum_models::MediaLot;
+use itertools::Itertools;
+use media_models::{
+    BookSpecifics, CreateCustomMetadataInput, MovieSpecifics, MusicSpecifics, VideoGameSpecifics,
+};
+use reqwest::{header::LOCATION, redirect::Policy, ClientBuilder, Url};
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+/// Extracts metadata from arbitrary web pages using the Schema.org (JSON-LD) and
+/// OpenGraph information embedded in them. Used to pre-fill custom metadata.
+pub struct WebPageService;
+
+impl WebPageService {
+    pub async fn new() -> Self {
+        Self
+    }
+}
+
+const MAX_REDIRECTS: usize = 5;
+const MAX_PAGE_SIZE: usize = 5 * 1024 * 1024;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Whether the address can be reached from the public internet. Used to prevent
+/// the server from being used to make requests to its own network.
+fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || ip.is_documentation()
+                || first == 0
+                || (first == 100 && (64..128).contains(&second)))
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_public_address(IpAddr::V4(mapped));
+            }
+            let first_segment = ip.segments()[0];
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                || (first_segment & 0xfe00) == 0xfc00
+                || (first_segment & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// Resolve the host of the URL and make sure that all of its addresses are public.
+async fn resolve_public_address(url: &Url) -> Result<(String, SocketAddr)> {
+    if !["http", "https"].contains(&url.scheme()) {
+        bail!("Only http and https URLs are supported");
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("The URL does not have a host"))?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_owned();
+    let port = url.port_or_known_default().unwrap_or(80);
+    let addresses = tokio::net::lookup_host((host.as_str(), port))
+        .await
+        .map_err(|_| anyhow!("Could not resolve the host of the URL"))?
+        .collect_vec();
+    if addresses.iter().any(|a| !is_public_address(a.ip())) {
+        bail!("The URL does not point to a public address");
+    }
+    let address = addresses
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("Could not resolve the host of the URL"))?;
+    Ok((host, address))
+}
+
+/// Fetch the page, following redirects manually so that every hop is checked
+/// against private addresses. Returns the final URL along with the page body.
+async fn fetch_page(mut url: Url) -> Result<(Url, String)> {
+    for _ in 0..=MAX_REDIRECTS {
+        let (host, address) = resolve_public_address(&url).await?;
+        // The connection is pinned to the checked address so that the host can not
+        // resolve to a different one when the request is actually made.
+        let client = ClientBuilder::new()
+            .user_agent(USER_AGENT_STR)
+            .timeout(REQUEST_TIMEOUT)
+            .redirect(Policy::none())
+            .resolve(&host, address)
+            .build()?;
+        let response = client.get(url.clone()).send().await?;
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|l| l.to_str().ok())
+                .ok_or_else(|| anyhow!("The page redirected without a location"))?;
+            url = url.join(location)?;
+            continue;
+        }
+        let mut response = response.error_for_status()?;
+        if response
+            .content_length()
+            .is_some_and(|l| l > MAX_PAGE_SIZE as u64)
+        {
+            bail!("The page is too large to be scraped");
+        }
+        let mut body = vec![];
+        while let Some(chunk) = response.chunk().await? {
+            if body.len() + chunk.len() > MAX_PAGE_SIZE {
+                bail!("The page is too large to be scraped");
+            }
+            body.extend_from_slice(&chunk);
+        }
+        return Ok((url, String::from_utf8_lossy(&body).into_owned()));
+    }
+    bail!("The page redirected too many times")
+}
+
+#[derive(Debug, Default)]
+struct PageData {
+    title: Option<String>,
+    description: Option<String>,
+    properties: HashMap<String, Vec<String>>,
+    linked_data: Vec<Value>,
+}
+
+impl PageData {
+    fn property(&self, key: &str) -> Option<String> {
+        self.properties.get(key).and_then(|v| v.first()).cloned()
+    }
+}
+
+fn get_schema_lot(schema_type: &str) -> Option<MediaLot> {
+    match schema_type {
+        "Book" => Some(MediaLot::Book),
+        "Movie" => Some(MediaLot::Movie),
+        "TVSeries" => Some(MediaLot::Show),
+        "VideoGame" => Some(MediaLot::VideoGame),
+        "MusicRecording" => Some(MediaLot::Music),
+        _ => None,
+    }
+}
+
+fn get_og_lot(og_type: &str) -> Option<MediaLot> {
+    match og_type {
+        "book" => Some(MediaLot::Book),
+        "video.movie" => Some(MediaLot::Movie),
+        "music.song" => Some(MediaLot::Music),
+        "video.tv_show" | "video.episode" => Some(MediaLot::Show),
+        _ => None,
+    }
+}
+
+fn parse_page(html: &str) -> PageData {
+    let document = Html::parse_document(html);
+    let meta_selector = Selector::parse("meta[content]").unwrap();
+    let title_selector = Selector::parse("title").unwrap();
+    let linked_data_selector = Selector::parse(r#"script[type="application/ld+json"]"#).unwrap();
+    let mut data = PageData {
+        title: document
+            .select(&title_selector)
+            .next()
+            .map(|t| t.text().collect::<String>().trim().to_owned()),
+        ..Default::default()
+    };
+    for meta in document.select(&meta_selector) {
+        let element = meta.value();
+        let content = element
+            .attr("content")
+            .unwrap_or_default()
+            .trim()
+            .to_owned();
+        if content.is_empty() {
+            continue;
+        }
+        let Some(key) = element.attr("property").or_else(|| element.attr("name")) else {
+            continue;
+        };
+        if key == "description" {
+            data.description = Some(content);
+        } else if key.contains(':') {
+            data.properties
+                .entry(key.to_owned())
+                .or_default()
+                .push(content);
+        }
+    }
+    for script in document.select(&linked_data_selector) {
+        let text = script.text().collect::<String>();
+        let Ok(value) = serde_json::from_str::<Value>(&text) else {
+            continue;
+        };
+        let nodes = match value {
+            Value::Array(nodes) => nodes,
+            Value::Object(ref object) => match object.get("@graph") {
+                Some(Value::Array(graph)) => graph.clone(),
+                _ => vec![value],
+            },
+            _ => vec![],
+        };
+        data.linked_data.extend(nodes);
+    }
+    data
+}
+
+/// Collects the strings in a JSON-LD value which can be a plain string, an object
+/// (in which case `key` is read from it) or an array of either.
+fn collect_strings(value: Option<&Value>, key: &str) -> Vec<String> {
+    match value {
+        Some(Value::String(s)) => vec![s.trim().to_owned()],
+        Some(Value::Number(n)) => vec![n.to_string()],
+        Some(Value::Array(values)) => values
+            .iter()
+            .flat_map(|v| collect_strings(Some(v), key))
+            .collect(),
+        Some(Value::Object(object)) => collect_strings(object.get(key), key),
+        _ => vec![],
+    }
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect()
+}
+
+fn first_string(value: Option<&Value>) -> Option<String> {
+    collect_strings(value, "name").into_iter().next()
+}
+
+fn parse_year(date: &str) -> Option<i32> {
+    date.get(..4).and_then(|y| y.parse().ok())
+}
+
+/// Parses an ISO 8601 duration (eg: `PT1H30M`) into seconds.
+fn parse_iso_duration_in_seconds(duration: &str) -> Option<i32> {
+    let mut seconds = 0.0;
+    let mut in_time = false;
+    let mut number = String::new();
+    for c in duration.strip_prefix('P')?.chars() {
+        let multiplier = match c {
+            'T' => {
+                in_time = true;
+                continue;
+            }
+            '0'..='9' | '.' => {
+                number.push(c);
+                continue;
+            }
+            'W' => 604800.0,
+            'D' => 86400.0,
+            'H' if in_time => 3600.0,
+            'M' if in_time => 60.0,
+            'S' if in_time => 1.0,
+            _ => return None,
+        };
+        seconds += number.parse::<f64>().ok()? * multiplier;
+        number.clear();
+    }
+    Some(seconds as i32)
+}
+
+impl WebPageService {
+    /// Scrape the page at `url` and convert it to an input for creating custom
+    /// metadata. The `lot` is used if provided, otherwise it is detected from the page.
+    pub async fn scrape_custom_metadata(
+        &self,
+        url: &str,
+        lot: Option<MediaLot>,
+    ) -> Result<CreateCustomMetadataInput> {
+        let url = Url::parse(url.trim()).map_err(|_| anyhow!("The URL is not valid"))?;
+        let (base_url, html) = fetch_page(url).await?;
+        let page = parse_page(&html);
+
+        let item_lot = |node: &Value| {
+            collect_strings(node.get("@type"), "@type")
+                .iter()
+                .find_map(|t| get_schema_lot(t))
+        };
+        let item = page
+            .linked_data
+            .iter()
+            .find(|node| lot.is_some() && item_lot(node) == lot)
+            .or_else(|| {
+                page.linked_data
+                    .iter()
+                    .find(|node| item_lot(node).is_some())
+            });
+        let Some(lot) = lot
+            .or_else(|| item.and_then(item_lot))
+            .or_else(|| page.property("og:type").as_deref().and_then(get_og_lot))
+        else {
+            bail!("Could not detect the type of media on this page, please select one");
+        };
+        let empty = Value::Null;
+        let item = item.unwrap_or(&empty);
+        let get = |key: &str| item.get(key);
+
+        let title = first_string(get("name"))
+            .or_else(|| first_string(get("headline")))
+            .or_else(|| page.property("og:title"))
+            .or(page.title.clone())
+            .filter(|t| !t.is_empty())
+            .ok_or_else(|| anyhow!("Could not find a title on this page"))?;
+        let description = first_string(get("description"))
+            .or_else(|| page.property("og:description"))
+            .or(page.description.clone());
+        let publish_year = ["datePublished", "dateCreated", "startDate", "copyrightYear"]
+            .into_iter()
+            .find_map(|key| first_string(get(key)))
+            .or_else(|| {
+                [
+                    "book:release_date",
+                    "video:release_date",
+                    "music:release_date",
+                ]
+                .into_iter()
+                .find_map(|key| page.property(key))
+            })
+            .and_then(|d| parse_year(&d));
+        let images = collect_strings(get("image"), "url")
+            .into_iter()
+            .chain(page.properties.get("og:image").cloned().unwrap_or_default())
+            .filter_map(|image| base_url.join(&image).ok().map(|u| u.to_string()))
+            .unique()
+            .collect_vec();
+        let genres = collect_strings(get("genre"), "name")
+            .into_iter()
+            .unique()
+            .collect_vec();
+        let creators = ["author", "creator", "director", "byArtist"]
+            .into_iter()
+            .flat_map(|key| collect_strings(get(key), "name"))
+            .unique()
+            .collect_vec();
+        let duration = first_string(get("duration"))
+            .and_then(|d| parse_iso_duration_in_seconds(&d))
+            .or_else(|| {
+                ["video:duration", "music:duration"]
+                    .into_iter()
+                    .find_map(|key| page.property(key))
+                    .and_then(|d| d.parse().ok())
+            });
+
+        let mut input = CreateCustomMetadataInput {
+            lot,
+            title,
+            description,
+            publish_year,
+            videos: None,
+            show_specifics: None,
+            book_specifics: None,
+            music_specifics: None,
+            movie_specifics: None,
+            manga_specifics: None,
+            comic_specifics: None,
+            anime_specifics: None,
+            podcast_specifics: None,
+            board_game_specifics: None,
+            audio_book_specifics: None,
+            video_game_specifics: None,
+            visual_novel_specifics: None,
+            is_nsfw: get("isFamilyFriendly").and_then(Value::as_bool).map(|f| !f),
+            images: (!images.is_empty()).then_some(images),
+            genres: (!genres.is_empty()).then_some(genres),
+            creators: (!creators.is_empty()).then_some(creators),
+        };
+        match lot {
+            MediaLot::Book => {
+                input.book_specifics = Some(BookSpecifics {
+                    pages: first_string(get("numberOfPages")).and_then(|p| p.parse().ok()),
+                    ..Default::default()
+                })
+            }
+            MediaLot::Movie => {
+                input.movie_specifics = Some(MovieSpecifics {
+                    runtime: duration.map(|d| d / 60),
+                })
+            }
+            MediaLot::Music => {
+                input.music_specifics = Some(MusicSpecifics {
+                    duration,
+                    ..Default::default()
+                })
+            }
+            MediaLot::VideoGame => {
+                input.video_game_specifics = Some(VideoGameSpecifics {
+                    platforms: collect_strings(get("gamePlatform"), "name"),
+                })
+            }
+            _ => {}
+        }
+        Ok(input)
+    }
+}
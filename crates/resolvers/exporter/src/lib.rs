@@ -3,6 +3,7 @@ use std::sync::Arc;
 use async_graphql::{Context, Object, Result};
 use common_models::ExportJob;
 use exporter_service::ExporterService;
+use media_models::DeployExportJobInput;
 use traits::AuthProvider;
 
 #[derive(Default)]
@@ -32,9 +33,28 @@ impl AuthProvider for ExporterMutation {
 #[Object]
 impl ExporterMutation {
     /// Deploy a job to export data for a user.
-    async fn deploy_export_job(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    async fn deploy_export_job(
+        &self,
+        gql_ctx: &Context<'_>,
+        #[graphql(default)] input: DeployExportJobInput,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<ExporterService>>();
         let user_id = self.user_id_from_ctx(gql_ctx).await?;
-        service.deploy_export_job(user_id).await
+        service.deploy_export_job(user_id, input).await
+    }
+
+    /// Deploy a job to create a backup of the entire instance. The backup will be
+    /// encrypted with the passphrase and appear in the exports of the admin that
+    /// requested it.
+    async fn deploy_instance_backup_job(
+        &self,
+        gql_ctx: &Context<'_>,
+        passphrase: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExporterService>>();
+        let user_id = self.user_id_from_ctx(gql_ctx).await?;
+        service
+            .deploy_instance_backup_job(user_id, passphrase)
+            .await
     }
 }
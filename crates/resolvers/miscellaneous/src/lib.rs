@@ -14,8 +14,9 @@ use media_models::{
     CommitMediaInput, CommitPersonInput, CreateCustomMetadataInput, CreateOrUpdateReviewInput,
     CreateReviewCommentInput, GenreDetailsInput, GraphqlCalendarEvent, GraphqlMetadataDetails,
     GroupedCalendarEvent, MarkEntityAsPartialInput, MetadataGroupsListInput, MetadataListInput,
-    MetadataPartialDetails, PeopleListInput, ProgressUpdateInput, UpdateCustomMetadataInput,
-    UpdateSeenItemInput, UserCalendarEventInput, UserUpcomingCalendarEventInput,
+    MetadataPartialDetails, PeopleListInput, ProgressUpdateInput, ScrapeCustomMetadataInput,
+    SwitchMetadataSourceInput, UpdateCustomMetadataInput, UpdateSeenItemInput,
+    UserCalendarEventInput, UserUpcomingCalendarEventInput,
 };
 use miscellaneous_service::MiscellaneousService;
 use traits::AuthProvider;
@@ -40,7 +41,10 @@ impl MiscellaneousQuery {
         metadata_id: String,
     ) -> Result<MetadataPartialDetails> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.metadata_partial_details(&metadata_id).await
+        let user_id = self.user_id_from_ctx(gql_ctx).await.ok();
+        service
+            .metadata_partial_details(user_id, &metadata_id)
+            .await
     }
 
     /// Get details about a media present in the database.
@@ -50,7 +54,8 @@ impl MiscellaneousQuery {
         metadata_id: String,
     ) -> Result<GraphqlMetadataDetails> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.metadata_details(&metadata_id).await
+        let user_id = self.user_id_from_ctx(gql_ctx).await.ok();
+        service.metadata_details(user_id, &metadata_id).await
     }
 
     /// Get details about a creator present in the database.
@@ -271,6 +276,18 @@ impl MiscellaneousMutation {
             .map(|m| StringIdObject { id: m.id })
     }
 
+    /// Extract the details of a media item from an arbitrary web page so that they
+    /// can be reviewed before creating a custom media item.
+    async fn scrape_custom_metadata(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ScrapeCustomMetadataInput,
+    ) -> Result<CreateCustomMetadataInput> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        self.user_id_from_ctx(gql_ctx).await?;
+        service.scrape_custom_metadata(input).await
+    }
+
     /// Update custom metadata.
     async fn update_custom_metadata(
         &self,
@@ -341,6 +358,20 @@ impl MiscellaneousMutation {
             .await
     }
 
+    /// Switch the primary provider of a media item to one of its equivalent items on
+    /// another provider. All history, reviews and collections are retained and the
+    /// episodes they refer to are matched to the episodes of the new provider.
+    /// Admin only.
+    async fn switch_metadata_source(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: SwitchMetadataSourceInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = self.user_id_from_ctx(gql_ctx).await?;
+        service.switch_metadata_source(&user_id, input).await
+    }
+
     /// Delete all history and reviews for a given media item and remove it from all
     /// collections for the user.
     async fn disassociate_metadata(
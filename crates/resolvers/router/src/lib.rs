@@ -3,13 +3,15 @@ use std::{fs::write, path::PathBuf, sync::Arc};
 use anyhow::Result;
 use async_graphql::http::GraphiQLSource;
 use axum::{
-    extract::{Multipart, Path},
-    http::StatusCode,
+    extract::{Multipart, Path, Query},
+    http::{header, StatusCode},
     response::{Html, IntoResponse},
     Extension, Json,
 };
 use common_utils::{ryot_log, TEMP_DIR};
 use integration_service::IntegrationService;
+use media_models::UserCalendarFeedInput;
+use miscellaneous_service::MiscellaneousService;
 use nanoid::nanoid;
 use serde_json::json;
 
@@ -61,3 +63,24 @@ pub async fn integration_webhook(
         })?;
     Ok((StatusCode::OK, response))
 }
+
+/// Serve the calendar of a user in the iCalendar format. The token can optionally end
+/// with `.ics` since some calendar applications expect the extension.
+pub async fn calendar_feed(
+    Path(token): Path<String>,
+    Query(input): Query<UserCalendarFeedInput>,
+    Extension(miscellaneous_service): Extension<Arc<MiscellaneousService>>,
+) -> std::result::Result<impl IntoResponse, StatusCode> {
+    let token = token.trim_end_matches(".ics").to_owned();
+    let feed = miscellaneous_service
+        .user_calendar_feed(token, input)
+        .await
+        .map_err(|e| {
+            ryot_log!(debug, "{:?}", e);
+            StatusCode::NOT_FOUND
+        })?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        feed,
+    ))
+}
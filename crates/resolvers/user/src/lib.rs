@@ -2,13 +2,15 @@ use std::sync::Arc;
 
 use async_graphql::{Context, Object, Result};
 use common_models::StringIdObject;
-use database_models::{access_link, integration, notification_platform, user};
-use dependent_models::{UserDetailsResult, UserMetadataRecommendationsResponse};
+use database_models::{access_link, integration, user};
+use dependent_models::{
+    UserDetailsResult, UserMetadataRecommendationsResponse, UserNotificationPlatform,
+};
 use media_models::{
     AuthUserInput, CreateAccessLinkInput, CreateUserIntegrationInput,
-    CreateUserNotificationPlatformInput, LoginResult, OidcTokenOutput, ProcessAccessLinkInput,
-    ProcessAccessLinkResult, RegisterResult, RegisterUserInput, UpdateUserIntegrationInput,
-    UpdateUserNotificationPlatformInput,
+    CreateUserNotificationPlatformInput, LoginResult, MalAuthorizationUrl, OidcTokenOutput,
+    ProcessAccessLinkInput, ProcessAccessLinkResult, RegisterResult, RegisterUserInput,
+    TraktDeviceCode, UpdateUserIntegrationInput, UpdateUserNotificationPlatformInput,
 };
 use traits::AuthProvider;
 use user_models::{UpdateUserInput, UserPreferences};
@@ -66,7 +68,7 @@ impl UserQuery {
     async fn user_notification_platforms(
         &self,
         gql_ctx: &Context<'_>,
-    ) -> Result<Vec<notification_platform::Model>> {
+    ) -> Result<Vec<UserNotificationPlatform>> {
         let service = gql_ctx.data_unchecked::<Arc<UserService>>();
         let user_id = self.user_id_from_ctx(gql_ctx).await?;
         service.user_notification_platforms(&user_id).await
@@ -184,6 +186,22 @@ impl UserMutation {
         service.update_user_preference(user_id, input).await
     }
 
+    /// Start logging in to Trakt. The returned code has to be approved on Trakt before
+    /// creating the integration with it.
+    async fn initiate_trakt_device_login(&self, gql_ctx: &Context<'_>) -> Result<TraktDeviceCode> {
+        let service = gql_ctx.data_unchecked::<Arc<UserService>>();
+        self.user_id_from_ctx(gql_ctx).await?;
+        service.initiate_trakt_device_login().await
+    }
+
+    /// Get the page where the user can authorize Ryot to access their MyAnimeList
+    /// account. The code it redirects to is used to create the integration.
+    async fn initiate_mal_login(&self, gql_ctx: &Context<'_>) -> Result<MalAuthorizationUrl> {
+        let service = gql_ctx.data_unchecked::<Arc<UserService>>();
+        self.user_id_from_ctx(gql_ctx).await?;
+        service.initiate_mal_login().await
+    }
+
     /// Create an integration for the currently logged in user.
     async fn create_user_integration(
         &self,
@@ -272,6 +290,14 @@ impl UserMutation {
         service.generate_auth_token(user_id).await
     }
 
+    /// Generate a token to subscribe to the calendar feed of the user. Any token
+    /// generated previously stops working.
+    async fn generate_calendar_feed_token(&self, gql_ctx: &Context<'_>) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<UserService>>();
+        let user_id = self.user_id_from_ctx(gql_ctx).await?;
+        service.generate_calendar_feed_token(user_id).await
+    }
+
     /// Refresh the user metadata recommendations.
     async fn refresh_user_metadata_recommendations(&self, gql_ctx: &Context<'_>) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<UserService>>();
@@ -47,8 +47,11 @@ impl CacheService {
 
             ApplicationCacheKey::YoutubeMusicSongListened { .. } => 24,
 
+            ApplicationCacheKey::SteamGameMapping { .. } => 720,
+
             ApplicationCacheKey::IgdbSettings
             | ApplicationCacheKey::TmdbSettings
+            | ApplicationCacheKey::TvdbSettings
             | ApplicationCacheKey::ListennotesSettings => 120,
         }
     }
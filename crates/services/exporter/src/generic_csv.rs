@@ -0,0 +1,376 @@
+use std::{
+    fs::{self, File},
+    io,
+    path::PathBuf,
+};
+
+use async_graphql::Result;
+use chrono::NaiveDate;
+use csv::{Writer, WriterBuilder};
+use enum_models::{EntityLot, ExerciseLot, MediaLot, MediaSource, Visibility};
+use fitness_models::SetLot;
+use media_models::ImportOrExportItemRating;
+use rust_decimal::Decimal;
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use super::{ExportRecord, ExportWriter};
+
+const SEEN_HISTORY_FILE: &str = "seen_history.csv";
+const REVIEWS_FILE: &str = "reviews.csv";
+const COLLECTIONS_FILE: &str = "collections.csv";
+const WORKOUT_SETS_FILE: &str = "workout_sets.csv";
+const MEASUREMENTS_FILE: &str = "measurements.csv";
+
+#[derive(Debug, Default, Serialize)]
+struct SeenHistoryRow {
+    lot: MediaLot,
+    source: MediaSource,
+    identifier: String,
+    title: String,
+    progress: Option<Decimal>,
+    started_on: Option<NaiveDate>,
+    ended_on: Option<NaiveDate>,
+    provider_watched_on: Option<String>,
+    show_season_number: Option<i32>,
+    show_episode_number: Option<i32>,
+    podcast_episode_number: Option<i32>,
+    anime_episode_number: Option<i32>,
+    manga_chapter_number: Option<Decimal>,
+    manga_volume_number: Option<i32>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ReviewRow {
+    entity_lot: EntityLot,
+    lot: Option<MediaLot>,
+    source: Option<MediaSource>,
+    identifier: String,
+    title: String,
+    rating: Option<Decimal>,
+    text: Option<String>,
+    posted_on: Option<DateTimeUtc>,
+    is_spoiler: Option<bool>,
+    visibility: Option<Visibility>,
+    show_season_number: Option<i32>,
+    show_episode_number: Option<i32>,
+    podcast_episode_number: Option<i32>,
+    anime_episode_number: Option<i32>,
+    manga_chapter_number: Option<Decimal>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CollectionRow {
+    collection: String,
+    entity_lot: EntityLot,
+    lot: Option<MediaLot>,
+    source: Option<MediaSource>,
+    identifier: String,
+    title: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct WorkoutSetRow {
+    workout_id: String,
+    workout_name: String,
+    workout_start_time: DateTimeUtc,
+    workout_end_time: DateTimeUtc,
+    exercise_index: usize,
+    exercise_id: String,
+    exercise_lot: ExerciseLot,
+    set_index: usize,
+    set_lot: SetLot,
+    reps: Option<Decimal>,
+    weight: Option<Decimal>,
+    duration: Option<Decimal>,
+    distance: Option<Decimal>,
+    rpe: Option<u8>,
+    rest_time: Option<u16>,
+    note: Option<String>,
+    confirmed_at: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct MeasurementRow {
+    timestamp: DateTimeUtc,
+    name: Option<String>,
+    comment: Option<String>,
+    statistic: String,
+    value: Decimal,
+}
+
+/// The entity that a review or collection membership row refers to.
+struct RowEntity<'a> {
+    title: &'a str,
+    identifier: &'a str,
+    entity_lot: EntityLot,
+    lot: Option<MediaLot>,
+    source: Option<MediaSource>,
+}
+
+/// Get the column names of a row by serializing an empty instance of it.
+fn headers_for<T: Serialize + Default>() -> Result<Vec<String>> {
+    let mut writer = Writer::from_writer(vec![]);
+    writer.serialize(T::default())?;
+    let data = writer.into_inner().map_err(|e| e.into_error())?;
+    let headers = csv::Reader::from_reader(data.as_slice())
+        .headers()?
+        .iter()
+        .map(String::from)
+        .collect();
+    Ok(headers)
+}
+
+fn create_writer<T: Serialize + Default>(path: PathBuf) -> Result<Writer<File>> {
+    let mut writer = WriterBuilder::new().has_headers(false).from_path(path)?;
+    writer.write_record(headers_for::<T>()?)?;
+    Ok(writer)
+}
+
+pub struct CsvExportWriter {
+    directory: PathBuf,
+    reviews: Writer<File>,
+    collections: Writer<File>,
+    seen_history: Writer<File>,
+    workout_sets: Writer<File>,
+    measurements: Writer<File>,
+}
+
+impl CsvExportWriter {
+    /// Create the CSV files in a new directory. They are archived to
+    /// `<directory>.zip` when the export finishes.
+    pub fn new(directory: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&directory)?;
+        Ok(Self {
+            reviews: create_writer::<ReviewRow>(directory.join(REVIEWS_FILE))?,
+            collections: create_writer::<CollectionRow>(directory.join(COLLECTIONS_FILE))?,
+            seen_history: create_writer::<SeenHistoryRow>(directory.join(SEEN_HISTORY_FILE))?,
+            workout_sets: create_writer::<WorkoutSetRow>(directory.join(WORKOUT_SETS_FILE))?,
+            measurements: create_writer::<MeasurementRow>(directory.join(MEASUREMENTS_FILE))?,
+            directory,
+        })
+    }
+
+    fn write_reviews(
+        &mut self,
+        entity: &RowEntity<'_>,
+        reviews: &[ImportOrExportItemRating],
+    ) -> Result<()> {
+        for rev in reviews {
+            let review = rev.review.clone().unwrap_or_default();
+            self.reviews.serialize(ReviewRow {
+                lot: entity.lot,
+                rating: rev.rating,
+                text: review.text,
+                source: entity.source,
+                posted_on: review.date,
+                is_spoiler: review.spoiler,
+                entity_lot: entity.entity_lot,
+                visibility: review.visibility,
+                title: entity.title.to_owned(),
+                show_season_number: rev.show_season_number,
+                identifier: entity.identifier.to_owned(),
+                show_episode_number: rev.show_episode_number,
+                anime_episode_number: rev.anime_episode_number,
+                manga_chapter_number: rev.manga_chapter_number,
+                podcast_episode_number: rev.podcast_episode_number,
+            })?;
+        }
+        Ok(())
+    }
+
+    fn write_collections(&mut self, entity: &RowEntity<'_>, collections: &[String]) -> Result<()> {
+        for collection in collections {
+            self.collections.serialize(CollectionRow {
+                lot: entity.lot,
+                source: entity.source,
+                entity_lot: entity.entity_lot,
+                collection: collection.to_owned(),
+                title: entity.title.to_owned(),
+                identifier: entity.identifier.to_owned(),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl ExportWriter for CsvExportWriter {
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()> {
+        match record {
+            ExportRecord::Metadata { item: m, .. } => {
+                for seen in m.seen_history.iter() {
+                    self.seen_history.serialize(SeenHistoryRow {
+                        lot: m.lot,
+                        source: m.source,
+                        ended_on: seen.ended_on,
+                        progress: seen.progress,
+                        title: m.source_id.clone(),
+                        started_on: seen.started_on,
+                        identifier: m.identifier.clone(),
+                        show_season_number: seen.show_season_number,
+                        manga_volume_number: seen.manga_volume_number,
+                        show_episode_number: seen.show_episode_number,
+                        provider_watched_on: seen.provider_watched_on.clone(),
+                        anime_episode_number: seen.anime_episode_number,
+                        manga_chapter_number: seen.manga_chapter_number,
+                        podcast_episode_number: seen.podcast_episode_number,
+                    })?;
+                }
+                let entity = RowEntity {
+                    lot: Some(m.lot),
+                    title: &m.source_id,
+                    source: Some(m.source),
+                    identifier: &m.identifier,
+                    entity_lot: EntityLot::Metadata,
+                };
+                self.write_reviews(&entity, &m.reviews)?;
+                self.write_collections(&entity, &m.collections)?;
+            }
+            ExportRecord::MetadataGroup(g) => {
+                let entity = RowEntity {
+                    lot: Some(g.lot),
+                    title: &g.title,
+                    source: Some(g.source),
+                    identifier: &g.identifier,
+                    entity_lot: EntityLot::MetadataGroup,
+                };
+                self.write_reviews(&entity, &g.reviews)?;
+                self.write_collections(&entity, &g.collections)?;
+            }
+            ExportRecord::Person(p) => {
+                let entity = RowEntity {
+                    lot: None,
+                    title: &p.name,
+                    source: Some(p.source),
+                    identifier: &p.identifier,
+                    entity_lot: EntityLot::Person,
+                };
+                self.write_reviews(&entity, &p.reviews)?;
+                self.write_collections(&entity, &p.collections)?;
+            }
+            ExportRecord::Exercise(e) => {
+                let entity = RowEntity {
+                    lot: None,
+                    source: None,
+                    title: &e.name,
+                    identifier: &e.id,
+                    entity_lot: EntityLot::Exercise,
+                };
+                self.write_reviews(&entity, &e.reviews)?;
+                self.write_collections(&entity, &e.collections)?;
+            }
+            ExportRecord::Workout(w) => {
+                let workout = &w.details;
+                for (exercise_index, exercise) in workout.information.exercises.iter().enumerate() {
+                    for (set_index, set) in exercise.sets.iter().enumerate() {
+                        self.workout_sets.serialize(WorkoutSetRow {
+                            set_index,
+                            exercise_index,
+                            set_lot: set.lot,
+                            rpe: set.rpe,
+                            note: set.note.clone(),
+                            rest_time: set.rest_time,
+                            exercise_lot: exercise.lot,
+                            confirmed_at: set.confirmed_at,
+                            reps: set.statistic.reps,
+                            weight: set.statistic.weight,
+                            exercise_id: exercise.id.clone(),
+                            workout_id: workout.id.clone(),
+                            duration: set.statistic.duration,
+                            distance: set.statistic.distance,
+                            workout_name: workout.name.clone(),
+                            workout_end_time: workout.end_time,
+                            workout_start_time: workout.start_time,
+                        })?;
+                    }
+                }
+                let entity = RowEntity {
+                    lot: None,
+                    source: None,
+                    title: &workout.name,
+                    identifier: &workout.id,
+                    entity_lot: EntityLot::Workout,
+                };
+                self.write_collections(&entity, &w.collections)?;
+            }
+            ExportRecord::WorkoutTemplate(t) => {
+                let entity = RowEntity {
+                    lot: None,
+                    source: None,
+                    title: &t.details.name,
+                    identifier: &t.details.id,
+                    entity_lot: EntityLot::WorkoutTemplate,
+                };
+                self.write_collections(&entity, &t.collections)?;
+            }
+            ExportRecord::Measurement(m) => {
+                // DEV: Measurements are written in a long format so that custom
+                // measurements do not change the columns of the file.
+                let serde_json::Value::Object(stats) = serde_json::to_value(&m.stats)? else {
+                    return Ok(());
+                };
+                let mut values = vec![];
+                for (statistic, value) in stats {
+                    match value {
+                        serde_json::Value::Object(custom) => {
+                            for (name, value) in custom {
+                                values.push((format!("custom.{}", name), value));
+                            }
+                        }
+                        value => values.push((statistic, value)),
+                    }
+                }
+                for (statistic, value) in values {
+                    let Ok(value) = serde_json::from_value::<Decimal>(value) else {
+                        continue;
+                    };
+                    self.measurements.serialize(MeasurementRow {
+                        value,
+                        statistic,
+                        timestamp: m.timestamp,
+                        name: m.name.clone(),
+                        comment: m.comment.clone(),
+                    })?;
+                }
+            }
+        };
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self {
+            directory,
+            mut reviews,
+            mut collections,
+            mut seen_history,
+            mut workout_sets,
+            mut measurements,
+        } = *self;
+        for writer in [
+            &mut reviews,
+            &mut collections,
+            &mut seen_history,
+            &mut workout_sets,
+            &mut measurements,
+        ] {
+            writer.flush()?;
+        }
+        let archive_path = directory.with_extension("zip");
+        let mut archive = ZipWriter::new(File::create(&archive_path)?);
+        for file_name in [
+            SEEN_HISTORY_FILE,
+            REVIEWS_FILE,
+            COLLECTIONS_FILE,
+            WORKOUT_SETS_FILE,
+            MEASUREMENTS_FILE,
+        ] {
+            archive.start_file(file_name, SimpleFileOptions::default())?;
+            let mut file = File::open(directory.join(file_name))?;
+            io::copy(&mut file, &mut archive)?;
+        }
+        archive.finish()?;
+        fs::remove_dir_all(&directory)?;
+        Ok(archive_path)
+    }
+}
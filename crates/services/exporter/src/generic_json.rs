@@ -0,0 +1,53 @@
+use std::{fs::File, path::PathBuf};
+
+use async_graphql::Result;
+use struson::writer::{JsonStreamWriter, JsonWriter};
+
+use super::{ExportItem, ExportRecord, ExportWriter};
+
+pub struct JsonExportWriter {
+    path: PathBuf,
+    writer: JsonStreamWriter<File>,
+}
+
+impl JsonExportWriter {
+    pub fn new(path: PathBuf) -> Result<Self> {
+        let file = File::create(&path)?;
+        let mut writer = JsonStreamWriter::new(file);
+        writer.begin_object()?;
+        Ok(Self { path, writer })
+    }
+}
+
+impl ExportWriter for JsonExportWriter {
+    fn begin_section(&mut self, section: ExportItem) -> Result<()> {
+        self.writer.name(&section.to_string())?;
+        self.writer.begin_array()?;
+        Ok(())
+    }
+
+    fn end_section(&mut self) -> Result<()> {
+        self.writer.end_array()?;
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()> {
+        match record {
+            ExportRecord::Person(p) => self.writer.serialize_value(p)?,
+            ExportRecord::Workout(w) => self.writer.serialize_value(w)?,
+            ExportRecord::Metadata { item, .. } => self.writer.serialize_value(item)?,
+            ExportRecord::Exercise(e) => self.writer.serialize_value(e)?,
+            ExportRecord::Measurement(m) => self.writer.serialize_value(m)?,
+            ExportRecord::MetadataGroup(g) => self.writer.serialize_value(g)?,
+            ExportRecord::WorkoutTemplate(t) => self.writer.serialize_value(t)?,
+        };
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self { path, mut writer } = *self;
+        writer.end_object()?;
+        writer.finish_document()?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,144 @@
+use std::{fs::File, path::PathBuf};
+
+use async_graphql::Result;
+use chrono::NaiveDate;
+use csv::Writer;
+use enum_models::MediaLot;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use serde::{Serialize, Serializer};
+use user_models::UserReviewScale;
+
+use super::{rating_out_of_hundred, ExportItem, ExportRecord, ExportWriter};
+
+fn serialize_date<S: Serializer>(date: &Option<NaiveDate>, s: S) -> Result<S::Ok, S::Error> {
+    match date {
+        Some(date) => s.serialize_str(&date.format("%Y/%m/%d").to_string()),
+        None => s.serialize_none(),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct LibraryRow {
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "Author")]
+    author: String,
+    #[serde(rename = "ISBN")]
+    isbn: Option<String>,
+    #[serde(rename = "ISBN13")]
+    isbn13: Option<String>,
+    #[serde(rename = "My Rating")]
+    my_rating: u8,
+    #[serde(rename = "Year Published")]
+    year_published: Option<i32>,
+    #[serde(rename = "Date Read", serialize_with = "serialize_date")]
+    date_read: Option<NaiveDate>,
+    #[serde(rename = "Bookshelves")]
+    bookshelves: String,
+    #[serde(rename = "Exclusive Shelf")]
+    exclusive_shelf: &'static str,
+    #[serde(rename = "My Review")]
+    my_review: Option<String>,
+    #[serde(rename = "Read Count")]
+    read_count: usize,
+}
+
+/// Writes books in the format accepted by the Goodreads library importer.
+pub struct GoodreadsExportWriter {
+    path: PathBuf,
+    writer: Writer<File>,
+    review_scale: UserReviewScale,
+}
+
+impl GoodreadsExportWriter {
+    pub fn new(path: PathBuf, review_scale: UserReviewScale) -> Result<Self> {
+        let writer = Writer::from_path(&path)?;
+        Ok(Self {
+            path,
+            writer,
+            review_scale,
+        })
+    }
+}
+
+impl ExportWriter for GoodreadsExportWriter {
+    fn sections(&self) -> Vec<ExportItem> {
+        vec![ExportItem::Metadata]
+    }
+
+    fn needs_authors(&self) -> bool {
+        true
+    }
+
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()> {
+        let ExportRecord::Metadata {
+            item,
+            details,
+            authors,
+        } = record
+        else {
+            return Ok(());
+        };
+        if item.lot != MediaLot::Book {
+            return Ok(());
+        }
+        let review = item
+            .reviews
+            .iter()
+            .rev()
+            .find(|r| r.rating.is_some() || r.review.is_some());
+        // DEV: Goodreads only accepts whole stars and treats 0 as unrated.
+        let my_rating = review
+            .and_then(|r| r.rating)
+            .map(|r| {
+                let rating = rating_out_of_hundred(r, self.review_scale);
+                (rating / dec!(20)).round().clamp(dec!(1), dec!(5))
+            })
+            .and_then(|r| r.to_u8())
+            .unwrap_or_default();
+        let completed = item
+            .seen_history
+            .iter()
+            .filter(|s| s.progress.map_or(true, |p| p >= dec!(100)))
+            .collect::<Vec<_>>();
+        let isbns = details
+            .external_identifiers
+            .as_ref()
+            .map(|e| {
+                e.isbns
+                    .iter()
+                    .map(|i| i.replace(['-', ' '], ""))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let isbn_of_length = |length| isbns.iter().find(|i| i.len() == length).cloned();
+        let exclusive_shelf = match (completed.is_empty(), item.seen_history.is_empty()) {
+            (false, _) => "read",
+            (true, false) => "currently-reading",
+            (true, true) => "to-read",
+        };
+        self.writer.serialize(LibraryRow {
+            my_rating,
+            exclusive_shelf,
+            isbn: isbn_of_length(10),
+            isbn13: isbn_of_length(13),
+            read_count: completed.len(),
+            title: details.title.clone(),
+            author: authors.join(", "),
+            bookshelves: item.collections.join(", "),
+            year_published: details.publish_year,
+            date_read: completed.iter().filter_map(|s| s.ended_on).max(),
+            my_review: review.and_then(|r| r.review.as_ref().and_then(|r| r.text.clone())),
+        })?;
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self {
+            path, mut writer, ..
+        } = *self;
+        writer.flush()?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use async_graphql::{Error, Result};
+use chrono::{DateTime, Utc};
+use common_utils::{decrypt_with_passphrase, is_encrypted_with_passphrase, ryot_log, TEMP_DIR};
+use env_utils::APP_VERSION;
+use futures::TryStreamExt;
+use itertools::Itertools;
+use nanoid::nanoid;
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, FromQueryResult, JsonValue, Statement,
+    TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const RESTORE_CHUNK_SIZE: usize = 500;
+
+/// The tables that are part of an instance backup, in the order in which they need to be
+/// restored so that foreign keys are satisfied. The application cache and views are
+/// skipped since they are derived from the other tables.
+const BACKUP_TABLES: [&str; 27] = [
+    "user",
+    "metadata",
+    "person",
+    "metadata_group",
+    "genre",
+    "exercise",
+    "collection",
+    "metadata_to_genre",
+    "metadata_to_metadata",
+    "metadata_to_metadata_group",
+    "metadata_to_person",
+    "metadata_group_to_person",
+    "review",
+    "seen",
+    "workout_template",
+    "workout",
+    "user_measurement",
+    "user_to_entity",
+    "collection_to_entity",
+    "calendar_event",
+    "integration",
+    "notification_platform",
+    "access_link",
+    "import_report",
+    "daily_user_activity",
+    "user_notification",
+    "notification_delivery",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct InstanceBackupManifest {
+    version: String,
+    created_at: DateTime<Utc>,
+    tables: HashMap<String, usize>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct BackupRow {
+    row: JsonValue,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TableColumn {
+    column_name: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TableForeignKey {
+    table_name: String,
+    referenced_table_name: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TableIsEmpty {
+    is_empty: bool,
+}
+
+fn table_file_name(table: &str) -> String {
+    format!("{}.jsonl", table)
+}
+
+/// Make sure that every table in `BACKUP_TABLES` comes after the tables it references,
+/// since the rows are inserted in that order without deferring the constraints.
+async fn check_backup_table_order<C: ConnectionTrait>(db: &C) -> Result<()> {
+    let foreign_keys = TableForeignKey::find_by_statement(Statement::from_string(
+        DatabaseBackend::Postgres,
+        r#"
+SELECT DISTINCT "tc"."table_name"::TEXT AS "table_name",
+"ccu"."table_name"::TEXT AS "referenced_table_name"
+FROM "information_schema"."table_constraints" "tc"
+JOIN "information_schema"."constraint_column_usage" "ccu"
+ON "tc"."constraint_name" = "ccu"."constraint_name"
+AND "tc"."table_schema" = "ccu"."table_schema"
+WHERE "tc"."constraint_type" = 'FOREIGN KEY' AND "tc"."table_schema" = current_schema()
+        "#,
+    ))
+    .all(db)
+    .await?;
+    let position = |table: &str| BACKUP_TABLES.iter().position(|t| *t == table);
+    for key in foreign_keys {
+        let (Some(table), Some(referenced)) = (
+            position(&key.table_name),
+            position(&key.referenced_table_name),
+        ) else {
+            continue;
+        };
+        if referenced > table {
+            return Err(Error::new(format!(
+                "Table {} references {} and can not be restored before it",
+                key.table_name, key.referenced_table_name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Write all the rows of the instance to a ZIP archive containing one JSON lines file
+/// per table and a manifest.
+pub async fn create_instance_backup(db: &DatabaseConnection, path: &Path) -> Result<()> {
+    let mut archive = ZipWriter::new(File::create(path)?);
+    let mut tables = HashMap::new();
+    for table in BACKUP_TABLES {
+        ryot_log!(debug, "Backing up table {}", table);
+        let mut rows = BackupRow::find_by_statement(Statement::from_string(
+            DatabaseBackend::Postgres,
+            format!(r#"SELECT to_jsonb("t") AS "row" FROM "{}" "t""#, table),
+        ))
+        .stream(db)
+        .await?;
+        archive.start_file(table_file_name(table), SimpleFileOptions::default())?;
+        let mut count = 0;
+        while let Some(row) = rows.try_next().await? {
+            serde_json::to_writer(&mut archive, &row.row)?;
+            archive.write_all(b"\n")?;
+            count += 1;
+        }
+        tables.insert(table.to_owned(), count);
+    }
+    let manifest = InstanceBackupManifest {
+        tables,
+        created_at: Utc::now(),
+        version: APP_VERSION.to_owned(),
+    };
+    archive.start_file(MANIFEST_FILE, SimpleFileOptions::default())?;
+    serde_json::to_writer_pretty(&mut archive, &manifest)?;
+    archive.finish()?;
+    Ok(())
+}
+
+/// Restore an archive created by `create_instance_backup`. The database must have all
+/// migrations applied and must not contain any data. If the archive was encrypted, the
+/// passphrase it was encrypted with is required.
+pub async fn restore_instance_backup(
+    db: &DatabaseConnection,
+    path: &Path,
+    passphrase: Option<&str>,
+) -> Result<()> {
+    let mut header = vec![];
+    File::open(path)?.take(16).read_to_end(&mut header)?;
+    if !is_encrypted_with_passphrase(&header) {
+        return restore_archive(db, path).await;
+    }
+    let Some(passphrase) = passphrase else {
+        return Err(Error::new(
+            "A passphrase is required to restore this backup",
+        ));
+    };
+    ryot_log!(debug, "Decrypting backup");
+    let decrypted_path = PathBuf::from(TEMP_DIR).join(format!("ryot-restore-{}.zip", nanoid!()));
+    let result = match decrypt_with_passphrase(
+        BufReader::new(File::open(path)?),
+        BufWriter::new(File::create(&decrypted_path)?),
+        passphrase,
+    ) {
+        Ok(_) => restore_archive(db, &decrypted_path).await,
+        Err(e) => Err(Error::new(e.to_string())),
+    };
+    fs::remove_file(&decrypted_path)?;
+    result
+}
+
+async fn restore_archive(db: &DatabaseConnection, path: &Path) -> Result<()> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let manifest: InstanceBackupManifest =
+        serde_json::from_reader(archive.by_name(MANIFEST_FILE)?)?;
+    ryot_log!(
+        info,
+        "Restoring {} rows from backup created at {}",
+        manifest.tables.values().sum::<usize>(),
+        manifest.created_at
+    );
+    if manifest.version != APP_VERSION {
+        ryot_log!(
+            warn,
+            "Backup was created by version {} but running version {}",
+            manifest.version,
+            APP_VERSION
+        );
+    }
+    let txn = db.begin().await?;
+    check_backup_table_order(&txn).await?;
+    for table in BACKUP_TABLES {
+        let status = TableIsEmpty::find_by_statement(Statement::from_string(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"SELECT NOT EXISTS (SELECT 1 FROM "{}") AS "is_empty""#,
+                table
+            ),
+        ))
+        .one(&txn)
+        .await?;
+        if !status.map(|s| s.is_empty).unwrap_or_default() {
+            return Err(Error::new(format!(
+                "Table {} is not empty, a backup can only be restored into an empty database",
+                table
+            )));
+        }
+    }
+    for table in BACKUP_TABLES {
+        let Ok(file) = archive.by_name(&table_file_name(table)) else {
+            ryot_log!(warn, "Backup does not contain table {}", table);
+            continue;
+        };
+        ryot_log!(debug, "Restoring table {}", table);
+        // DEV: Generated columns can not be inserted into, so only the columns that are
+        // actually stored are populated from the backup.
+        let columns = TableColumn::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"
+SELECT "column_name"::TEXT AS "column_name" FROM "information_schema"."columns"
+WHERE "table_schema" = current_schema() AND "table_name" = $1 AND "is_generated" = 'NEVER'
+ORDER BY "ordinal_position"
+            "#,
+            [table.into()],
+        ))
+        .all(&txn)
+        .await?
+        .into_iter()
+        .map(|c| format!(r#""{}""#, c.column_name))
+        .join(", ");
+        let sql = format!(
+            r#"INSERT INTO "{table}" ({columns}) SELECT {columns} FROM jsonb_populate_recordset(NULL::"{table}", $1)"#,
+        );
+        let mut lines = BufReader::new(file).lines().enumerate().peekable();
+        while lines.peek().is_some() {
+            let mut chunk = Vec::with_capacity(RESTORE_CHUNK_SIZE);
+            for (index, line) in lines.by_ref().take(RESTORE_CHUNK_SIZE) {
+                let row = line
+                    .map_err(|e| e.to_string())
+                    .and_then(|l| serde_json::from_str::<JsonValue>(&l).map_err(|e| e.to_string()))
+                    .map_err(|e| {
+                        Error::new(format!(
+                            "Could not read line {} of table {}: {}",
+                            index + 1,
+                            table,
+                            e
+                        ))
+                    })?;
+                chunk.push(row);
+            }
+            txn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                &sql,
+                [JsonValue::Array(chunk).into()],
+            ))
+            .await?;
+        }
+    }
+    txn.commit().await?;
+    Ok(())
+}
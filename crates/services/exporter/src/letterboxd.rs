@@ -0,0 +1,118 @@
+use std::{fs::File, path::PathBuf};
+
+use async_graphql::Result;
+use chrono::NaiveDate;
+use csv::Writer;
+use enum_models::{MediaLot, MediaSource};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+use user_models::UserReviewScale;
+
+use super::{rating_out_of_hundred, ExportItem, ExportRecord, ExportWriter};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct DiaryRow {
+    title: String,
+    year: Option<i32>,
+    #[serde(rename = "tmdbID")]
+    tmdb_id: String,
+    watched_date: Option<NaiveDate>,
+    rating: Option<Decimal>,
+    rewatch: Option<bool>,
+    review: Option<String>,
+    tags: Option<String>,
+}
+
+/// Writes movies in the format accepted by the Letterboxd diary importer.
+pub struct LetterboxdExportWriter {
+    path: PathBuf,
+    writer: Writer<File>,
+    review_scale: UserReviewScale,
+}
+
+impl LetterboxdExportWriter {
+    pub fn new(path: PathBuf, review_scale: UserReviewScale) -> Result<Self> {
+        let writer = Writer::from_path(&path)?;
+        Ok(Self {
+            path,
+            writer,
+            review_scale,
+        })
+    }
+}
+
+impl ExportWriter for LetterboxdExportWriter {
+    fn sections(&self) -> Vec<ExportItem> {
+        vec![ExportItem::Metadata]
+    }
+
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()> {
+        let ExportRecord::Metadata { item, details, .. } = record else {
+            return Ok(());
+        };
+        if item.lot != MediaLot::Movie || item.source != MediaSource::Tmdb {
+            return Ok(());
+        }
+        let review = item
+            .reviews
+            .iter()
+            .rev()
+            .find(|r| r.rating.is_some() || r.review.is_some());
+        // DEV: Letterboxd accepts ratings from 0.5 to 5 in steps of 0.5.
+        let rating = review.and_then(|r| r.rating).map(|r| {
+            let rating = rating_out_of_hundred(r, self.review_scale);
+            ((rating / dec!(10)).round() / dec!(2)).clamp(dec!(0.5), dec!(5))
+        });
+        let text = review.and_then(|r| r.review.as_ref().and_then(|r| r.text.clone()));
+        let tags = match item.collections.is_empty() {
+            true => None,
+            false => Some(item.collections.join(", ")),
+        };
+        let row = |watched_date, rewatch, rating, review| DiaryRow {
+            rating,
+            review,
+            rewatch,
+            watched_date,
+            tags: tags.clone(),
+            year: details.publish_year,
+            title: details.title.clone(),
+            tmdb_id: item.identifier.clone(),
+        };
+        let watches = item
+            .seen_history
+            .iter()
+            .filter(|s| s.progress.map_or(true, |p| p >= dec!(100)))
+            .collect::<Vec<_>>();
+        if watches.is_empty() {
+            if rating.is_some() || text.is_some() {
+                self.writer.serialize(row(None, None, rating, text))?;
+            }
+            return Ok(());
+        }
+        let last_index = watches.len() - 1;
+        for (index, seen) in watches.into_iter().enumerate() {
+            // DEV: The rating and review are attached to the most recent watch only.
+            let (rating, text) = match index == last_index {
+                true => (rating, text.clone()),
+                false => (None, None),
+            };
+            self.writer.serialize(row(
+                seen.ended_on.or(seen.started_on),
+                Some(index > 0),
+                rating,
+                text,
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self {
+            path, mut writer, ..
+        } = *self;
+        writer.flush()?;
+        Ok(path)
+    }
+}
@@ -1,44 +1,77 @@
-use std::{collections::HashMap, fs::File as StdFile, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use async_graphql::{Error, Result};
 use background_models::{ApplicationJob, MpApplicationJob};
 use chrono::{DateTime, Utc};
 use common_models::{ExportJob, SearchInput};
-use common_utils::{ryot_log, TEMP_DIR};
+use common_utils::{encrypt_with_passphrase, ryot_log, TEMP_DIR};
 use database_models::{
-    prelude::{Exercise, Metadata, MetadataGroup, Person, Seen},
-    seen,
+    metadata, metadata_to_person,
+    prelude::{Exercise, Metadata, MetadataGroup, MetadataToPerson, Person, Seen},
+    seen, user_measurement,
 };
 use database_utils::{
-    entity_in_collections, item_reviews, user_measurements_list, user_workout_details,
-    user_workout_template_details,
+    admin_account_guard, entity_in_collections, item_reviews, user_by_id, user_measurements_list,
+    user_workout_details, user_workout_template_details,
 };
 use dependent_models::{ImportOrExportWorkoutItem, ImportOrExportWorkoutTemplateItem};
 use dependent_utils::{
     exercises_list, metadata_groups_list, metadata_list, people_list, user_workout_templates_list,
     user_workouts_list,
 };
-use enum_models::EntityLot;
+use enum_models::{EntityLot, ExportFormat};
 use fitness_models::{ExercisesListInput, UserMeasurementsListInput};
 use itertools::Itertools;
 use media_models::{
-    ImportOrExportExerciseItem, ImportOrExportItemRating, ImportOrExportItemReview,
-    ImportOrExportMetadataGroupItem, ImportOrExportMetadataItem, ImportOrExportMetadataItemSeen,
-    ImportOrExportPersonItem, MetadataGroupsListInput, MetadataListInput, PeopleListInput,
-    ReviewItem,
+    DeployExportJobInput, ImportOrExportExerciseItem, ImportOrExportItemRating,
+    ImportOrExportItemReview, ImportOrExportMetadataGroupItem, ImportOrExportMetadataItem,
+    ImportOrExportMetadataItemSeen, ImportOrExportPersonItem, MetadataGroupsListInput,
+    MetadataListInput, PeopleListInput, ReviewItem,
 };
 use nanoid::nanoid;
 use reqwest::{
     header::{CONTENT_LENGTH, CONTENT_TYPE},
     Body, Client,
 };
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use sea_orm::{
     strum::Display, ColumnTrait, EntityTrait, EnumIter, Iterable, ModelTrait, QueryFilter,
+    QueryOrder,
 };
-use struson::writer::{JsonStreamWriter, JsonWriter};
 use supporting_service::SupportingService;
-use tokio::fs::File;
+use tokio::fs::{self, File};
 use tokio_util::codec::{BytesCodec, FramedRead};
+use user_models::UserReviewScale;
+
+mod generic_csv;
+mod generic_json;
+mod goodreads;
+mod instance_backup;
+mod letterboxd;
+mod trakt;
+
+pub use instance_backup::{create_instance_backup, restore_instance_backup};
+
+/// Encrypt the export or backup at the given path and replace it with a file that has an
+/// `.enc` extension. The file is streamed through the cipher so that it is never held in
+/// memory as a whole.
+pub async fn encrypt_export(path: PathBuf, passphrase: &str) -> Result<PathBuf> {
+    let mut encrypted_path = path.clone();
+    encrypted_path.as_mut_os_string().push(".enc");
+    encrypt_with_passphrase(
+        BufReader::new(std::fs::File::open(&path)?),
+        BufWriter::new(std::fs::File::create(&encrypted_path)?),
+        passphrase,
+    )?;
+    fs::remove_file(&path).await?;
+    Ok(encrypted_path)
+}
 
 #[derive(Eq, PartialEq, Copy, Display, Clone, Debug, EnumIter)]
 #[strum(serialize_all = "snake_case")]
@@ -52,12 +85,68 @@ enum ExportItem {
     WorkoutTemplates,
 }
 
+/// A single entity produced while traversing the data of a user.
+enum ExportRecord<'a> {
+    Person(&'a ImportOrExportPersonItem),
+    Workout(&'a ImportOrExportWorkoutItem),
+    Metadata {
+        item: &'a ImportOrExportMetadataItem,
+        details: &'a metadata::Model,
+        authors: &'a [String],
+    },
+    Exercise(&'a ImportOrExportExerciseItem),
+    Measurement(&'a user_measurement::Model),
+    MetadataGroup(&'a ImportOrExportMetadataGroupItem),
+    WorkoutTemplate(&'a ImportOrExportWorkoutTemplateItem),
+}
+
+/// The destination of an export. Each format decides how the traversed records are
+/// laid out on disk.
+trait ExportWriter: Send {
+    /// The sections of the export that this format needs to be traversed.
+    fn sections(&self) -> Vec<ExportItem> {
+        ExportItem::iter().collect()
+    }
+
+    /// Whether the authors of each media item need to be fetched.
+    fn needs_authors(&self) -> bool {
+        false
+    }
+
+    fn begin_section(&mut self, _section: ExportItem) -> Result<()> {
+        Ok(())
+    }
+
+    fn end_section(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()>;
+
+    /// Flush everything to disk and return the path of the file to upload.
+    fn finish(self: Box<Self>) -> Result<PathBuf>;
+}
+
+/// Convert a rating in the review scale preferred by the user to a rating out of 100.
+fn rating_out_of_hundred(rating: Decimal, review_scale: UserReviewScale) -> Decimal {
+    match review_scale {
+        UserReviewScale::OutOfFive => rating * dec!(20),
+        UserReviewScale::OutOfHundred | UserReviewScale::ThreePointSmiley => rating,
+    }
+}
+
 pub struct ExporterService(pub Arc<SupportingService>);
 
 impl ExporterService {
-    pub async fn deploy_export_job(&self, user_id: String) -> Result<bool> {
+    pub async fn deploy_export_job(
+        &self,
+        user_id: String,
+        input: DeployExportJobInput,
+    ) -> Result<bool> {
         self.0
-            .perform_application_job(ApplicationJob::Mp(MpApplicationJob::PerformExport(user_id)))
+            .perform_application_job(ApplicationJob::Mp(MpApplicationJob::PerformExport(
+                user_id, input,
+            )))
             .await?;
         Ok(true)
     }
@@ -101,39 +190,104 @@ impl ExporterService {
         Ok(resp)
     }
 
-    pub async fn perform_export(&self, user_id: String) -> Result<()> {
+    pub async fn perform_export(&self, user_id: String, input: DeployExportJobInput) -> Result<()> {
         if !self.0.config.file_storage.is_enabled() {
             return Err(Error::new(
                 "File storage needs to be enabled to perform an export.",
             ));
         }
         let started_at = Utc::now();
-        let export_path = PathBuf::from(TEMP_DIR).join(format!("ryot-export-{}.json", nanoid!()));
-        let file = std::fs::File::create(&export_path).unwrap();
-        let mut writer = JsonStreamWriter::new(file);
-        writer.begin_object().unwrap();
-        for export in ExportItem::iter() {
+        let export_name = format!("ryot-export-{}", nanoid!());
+        let export_path = PathBuf::from(TEMP_DIR).join(&export_name);
+        let review_scale = user_by_id(&user_id, &self.0)
+            .await?
+            .preferences
+            .general
+            .review_scale;
+        let mut writer: Box<dyn ExportWriter> = match input.format {
+            ExportFormat::GenericJson => Box::new(generic_json::JsonExportWriter::new(
+                export_path.with_extension("json"),
+            )?),
+            ExportFormat::GenericCsv => Box::new(generic_csv::CsvExportWriter::new(export_path)?),
+            ExportFormat::Letterboxd => Box::new(letterboxd::LetterboxdExportWriter::new(
+                export_path.with_extension("csv"),
+                review_scale,
+            )?),
+            ExportFormat::Goodreads => Box::new(goodreads::GoodreadsExportWriter::new(
+                export_path.with_extension("csv"),
+                review_scale,
+            )?),
+            ExportFormat::Trakt => Box::new(trakt::TraktExportWriter::new(
+                export_path.with_extension("json"),
+                review_scale,
+            )?),
+        };
+        for export in writer.sections() {
             ryot_log!(debug, "Exporting {export}");
-            writer.name(&export.to_string())?;
-            writer.begin_array().unwrap();
+            writer.begin_section(export)?;
+            let writer = writer.as_mut();
             match export {
-                ExportItem::Metadata => self.export_media(&user_id, &mut writer).await?,
-                ExportItem::People => self.export_people(&user_id, &mut writer).await?,
-                ExportItem::Workouts => self.export_workouts(&user_id, &mut writer).await?,
-                ExportItem::Exercises => self.export_exercises(&user_id, &mut writer).await?,
-                ExportItem::MetadataGroups => {
-                    self.export_media_group(&user_id, &mut writer).await?
-                }
-                ExportItem::Measurements => self.export_measurements(&user_id, &mut writer).await?,
+                ExportItem::Metadata => self.export_media(&user_id, writer).await?,
+                ExportItem::People => self.export_people(&user_id, writer).await?,
+                ExportItem::Workouts => self.export_workouts(&user_id, writer).await?,
+                ExportItem::Exercises => self.export_exercises(&user_id, writer).await?,
+                ExportItem::MetadataGroups => self.export_media_group(&user_id, writer).await?,
+                ExportItem::Measurements => self.export_measurements(&user_id, writer).await?,
                 ExportItem::WorkoutTemplates => {
-                    self.export_workout_templates(&user_id, &mut writer).await?
+                    self.export_workout_templates(&user_id, writer).await?
                 }
             };
-            writer.end_array().unwrap();
+            writer.end_section()?;
+        }
+        let mut export_path = writer.finish()?;
+        if let Some(passphrase) = input.passphrase.filter(|p| !p.is_empty()) {
+            ryot_log!(debug, "Encrypting export");
+            export_path = encrypt_export(export_path, &passphrase).await?;
         }
-        writer.end_object().unwrap();
-        writer.finish_document().unwrap();
         ryot_log!(debug, "Exporting completed");
+        self.upload_export(&user_id, &export_path, started_at).await
+    }
+
+    pub async fn deploy_instance_backup_job(
+        &self,
+        user_id: String,
+        passphrase: String,
+    ) -> Result<bool> {
+        admin_account_guard(&user_id, &self.0).await?;
+        if passphrase.is_empty() {
+            return Err(Error::new("A passphrase is required to perform a backup."));
+        }
+        if !self.0.config.file_storage.is_enabled() {
+            return Err(Error::new(
+                "File storage needs to be enabled to perform a backup.",
+            ));
+        }
+        self.0
+            .perform_application_job(ApplicationJob::Mp(MpApplicationJob::PerformInstanceBackup(
+                user_id, passphrase,
+            )))
+            .await?;
+        Ok(true)
+    }
+
+    /// Create a backup of the entire instance and upload it to the exports of the admin
+    /// that requested it. Since the backup contains the credentials of all users, it is
+    /// always encrypted before being uploaded.
+    pub async fn perform_instance_backup(&self, user_id: String, passphrase: String) -> Result<()> {
+        let started_at = Utc::now();
+        let backup_path = PathBuf::from(TEMP_DIR).join(format!("ryot-backup-{}.zip", nanoid!()));
+        create_instance_backup(&self.0.db, &backup_path).await?;
+        ryot_log!(debug, "Instance backup completed");
+        let backup_path = encrypt_export(backup_path, &passphrase).await?;
+        self.upload_export(&user_id, &backup_path, started_at).await
+    }
+
+    async fn upload_export(
+        &self,
+        user_id: &String,
+        export_path: &PathBuf,
+        started_at: DateTime<Utc>,
+    ) -> Result<()> {
         let ended_at = Utc::now();
         let (_key, url) = self
             .0
@@ -153,9 +307,9 @@ impl ExporterService {
                 ])),
             )
             .await;
-        let file = File::open(&export_path).await.unwrap();
+        let file = File::open(export_path).await.unwrap();
         let content_length = file.metadata().await.unwrap().len();
-        let content_type = mime_guess::from_path(&export_path).first_or_octet_stream();
+        let content_type = mime_guess::from_path(export_path).first_or_octet_stream();
         let stream = FramedRead::new(file, BytesCodec::new());
         let body = Body::wrap_stream(stream);
         let client = Client::new();
@@ -172,11 +326,7 @@ impl ExporterService {
         Ok(())
     }
 
-    async fn export_media(
-        &self,
-        user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
-    ) -> Result<()> {
+    async fn export_media(&self, user_id: &String, writer: &mut dyn ExportWriter) -> Result<()> {
         let mut current_page = 1;
         loop {
             let related_metadata = metadata_list(
@@ -201,6 +351,8 @@ impl ExporterService {
                 let seen_history = m
                     .find_related(Seen)
                     .filter(seen::Column::UserId.eq(user_id))
+                    .order_by_asc(seen::Column::FinishedOn)
+                    .order_by_asc(seen::Column::StartedOn)
                     .all(&self.0.db)
                     .await
                     .unwrap();
@@ -218,6 +370,11 @@ impl ExporterService {
                         let manga_chapter_number =
                             s.manga_extra_information.clone().and_then(|d| d.chapter);
                         let manga_volume_number = s.manga_extra_information.and_then(|d| d.volume);
+                        let (board_game_player_count, board_game_duration, board_game_winner) =
+                            match s.board_game_extra_information {
+                                Some(d) => (d.player_count, d.duration, d.winner),
+                                None => (None, None, None),
+                            };
                         ImportOrExportMetadataItemSeen {
                             progress: Some(s.progress),
                             started_on: s.started_on,
@@ -229,6 +386,10 @@ impl ExporterService {
                             anime_episode_number,
                             manga_chapter_number,
                             manga_volume_number,
+                            board_game_player_count,
+                            board_game_duration,
+                            board_game_winner,
+                            manual_time_spent: s.manual_time_spent,
                         }
                     })
                     .collect();
@@ -243,16 +404,24 @@ impl ExporterService {
                         .into_iter()
                         .map(|c| c.name)
                         .collect();
+                let authors = match writer.needs_authors() {
+                    true => self.get_metadata_authors(&m).await?,
+                    false => vec![],
+                };
                 let exp = ImportOrExportMetadataItem {
                     reviews,
                     lot: m.lot,
                     collections,
                     seen_history,
                     source: m.source,
-                    source_id: m.title,
+                    source_id: m.title.clone(),
                     identifier: m.identifier.clone(),
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::Metadata {
+                    item: &exp,
+                    details: &m,
+                    authors: &authors,
+                })?;
             }
             if let Some(next_page) = related_metadata.details.next_page {
                 current_page = next_page;
@@ -266,7 +435,7 @@ impl ExporterService {
     async fn export_media_group(
         &self,
         user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
+        writer: &mut dyn ExportWriter,
     ) -> Result<()> {
         let mut current_page = 1;
         loop {
@@ -309,7 +478,7 @@ impl ExporterService {
                     source: m.source,
                     identifier: m.identifier.clone(),
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::MetadataGroup(&exp))?;
             }
             if let Some(next_page) = related_metadata.details.next_page {
                 current_page = next_page;
@@ -320,11 +489,7 @@ impl ExporterService {
         Ok(())
     }
 
-    async fn export_people(
-        &self,
-        user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
-    ) -> Result<()> {
+    async fn export_people(&self, user_id: &String, writer: &mut dyn ExportWriter) -> Result<()> {
         let mut current_page = 1;
         loop {
             let related_people = people_list(
@@ -365,7 +530,7 @@ impl ExporterService {
                     identifier: p.identifier,
                     source_specifics: p.source_specifics,
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::Person(&exp))?;
             }
             if let Some(next_page) = related_people.details.next_page {
                 current_page = next_page;
@@ -376,11 +541,7 @@ impl ExporterService {
         Ok(())
     }
 
-    async fn export_workouts(
-        &self,
-        user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
-    ) -> Result<()> {
+    async fn export_workouts(&self, user_id: &String, writer: &mut dyn ExportWriter) -> Result<()> {
         let mut current_page = 1;
         loop {
             let workout_ids = user_workouts_list(
@@ -400,7 +561,7 @@ impl ExporterService {
                     details: details.details,
                     collections: details.collections.into_iter().map(|c| c.name).collect(),
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::Workout(&exp))?;
             }
             if let Some(next_page) = workout_ids.details.next_page {
                 current_page = next_page;
@@ -414,13 +575,13 @@ impl ExporterService {
     async fn export_measurements(
         &self,
         user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
+        writer: &mut dyn ExportWriter,
     ) -> Result<()> {
         let measurements =
             user_measurements_list(&self.0.db, user_id, UserMeasurementsListInput::default())
                 .await?;
         for measurement in measurements {
-            writer.serialize_value(&measurement).unwrap();
+            writer.write_record(ExportRecord::Measurement(&measurement))?;
         }
         Ok(())
     }
@@ -428,7 +589,7 @@ impl ExporterService {
     async fn export_exercises(
         &self,
         user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
+        writer: &mut dyn ExportWriter,
     ) -> Result<()> {
         let mut current_page = 1;
         loop {
@@ -471,7 +632,7 @@ impl ExporterService {
                     id: exercise_id,
                     name: exercise.name,
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::Exercise(&exp))?;
             }
             if let Some(next_page) = exercises.details.next_page {
                 current_page = next_page;
@@ -485,7 +646,7 @@ impl ExporterService {
     async fn export_workout_templates(
         &self,
         user_id: &String,
-        writer: &mut JsonStreamWriter<StdFile>,
+        writer: &mut dyn ExportWriter,
     ) -> Result<()> {
         let mut current_page = 1;
         loop {
@@ -510,7 +671,7 @@ impl ExporterService {
                     details: details.details,
                     collections: details.collections.into_iter().map(|c| c.name).collect(),
                 };
-                writer.serialize_value(&exp).unwrap();
+                writer.write_record(ExportRecord::WorkoutTemplate(&exp))?;
             }
             if let Some(next_page) = workout_template_ids.details.next_page {
                 current_page = next_page;
@@ -521,6 +682,28 @@ impl ExporterService {
         Ok(())
     }
 
+    async fn get_metadata_authors(&self, m: &metadata::Model) -> Result<Vec<String>> {
+        let mut authors = MetadataToPerson::find()
+            .filter(metadata_to_person::Column::MetadataId.eq(&m.id))
+            .filter(metadata_to_person::Column::Role.eq("Author"))
+            .order_by_asc(metadata_to_person::Column::Index)
+            .find_also_related(Person)
+            .all(&self.0.db)
+            .await?
+            .into_iter()
+            .filter_map(|(_, p)| p.map(|p| p.name))
+            .collect_vec();
+        authors.extend(
+            m.free_creators
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|c| c.role == "Author")
+                .map(|c| c.name),
+        );
+        Ok(authors)
+    }
+
     fn get_review_export_item(&self, rev: ReviewItem) -> ImportOrExportItemRating {
         let (show_season_number, show_episode_number) = match rev.show_extra_information {
             Some(d) => (d.season, d.episode),
@@ -0,0 +1,220 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::BufReader,
+    path::PathBuf,
+};
+
+use async_graphql::Result;
+use chrono::{NaiveDate, NaiveTime};
+use enum_models::{MediaLot, MediaSource};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use sea_orm::prelude::DateTimeUtc;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+use struson::{
+    reader::{JsonReader, JsonStreamReader},
+    writer::{JsonStreamWriter, JsonWriter},
+};
+use user_models::UserReviewScale;
+
+use super::{rating_out_of_hundred, ExportItem, ExportRecord, ExportWriter};
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+struct TraktIds {
+    tmdb: Option<i32>,
+    tvdb: Option<i32>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+struct TraktMovie {
+    title: String,
+    year: Option<i32>,
+    ids: TraktIds,
+    watched_at: Option<DateTimeUtc>,
+    rating: Option<u8>,
+    rated_at: Option<DateTimeUtc>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+struct TraktEpisode {
+    number: i32,
+    watched_at: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Serialize)]
+struct TraktSeason {
+    number: i32,
+    episodes: Vec<TraktEpisode>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+struct TraktShow {
+    title: String,
+    year: Option<i32>,
+    ids: TraktIds,
+    rating: Option<u8>,
+    rated_at: Option<DateTimeUtc>,
+    seasons: Vec<TraktSeason>,
+}
+
+fn watched_at(date: Option<NaiveDate>) -> Option<DateTimeUtc> {
+    date.map(|d| d.and_time(NaiveTime::MIN).and_utc())
+}
+
+/// Writes movies and shows in the shape of the body accepted by the Trakt
+/// `sync/history` endpoint. Both are produced while traversing the media, so the
+/// movies are streamed to the export directly and the shows to a separate file that
+/// is appended to it at the end.
+pub struct TraktExportWriter {
+    path: PathBuf,
+    shows_path: PathBuf,
+    movies: JsonStreamWriter<File>,
+    shows: JsonStreamWriter<File>,
+    review_scale: UserReviewScale,
+}
+
+impl TraktExportWriter {
+    pub fn new(path: PathBuf, review_scale: UserReviewScale) -> Result<Self> {
+        let mut movies = JsonStreamWriter::new(File::create(&path)?);
+        movies.begin_object()?;
+        movies.name("movies")?;
+        movies.begin_array()?;
+        let shows_path = path.with_extension("shows.json");
+        let mut shows = JsonStreamWriter::new(File::create(&shows_path)?);
+        shows.begin_array()?;
+        Ok(Self {
+            path,
+            shows,
+            movies,
+            shows_path,
+            review_scale,
+        })
+    }
+}
+
+impl ExportWriter for TraktExportWriter {
+    fn sections(&self) -> Vec<ExportItem> {
+        vec![ExportItem::Metadata]
+    }
+
+    fn write_record(&mut self, record: ExportRecord<'_>) -> Result<()> {
+        let ExportRecord::Metadata { item, details, .. } = record else {
+            return Ok(());
+        };
+        if item.source != MediaSource::Tmdb {
+            return Ok(());
+        }
+        let review = item
+            .reviews
+            .iter()
+            .rev()
+            .find(|r| r.rating.is_some() && r.show_season_number.is_none());
+        // DEV: Trakt only accepts whole ratings from 1 to 10.
+        let rating = review.and_then(|r| r.rating).and_then(|r| {
+            let rating = rating_out_of_hundred(r, self.review_scale);
+            (rating / dec!(10)).round().clamp(dec!(1), dec!(10)).to_u8()
+        });
+        let rated_at = review.and_then(|r| r.review.as_ref().and_then(|r| r.date));
+        let ids = TraktIds {
+            tmdb: item.identifier.parse().ok(),
+            tvdb: details
+                .external_identifiers
+                .as_ref()
+                .and_then(|e| e.tvdb_id),
+        };
+        match item.lot {
+            MediaLot::Movie => {
+                let watches = item
+                    .seen_history
+                    .iter()
+                    .filter(|s| s.progress.map_or(true, |p| p >= dec!(100)))
+                    .collect::<Vec<_>>();
+                if watches.is_empty() && rating.is_none() {
+                    return Ok(());
+                }
+                let mut movie = |watched_at, rating, rated_at| {
+                    self.movies.serialize_value(&TraktMovie {
+                        rating,
+                        rated_at,
+                        watched_at,
+                        year: details.publish_year,
+                        title: details.title.clone(),
+                        ids: TraktIds {
+                            tvdb: None,
+                            tmdb: ids.tmdb,
+                        },
+                    })
+                };
+                if watches.is_empty() {
+                    movie(None, rating, rated_at)?;
+                }
+                let last_index = watches.len().saturating_sub(1);
+                for (index, seen) in watches.iter().enumerate() {
+                    let at = watched_at(seen.ended_on.or(seen.started_on));
+                    // DEV: The rating is attached to the most recent watch only.
+                    match index == last_index {
+                        true => movie(at, rating, rated_at)?,
+                        false => movie(at, None, None)?,
+                    }
+                }
+            }
+            MediaLot::Show => {
+                let mut seasons: BTreeMap<i32, Vec<TraktEpisode>> = BTreeMap::new();
+                for seen in item.seen_history.iter() {
+                    let (Some(season), Some(episode)) =
+                        (seen.show_season_number, seen.show_episode_number)
+                    else {
+                        continue;
+                    };
+                    seasons.entry(season).or_default().push(TraktEpisode {
+                        number: episode,
+                        watched_at: watched_at(seen.ended_on.or(seen.started_on)),
+                    });
+                }
+                if seasons.is_empty() && rating.is_none() {
+                    return Ok(());
+                }
+                self.shows.serialize_value(&TraktShow {
+                    ids,
+                    rating,
+                    rated_at,
+                    year: details.publish_year,
+                    title: details.title.clone(),
+                    seasons: seasons
+                        .into_iter()
+                        .map(|(number, episodes)| TraktSeason { number, episodes })
+                        .collect(),
+                })?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<PathBuf> {
+        let Self {
+            path,
+            shows_path,
+            mut movies,
+            mut shows,
+            ..
+        } = *self;
+        shows.end_array()?;
+        shows.finish_document()?;
+        movies.end_array()?;
+        movies.name("shows")?;
+        let mut reader = JsonStreamReader::new(BufReader::new(File::open(&shows_path)?));
+        reader.transfer_to(&mut movies)?;
+        reader.consume_trailing_whitespace()?;
+        movies.end_object()?;
+        movies.finish_document()?;
+        fs::remove_file(&shows_path)?;
+        Ok(path)
+    }
+}
@@ -1,14 +1,24 @@
 use std::fs;
 
-use async_graphql::Result;
+use async_graphql::{Error, Result};
+use common_utils::{decrypt_with_passphrase, is_encrypted_with_passphrase};
 use dependent_models::{CompleteExport, ImportCompletedItem, ImportResult};
 use enum_models::ImportSource;
 use itertools::Itertools;
 use media_models::DeployJsonImportInput;
 
 pub async fn import(input: DeployJsonImportInput) -> Result<ImportResult> {
-    let export = fs::read_to_string(input.export)?;
-    let complete_data = serde_json::from_str::<CompleteExport>(&export).unwrap();
+    let mut export = fs::read(input.export)?;
+    if is_encrypted_with_passphrase(&export) {
+        let Some(passphrase) = input.passphrase else {
+            return Err(Error::new("A passphrase is required to import this export"));
+        };
+        let mut decrypted = vec![];
+        decrypt_with_passphrase(export.as_slice(), &mut decrypted, &passphrase)?;
+        export = decrypted;
+    }
+    let complete_data = serde_json::from_slice::<CompleteExport>(&export)
+        .map_err(|e| Error::new(format!("Could not parse the export: {e}")))?;
 
     let media = complete_data
         .media
@@ -1,23 +1,29 @@
 use std::{collections::HashSet, sync::Arc};
 
 use async_graphql::{Error, Result};
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use common_utils::ryot_log;
 use database_models::{
-    integration, metadata,
-    prelude::{CollectionToEntity, Integration, Metadata, Seen, UserToEntity},
-    seen, user_to_entity,
+    integration,
+    prelude::{CollectionToEntity, Integration, Metadata, Review, Seen, UserToEntity},
+    review, user_to_entity,
 };
 use database_utils::{server_key_validation_guard, user_by_id};
 use dependent_models::{ImportCompletedItem, ImportResult};
 use dependent_utils::{
     commit_metadata, get_google_books_service, get_hardcover_service, get_openlibrary_service,
-    process_import,
+    process_import, progress_update,
+};
+use enum_models::{EntityLot, IntegrationLot, IntegrationProvider, MediaLot, SeenState};
+use external_utils::{mal, trakt};
+use media_models::{
+    CommitMediaInput, IntegrationProviderSpecifics, ProgressUpdateInput, UniqueMediaIdentifier,
 };
-use enum_models::{EntityLot, IntegrationLot, IntegrationProvider, MediaLot};
-use media_models::{CommitMediaInput, SeenShowExtraInformation};
 use rust_decimal_macros::dec;
-use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter, QuerySelect};
+use sea_orm::{
+    prelude::DateTimeUtc, ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
 use supporting_service::SupportingService;
 use traits::TraceOk;
 use uuid::Uuid;
@@ -40,6 +46,119 @@ impl IntegrationService {
         Ok(())
     }
 
+    /// The access tokens of Trakt and MyAnimeList expire after some time. They are
+    /// refreshed a day before that and the new tokens are saved to the integration. The
+    /// integration is locked while refreshing since a refresh token can only be used once.
+    async fn get_access_token(&self, integration: &integration::Model) -> Result<String> {
+        let get_tokens = |integration: &integration::Model| {
+            let specifics = integration.provider_specifics.clone().unwrap_or_default();
+            let tokens = match integration.provider {
+                IntegrationProvider::Trakt => (
+                    specifics.trakt_access_token.clone(),
+                    specifics.trakt_refresh_token.clone(),
+                    specifics.trakt_token_expires_at,
+                ),
+                _ => (
+                    specifics.mal_access_token.clone(),
+                    specifics.mal_refresh_token.clone(),
+                    specifics.mal_token_expires_at,
+                ),
+            };
+            (specifics, tokens)
+        };
+        let is_valid = |expires_at: Option<DateTimeUtc>| {
+            expires_at.is_some_and(|expires_at| expires_at > Utc::now() + Duration::days(1))
+        };
+        if let (_, (Some(access_token), Some(_), expires_at)) = get_tokens(integration) {
+            if is_valid(expires_at) {
+                return Ok(access_token);
+            }
+        }
+        let txn = self.0.db.begin().await?;
+        let integration = Integration::find_by_id(&integration.id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| Error::new("Integration with the given ID does not exist"))?;
+        let (specifics, tokens) = get_tokens(&integration);
+        let (Some(access_token), Some(refresh_token), expires_at) = tokens else {
+            return Err(Error::new("The integration is not logged in"));
+        };
+        // Another push or yank might have refreshed the tokens in the meantime
+        if is_valid(expires_at) {
+            txn.commit().await?;
+            return Ok(access_token);
+        }
+        let config = &self.0.config.integration;
+        let (access_token, specifics) = match integration.provider {
+            IntegrationProvider::Trakt => {
+                let tokens = trakt::refresh_tokens(&config.trakt, &refresh_token)
+                    .await
+                    .map_err(|e| Error::new(e.to_string()))?;
+                (
+                    tokens.trakt_access_token.clone().unwrap(),
+                    IntegrationProviderSpecifics {
+                        trakt_access_token: tokens.trakt_access_token,
+                        trakt_refresh_token: tokens.trakt_refresh_token,
+                        trakt_token_expires_at: tokens.trakt_token_expires_at,
+                        ..specifics
+                    },
+                )
+            }
+            _ => {
+                let tokens = mal::refresh_tokens(&config.mal, &refresh_token)
+                    .await
+                    .map_err(|e| Error::new(e.to_string()))?;
+                (
+                    tokens.mal_access_token.clone().unwrap(),
+                    IntegrationProviderSpecifics {
+                        mal_access_token: tokens.mal_access_token,
+                        mal_refresh_token: tokens.mal_refresh_token,
+                        mal_token_expires_at: tokens.mal_token_expires_at,
+                        ..specifics
+                    },
+                )
+            }
+        };
+        let mut to_update: integration::ActiveModel = integration.into();
+        to_update.provider_specifics = ActiveValue::Set(Some(specifics));
+        to_update.update(&txn).await?;
+        txn.commit().await?;
+        Ok(access_token)
+    }
+
+    /// Change the state of the media after the items from an anime or manga list have
+    /// been imported.
+    async fn change_media_list_states(
+        &self,
+        user_id: &String,
+        provider_name: &str,
+        state_changes: Vec<(UniqueMediaIdentifier, SeenState)>,
+    ) -> Result<()> {
+        for (unique, state) in state_changes {
+            let Some(metadata) =
+                utils::find_metadata(&self.0.db, unique.lot, unique.source, &unique.identifier)
+                    .await?
+            else {
+                continue;
+            };
+            progress_update(
+                user_id,
+                false,
+                ProgressUpdateInput {
+                    metadata_id: metadata.id,
+                    change_state: Some(state),
+                    provider_watched_on: Some(provider_name.to_owned()),
+                    ..Default::default()
+                },
+                &self.0,
+            )
+            .await
+            .trace_ok();
+        }
+        Ok(())
+    }
+
     async fn integration_progress_update(
         &self,
         integration: integration::Model,
@@ -216,22 +335,50 @@ impl IntegrationService {
     }
 
     pub async fn handle_on_seen_complete(&self, id: String) -> Result<()> {
-        let (seen, show_extra_information, metadata_title, metadata_lot) = Seen::find_by_id(id)
-            .left_join(Metadata)
-            .select_only()
-            .columns([seen::Column::UserId, seen::Column::ShowExtraInformation])
-            .columns([metadata::Column::Title, metadata::Column::Lot])
-            .into_tuple::<(String, Option<SeenShowExtraInformation>, String, MediaLot)>()
+        let (seen, metadata) = Seen::find_by_id(id)
+            .find_also_related(Metadata)
             .one(&self.0.db)
             .await?
             .ok_or_else(|| Error::new("Seen with the given ID could not be found"))?;
+        let metadata = metadata.ok_or_else(|| Error::new("Metadata does not exist"))?;
         let integrations = Integration::find()
-            .filter(integration::Column::UserId.eq(seen))
-            .filter(integration::Column::Lot.eq(IntegrationLot::Push))
-            .filter(integration::Column::Provider.eq(IntegrationProvider::JellyfinPush))
+            .filter(integration::Column::UserId.eq(&seen.user_id))
+            .filter(integration::Column::Provider.is_in([
+                IntegrationProvider::Trakt,
+                IntegrationProvider::Anilist,
+                IntegrationProvider::Myanimelist,
+                IntegrationProvider::JellyfinPush,
+            ]))
             .all(&self.0.db)
             .await?;
         for integration in integrations {
+            if integration.is_disabled.unwrap_or_default() {
+                ryot_log!(debug, "Integration {} is disabled", integration.id);
+                continue;
+            }
+            let is_media_list = matches!(
+                integration.provider,
+                IntegrationProvider::Anilist | IntegrationProvider::Myanimelist
+            );
+            if is_media_list && !matches!(metadata.lot, MediaLot::Anime | MediaLot::Manga) {
+                continue;
+            }
+            // Episodes, chapters and states synced from a list do not have to be sent back to it
+            let provider_name = match integration.provider {
+                IntegrationProvider::Anilist => Some(yank::anilist::PROVIDER_NAME),
+                IntegrationProvider::Myanimelist => Some(yank::myanimelist::PROVIDER_NAME),
+                _ => None,
+            };
+            if seen.state != SeenState::InProgress
+                && provider_name.is_some()
+                && seen.provider_watched_on.as_deref() == provider_name
+            {
+                continue;
+            }
+            // Only the anime and manga lists keep track of media that were dropped or put on hold
+            if !is_media_list && seen.state != SeenState::Completed {
+                continue;
+            }
             let specifics = integration.provider_specifics.clone().unwrap();
             let push_result = match integration.provider {
                 IntegrationProvider::JellyfinPush => {
@@ -240,15 +387,70 @@ impl IntegrationService {
                         specifics.jellyfin_push_base_url.unwrap(),
                         specifics.jellyfin_push_username.unwrap(),
                         specifics.jellyfin_push_password.unwrap(),
-                        &metadata_lot,
-                        &metadata_title,
-                        &show_extra_information,
+                        &metadata.lot,
+                        &metadata.title,
+                        &seen.show_extra_information,
+                    )
+                    .await
+                }
+                IntegrationProvider::Trakt => {
+                    if seen.provider_watched_on.as_deref() == Some(yank::trakt::PROVIDER_NAME) {
+                        continue;
+                    }
+                    let rating = Review::find()
+                        .filter(review::Column::UserId.eq(&seen.user_id))
+                        .filter(review::Column::MetadataId.eq(&metadata.id))
+                        .filter(review::Column::ShowExtraInformation.is_null())
+                        .filter(review::Column::Rating.is_not_null())
+                        .order_by_desc(review::Column::PostedOn)
+                        .one(&self.0.db)
+                        .await?
+                        .and_then(|r| r.rating);
+                    let access_token = match self.get_access_token(&integration).await {
+                        Ok(access_token) => access_token,
+                        Err(e) => {
+                            ryot_log!(debug, "Error refreshing Trakt token: {:?}", e);
+                            continue;
+                        }
+                    };
+                    push::trakt::push_progress(
+                        &self.0.config.integration.trakt.client_id,
+                        access_token,
+                        &metadata,
+                        &seen,
+                        rating,
+                    )
+                    .await
+                }
+                IntegrationProvider::Anilist => {
+                    let user_state =
+                        utils::get_user_media_list_state(&self.0.db, &seen.user_id, &metadata)
+                            .await?;
+                    push::anilist::push_progress(
+                        &specifics.anilist_token.unwrap(),
+                        &metadata,
+                        user_state,
                     )
                     .await
                 }
+                IntegrationProvider::Myanimelist => {
+                    let access_token = match self.get_access_token(&integration).await {
+                        Ok(access_token) => access_token,
+                        Err(e) => {
+                            ryot_log!(debug, "Error refreshing MyAnimeList token: {:?}", e);
+                            continue;
+                        }
+                    };
+                    let user_state =
+                        utils::get_user_media_list_state(&self.0.db, &seen.user_id, &metadata)
+                            .await?;
+                    push::myanimelist::push_progress(&access_token, &metadata, user_state).await
+                }
                 _ => unreachable!(),
             };
-            if push_result.is_ok() {
+            // Yank integrations fetch the changes made since they were last triggered, so
+            // pushing to them must not move that time forward
+            if push_result.is_ok() && integration.lot != IntegrationLot::Yank {
                 self.set_integration_last_triggered_on(&integration).await?;
             }
         }
@@ -266,6 +468,7 @@ impl IntegrationService {
             .all(&self.0.db)
             .await?;
         let mut progress_updates = vec![];
+        let mut media_list_state_changes = vec![];
         for integration in integrations.into_iter() {
             if integration.is_disabled.unwrap_or_default() {
                 ryot_log!(debug, "Integration {} is disabled", integration.id);
@@ -312,6 +515,66 @@ impl IntegrationService {
                     )
                     .await
                 }
+                IntegrationProvider::Steam => {
+                    yank::steam::yank_progress(
+                        &integration.user_id,
+                        specifics.steam_api_key.unwrap(),
+                        specifics.steam_user_id.unwrap(),
+                        integration.last_triggered_on,
+                        &self.0,
+                    )
+                    .await
+                }
+                IntegrationProvider::Trakt => {
+                    let access_token = match self.get_access_token(&integration).await {
+                        Ok(access_token) => access_token,
+                        Err(e) => {
+                            ryot_log!(debug, "Error refreshing Trakt token: {:?}", e);
+                            continue;
+                        }
+                    };
+                    yank::trakt::yank_progress(
+                        &self.0.config.integration.trakt.client_id,
+                        access_token,
+                        integration.last_triggered_on,
+                        user_id,
+                        &self.0.db,
+                    )
+                    .await
+                }
+                IntegrationProvider::Anilist => yank::anilist::yank_progress(
+                    &specifics.anilist_token.unwrap(),
+                    integration.last_triggered_on,
+                    user_id,
+                    &self.0.db,
+                )
+                .await
+                .map(|import| {
+                    media_list_state_changes
+                        .push((yank::anilist::PROVIDER_NAME, import.state_changes));
+                    import.result
+                }),
+                IntegrationProvider::Myanimelist => {
+                    let access_token = match self.get_access_token(&integration).await {
+                        Ok(access_token) => access_token,
+                        Err(e) => {
+                            ryot_log!(debug, "Error refreshing MyAnimeList token: {:?}", e);
+                            continue;
+                        }
+                    };
+                    yank::myanimelist::yank_progress(
+                        &access_token,
+                        integration.last_triggered_on,
+                        user_id,
+                        &self.0.db,
+                    )
+                    .await
+                    .map(|import| {
+                        media_list_state_changes
+                            .push((yank::myanimelist::PROVIDER_NAME, import.state_changes));
+                        import.result
+                    })
+                }
                 _ => continue,
             };
             if let Ok(update) = response {
@@ -323,6 +586,11 @@ impl IntegrationService {
                 .await
                 .trace_ok();
         }
+        for (provider_name, state_changes) in media_list_state_changes {
+            self.change_media_list_states(user_id, provider_name, state_changes)
+                .await
+                .trace_ok();
+        }
         Ok(())
     }
 
@@ -390,6 +658,14 @@ impl IntegrationService {
                     )
                     .await
                 }
+                IntegrationProvider::Steam => {
+                    yank::steam::sync_to_owned_collection(
+                        specifics.steam_api_key.unwrap(),
+                        specifics.steam_user_id.unwrap(),
+                        &self.0,
+                    )
+                    .await
+                }
                 _ => continue,
             };
             if let Ok(update) = response {
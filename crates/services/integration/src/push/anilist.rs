@@ -0,0 +1,39 @@
+use anyhow::Result;
+use common_utils::ryot_log;
+use database_models::metadata;
+use enum_models::MediaSource;
+use external_utils::anilist;
+
+use crate::{
+    utils::{get_media_list_entry_to_push, UserMediaListState},
+    yank::anilist::SCORE_STEP,
+};
+
+/// Update the entry of the media in the list of the user on Anilist to match the
+/// progress, state and score in Ryot.
+pub async fn push_progress(
+    token: &str,
+    metadata: &metadata::Model,
+    user_state: UserMediaListState,
+) -> Result<()> {
+    let identifier = match metadata.source {
+        MediaSource::Anilist => Some(metadata.identifier.clone()),
+        MediaSource::Mal => {
+            anilist::get_media_identifiers(metadata.lot, None, Some(&metadata.identifier))
+                .await?
+                .map(|ids| ids.identifier)
+        }
+        _ => None,
+    };
+    let Some(identifier) = identifier else {
+        ryot_log!(debug, "Not pushing {} to Anilist", metadata.title);
+        return Ok(());
+    };
+    let remote = anilist::get_list_entry(token, metadata.lot, &identifier).await?;
+    if let Some(entry) =
+        get_media_list_entry_to_push(user_state, remote, metadata, identifier, SCORE_STEP)
+    {
+        anilist::save_list_entry(token, &entry).await?;
+    }
+    Ok(())
+}
@@ -1,3 +1,6 @@
+pub mod anilist;
 pub mod jellyfin;
+pub mod myanimelist;
 pub mod radarr;
 pub mod sonarr;
+pub mod trakt;
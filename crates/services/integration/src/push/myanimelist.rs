@@ -0,0 +1,39 @@
+use anyhow::Result;
+use common_utils::ryot_log;
+use database_models::metadata;
+use enum_models::MediaSource;
+use external_utils::{anilist, mal};
+
+use crate::{
+    utils::{get_media_list_entry_to_push, UserMediaListState},
+    yank::myanimelist::SCORE_STEP,
+};
+
+/// Update the entry of the media in the list of the user on MyAnimeList to match the
+/// progress, state and score in Ryot.
+pub async fn push_progress(
+    access_token: &str,
+    metadata: &metadata::Model,
+    user_state: UserMediaListState,
+) -> Result<()> {
+    let identifier = match metadata.source {
+        MediaSource::Mal => Some(metadata.identifier.clone()),
+        MediaSource::Anilist => {
+            anilist::get_media_identifiers(metadata.lot, Some(&metadata.identifier), None)
+                .await?
+                .and_then(|ids| ids.mal_identifier)
+        }
+        _ => None,
+    };
+    let Some(identifier) = identifier else {
+        ryot_log!(debug, "Not pushing {} to MyAnimeList", metadata.title);
+        return Ok(());
+    };
+    let remote = mal::get_list_entry(access_token, metadata.lot, &identifier).await?;
+    if let Some(entry) =
+        get_media_list_entry_to_push(user_state, remote, metadata, identifier, SCORE_STEP)
+    {
+        mal::save_list_entry(access_token, &entry).await?;
+    }
+    Ok(())
+}
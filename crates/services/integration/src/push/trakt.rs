@@ -0,0 +1,62 @@
+use anyhow::Result;
+use common_utils::ryot_log;
+use database_models::{metadata, seen};
+use enum_models::{MediaLot, MediaSource};
+use external_utils::trakt::{get_authenticated_client, API_URL};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+use crate::utils::get_trakt_watched_at;
+
+/// Add the seen item to the Trakt history and update the rating of the media if the
+/// user has rated it. Ratings in Ryot are out of 100 while Trakt uses 1 to 10.
+pub async fn push_progress(
+    client_id: &str,
+    access_token: String,
+    metadata: &metadata::Model,
+    seen: &seen::Model,
+    rating: Option<Decimal>,
+) -> Result<()> {
+    let kind = match (metadata.lot, metadata.source) {
+        (MediaLot::Movie, MediaSource::Tmdb) => "movies",
+        (MediaLot::Show, MediaSource::Tmdb) => "shows",
+        _ => {
+            ryot_log!(
+                debug,
+                "Not pushing {:#?} progress for trakt integration",
+                metadata.lot
+            );
+            return Ok(());
+        }
+    };
+    let ids = json!({ "tmdb": metadata.identifier.parse::<i64>()? });
+    let watched_at = get_trakt_watched_at(seen);
+    let history_item = match &seen.show_extra_information {
+        Some(information) => json!({
+            "ids": ids,
+            "seasons": [{
+                "number": information.season,
+                "episodes": [{ "number": information.episode, "watched_at": watched_at }],
+            }],
+        }),
+        None => json!({ "ids": ids, "watched_at": watched_at }),
+    };
+    let client = get_authenticated_client(client_id, &access_token)?;
+    client
+        .post(format!("{}/sync/history", API_URL))
+        .json(&json!({ kind: [history_item] }))
+        .send()
+        .await?
+        .error_for_status()?;
+    if let Some(rating) = rating {
+        let rating = i64::try_from((rating / dec!(10)).round().clamp(dec!(1), dec!(10)))?;
+        client
+            .post(format!("{}/sync/ratings", API_URL))
+            .json(&json!({ kind: [{ "ids": ids, "rating": rating }] }))
+            .send()
+            .await?
+            .error_for_status()?;
+    }
+    Ok(())
+}
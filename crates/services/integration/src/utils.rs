@@ -1,8 +1,24 @@
 use anyhow::{bail, Result};
-use database_models::metadata;
+use common_models::DefaultCollection;
+use common_utils::ryot_log;
+use database_models::{
+    metadata,
+    prelude::{Metadata, Review, Seen},
+    review, seen,
+};
 use database_utils::ilike_sql;
-use enum_models::{MediaLot, MediaSource};
-use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
+use dependent_models::{ImportCompletedItem, ImportResult};
+use enum_models::{MediaLot, MediaSource, SeenState};
+use external_utils::MediaListEntry;
+use media_models::{
+    ImportOrExportItemRating, ImportOrExportItemReview, ImportOrExportMetadataItem,
+    ImportOrExportMetadataItemSeen, UniqueMediaIdentifier,
+};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use sea_orm::{
+    prelude::DateTimeUtc, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
 use sea_query::{extension::postgres::PgExpr, Alias, Expr, Func};
 
 pub async fn get_show_by_episode_identifier(
@@ -35,3 +51,242 @@ pub async fn get_show_by_episode_identifier(
         ),
     }
 }
+
+pub async fn find_metadata(
+    db: &DatabaseConnection,
+    lot: MediaLot,
+    source: MediaSource,
+    identifier: &str,
+) -> Result<Option<metadata::Model>> {
+    let metadata = Metadata::find()
+        .filter(metadata::Column::Lot.eq(lot))
+        .filter(metadata::Column::Source.eq(source))
+        .filter(metadata::Column::Identifier.eq(identifier))
+        .one(db)
+        .await?;
+    Ok(metadata)
+}
+
+/// The progress of the user on an anime or manga in Ryot, in the same shape as the
+/// lists on Anilist and MyAnimeList.
+#[derive(Debug, Default)]
+pub struct UserMediaListState {
+    /// `None` if the user has not seen the media yet.
+    pub state: Option<SeenState>,
+    pub progress: i32,
+    pub score: Option<Decimal>,
+    pub updated_on: Option<DateTimeUtc>,
+}
+
+pub async fn get_user_media_list_state(
+    db: &DatabaseConnection,
+    user_id: &String,
+    metadata: &metadata::Model,
+) -> Result<UserMediaListState> {
+    let seen_history = Seen::find()
+        .filter(seen::Column::UserId.eq(user_id))
+        .filter(seen::Column::MetadataId.eq(&metadata.id))
+        .order_by_desc(seen::Column::LastUpdatedOn)
+        .all(db)
+        .await?;
+    let review = Review::find()
+        .filter(review::Column::UserId.eq(user_id))
+        .filter(review::Column::MetadataId.eq(&metadata.id))
+        .filter(review::Column::Rating.is_not_null())
+        .filter(review::Column::AnimeExtraInformation.is_null())
+        .filter(review::Column::MangaExtraInformation.is_null())
+        .order_by_desc(review::Column::PostedOn)
+        .one(db)
+        .await?;
+    let total = match metadata.lot {
+        MediaLot::Anime => metadata.anime_specifics.as_ref().and_then(|s| s.episodes),
+        _ => metadata
+            .manga_specifics
+            .as_ref()
+            .and_then(|s| s.chapters)
+            .and_then(|c| c.floor().to_i32()),
+    };
+    // Dropping a media or putting it on hold changes the state of the last episode or
+    // chapter that was seen, so it still counts towards the progress
+    let progress = seen_history
+        .iter()
+        .filter(|s| s.state != SeenState::InProgress)
+        .filter_map(|s| {
+            let number = match metadata.lot {
+                MediaLot::Anime => s.anime_extra_information.as_ref().and_then(|e| e.episode),
+                _ => s
+                    .manga_extra_information
+                    .as_ref()
+                    .and_then(|e| e.chapter)
+                    .and_then(|c| c.floor().to_i32()),
+            };
+            // A seen item without a number means that the whole media was marked as seen
+            number.or(total)
+        })
+        .max()
+        .unwrap_or_default();
+    let state = seen_history.first().map(|s| match s.state {
+        SeenState::Dropped | SeenState::OnAHold => s.state,
+        _ if total.is_some_and(|t| t > 0 && progress >= t) => SeenState::Completed,
+        _ => SeenState::InProgress,
+    });
+    let updated_on = seen_history
+        .first()
+        .map(|s| s.last_updated_on)
+        .into_iter()
+        .chain(review.as_ref().map(|r| r.posted_on))
+        .max();
+    Ok(UserMediaListState {
+        state,
+        progress,
+        updated_on,
+        score: review.and_then(|r| r.rating),
+    })
+}
+
+/// Whether two scores out of 100 are the same on a tracker that only supports scores
+/// in steps of `score_step`.
+fn is_same_score(a: Option<Decimal>, b: Option<Decimal>, score_step: Decimal) -> bool {
+    a.map(|s| (s / score_step).round()) == b.map(|s| (s / score_step).round())
+}
+
+/// The items to import from the list of a user on Anilist or MyAnimeList. The states
+/// have to be changed after the items have been imported.
+#[derive(Debug, Default)]
+pub struct MediaListImport {
+    pub result: ImportResult,
+    pub state_changes: Vec<(UniqueMediaIdentifier, SeenState)>,
+}
+
+/// Convert the list entries of a tracker into the items to import. The identifiers of
+/// the entries must already point to the source the media has in Ryot. An entry is
+/// skipped if the media was updated in Ryot after it was updated on the tracker.
+pub async fn get_media_list_import(
+    db: &DatabaseConnection,
+    user_id: &String,
+    provider_name: &str,
+    score_step: Decimal,
+    entries: Vec<(MediaSource, MediaListEntry)>,
+) -> Result<MediaListImport> {
+    let mut import = MediaListImport::default();
+    for (source, entry) in entries {
+        let user_state = match find_metadata(db, entry.lot, source, &entry.identifier).await? {
+            Some(metadata) => get_user_media_list_state(db, user_id, &metadata).await?,
+            None => UserMediaListState::default(),
+        };
+        if user_state
+            .updated_on
+            .is_some_and(|updated_on| updated_on > entry.updated_at)
+        {
+            ryot_log!(
+                debug,
+                "{} was updated in Ryot after {}",
+                entry.title,
+                provider_name
+            );
+            continue;
+        }
+        let mut item = ImportOrExportMetadataItem {
+            source,
+            lot: entry.lot,
+            source_id: entry.title.clone(),
+            identifier: entry.identifier.clone(),
+            ..Default::default()
+        };
+        match entry.state {
+            None if user_state.state.is_none() => {
+                item.collections
+                    .push(DefaultCollection::Watchlist.to_string());
+            }
+            None => {}
+            Some(state) => {
+                for number in user_state.progress + 1..=entry.progress {
+                    let (anime_episode_number, manga_chapter_number) = match entry.lot {
+                        MediaLot::Anime => (Some(number), None),
+                        _ => (None, Some(Decimal::from(number))),
+                    };
+                    item.seen_history.push(ImportOrExportMetadataItemSeen {
+                        anime_episode_number,
+                        manga_chapter_number,
+                        ended_on: Some(entry.updated_at.date_naive()),
+                        provider_watched_on: Some(provider_name.to_owned()),
+                        ..Default::default()
+                    });
+                }
+                if matches!(state, SeenState::Dropped | SeenState::OnAHold)
+                    && user_state.state != Some(state)
+                {
+                    import.state_changes.push((
+                        UniqueMediaIdentifier {
+                            source,
+                            lot: entry.lot,
+                            identifier: entry.identifier.clone(),
+                        },
+                        state,
+                    ));
+                }
+            }
+        }
+        if entry.score.is_some() && !is_same_score(entry.score, user_state.score, score_step) {
+            item.reviews.push(ImportOrExportItemRating {
+                rating: entry.score,
+                review: Some(ImportOrExportItemReview {
+                    date: Some(entry.updated_at),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        import
+            .result
+            .completed
+            .push(ImportCompletedItem::Metadata(item));
+    }
+    Ok(import)
+}
+
+/// Get the entry that has to be saved on a tracker to match the progress in Ryot.
+/// Returns `None` if the entry on the tracker is up to date or was updated after the
+/// media was updated in Ryot.
+pub fn get_media_list_entry_to_push(
+    user_state: UserMediaListState,
+    remote: Option<MediaListEntry>,
+    metadata: &metadata::Model,
+    identifier: String,
+    score_step: Decimal,
+) -> Option<MediaListEntry> {
+    let updated_on = user_state.updated_on?;
+    if let Some(remote) = &remote {
+        if remote.updated_at > updated_on {
+            ryot_log!(debug, "{} was updated on the tracker later", remote.title);
+            return None;
+        }
+        if remote.state == user_state.state
+            && remote.progress == user_state.progress
+            && (user_state.score.is_none()
+                || is_same_score(remote.score, user_state.score, score_step))
+        {
+            return None;
+        }
+    }
+    Some(MediaListEntry {
+        identifier,
+        updated_at: updated_on,
+        lot: metadata.lot,
+        state: user_state.state,
+        progress: user_state.progress,
+        title: metadata.title.clone(),
+        score: user_state.score.or(remote.and_then(|r| r.score)),
+    })
+}
+
+/// The time at which a seen item is added to the Trakt history. Entries from the Trakt
+/// history are compared with the seen items in Ryot using the same time.
+pub fn get_trakt_watched_at(seen: &seen::Model) -> DateTimeUtc {
+    match seen.finished_on {
+        Some(date) if date != seen.last_updated_on.date_naive() => {
+            date.and_hms_opt(0, 0, 0).unwrap().and_utc()
+        }
+        _ => seen.last_updated_on,
+    }
+}
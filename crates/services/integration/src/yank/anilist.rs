@@ -0,0 +1,50 @@
+use anyhow::Result;
+use enum_models::MediaSource;
+use external_utils::{anilist, MediaListEntry};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sea_orm::{prelude::DateTimeUtc, DatabaseConnection};
+
+use crate::utils::{find_metadata, get_media_list_import, MediaListImport};
+
+pub const PROVIDER_NAME: &str = "Anilist";
+/// Scores are synced out of 100, so no precision is lost.
+pub const SCORE_STEP: Decimal = dec!(1);
+
+/// Sync the anime and manga lists of the user that were updated on Anilist since the
+/// last sync. Everything is synced when the integration runs for the first time.
+pub async fn yank_progress(
+    token: &str,
+    last_triggered_on: Option<DateTimeUtc>,
+    user_id: &String,
+    db: &DatabaseConnection,
+) -> Result<MediaListImport> {
+    let mut entries = vec![];
+    for item in anilist::get_list_entries(token).await? {
+        if last_triggered_on.is_some_and(|t| item.entry.updated_at <= t) {
+            continue;
+        }
+        let lot = item.entry.lot;
+        // Use the MyAnimeList version of the media if that is the one present in Ryot
+        if let Some(mal_identifier) = item.mal_identifier {
+            if find_metadata(db, lot, MediaSource::Anilist, &item.entry.identifier)
+                .await?
+                .is_none()
+                && find_metadata(db, lot, MediaSource::Mal, &mal_identifier)
+                    .await?
+                    .is_some()
+            {
+                entries.push((
+                    MediaSource::Mal,
+                    MediaListEntry {
+                        identifier: mal_identifier,
+                        ..item.entry
+                    },
+                ));
+                continue;
+            }
+        }
+        entries.push((MediaSource::Anilist, item.entry));
+    }
+    get_media_list_import(db, user_id, PROVIDER_NAME, SCORE_STEP, entries).await
+}
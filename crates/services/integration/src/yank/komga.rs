@@ -44,6 +44,25 @@ mod komga_book {
     #[serde(rename_all = "camelCase")]
     pub struct Metadata {
         pub number: String,
+        #[serde(default)]
+        pub links: Vec<Link>,
+    }
+
+    impl Metadata {
+        /// Extracts the Comic Vine issue ID from the links of the book. For example the
+        /// url https://comicvine.gamespot.com/batman-1/4000-12345/ will extract 12345
+        pub fn find_comic_vine_id(&self) -> Option<String> {
+            self.links.iter().find_map(|link| {
+                Url::parse(&link.url)
+                    .ok()
+                    .filter(|url| {
+                        url.host_str()
+                            .is_some_and(|h| h.ends_with("comicvine.gamespot.com"))
+                    })?
+                    .path_segments()?
+                    .find_map(|segment| segment.strip_prefix("4000-").map(String::from))
+            })
+        }
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -62,7 +81,13 @@ mod komga_book {
         pub media: Media,
         pub number: i32,
         pub metadata: Metadata,
-        pub read_progress: ReadProgress,
+        pub read_progress: Option<ReadProgress>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Response {
+        pub content: Vec<Item>,
     }
 }
 
@@ -347,30 +372,41 @@ async fn process_events(
     )
     .await?;
 
-    let (source, id) = find_provider_and_id(source, db, &series).await?;
+    let (lot, source, id) = match source {
+        MediaSource::ComicVine => (MediaLot::Comic, source, book.metadata.find_comic_vine_id()),
+        _ => {
+            let (source, id) = find_provider_and_id(source, db, &series).await?;
+            (MediaLot::Manga, source, id)
+        }
+    };
 
     let Some(id) = id else {
         let msg = format!(
-            "No MAL URL or database entry found for manga: {}",
-            series.name
+            "No provider URL or database entry found for {:?}: {}",
+            lot, series.name
         );
         ryot_log!(debug, msg);
         bail!(msg)
     };
 
+    let manga_chapter_number = match lot {
+        MediaLot::Manga => Some(book.metadata.number.parse().unwrap_or_default()),
+        _ => None,
+    };
+
     Ok((
         UniqueMediaIdentifier {
+            lot,
             source,
             identifier: id,
-            lot: MediaLot::Manga,
         },
         ImportOrExportMetadataItemSeen {
+            manga_chapter_number,
             progress: Some(calculate_percentage(
-                book.read_progress.page,
+                book.read_progress.map(|p| p.page).unwrap_or_default(),
                 book.media.pages_count,
             )),
             provider_watched_on: Some("Komga".to_string()),
-            manga_chapter_number: Some(book.metadata.number.parse().unwrap_or_default()),
             ..Default::default()
         },
     ))
@@ -483,6 +519,42 @@ pub async fn sync_to_owned_collection(
     let url = &format!("{}/api/v1", base_url);
     let client = get_base_http_client(None);
 
+    // Comic Vine tracks individual issues, so each book is committed on its own
+    if source == MediaSource::ComicVine {
+        let books: komga_book::Response = client
+            .get(format!("{}/{}", url, "books?unpaged=true"))
+            .basic_auth(username, Some(password))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let unique_collection_updates: HashMap<String, _> = books
+            .content
+            .into_iter()
+            .filter_map(|book| match book.metadata.find_comic_vine_id() {
+                Some(id) => Some((
+                    id.clone(),
+                    ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+                        source,
+                        identifier: id,
+                        lot: MediaLot::Comic,
+                        collections: vec![DefaultCollection::Owned.to_string()],
+                        ..Default::default()
+                    }),
+                )),
+                None => {
+                    tracing::debug!("No Comic Vine URL found for comic: {}", book.name);
+                    None
+                }
+            })
+            .collect();
+        result
+            .completed
+            .extend(unique_collection_updates.into_values());
+        return Ok(result);
+    }
+
     let series: komga_series::Response = client
         .get(format!("{}/{}", url, "series?unpaged=true"))
         .basic_auth(username, Some(password))
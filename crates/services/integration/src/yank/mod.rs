@@ -1,4 +1,8 @@
+pub mod anilist;
 pub mod audiobookshelf;
 pub mod komga;
+pub mod myanimelist;
 pub mod plex;
+pub mod steam;
+pub mod trakt;
 pub mod youtube_music;
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use common_utils::ryot_log;
+use enum_models::MediaSource;
+use external_utils::{anilist, mal, MediaListEntry};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sea_orm::{prelude::DateTimeUtc, DatabaseConnection};
+
+use crate::utils::{find_metadata, get_media_list_import, MediaListImport};
+
+pub const PROVIDER_NAME: &str = "MyAnimeList";
+/// MyAnimeList only supports scores from 1 to 10.
+pub const SCORE_STEP: Decimal = dec!(10);
+
+/// Sync the anime and manga lists of the user that were updated on MyAnimeList since
+/// the last sync. Everything is synced when the integration runs for the first time.
+pub async fn yank_progress(
+    access_token: &str,
+    last_triggered_on: Option<DateTimeUtc>,
+    user_id: &String,
+    db: &DatabaseConnection,
+) -> Result<MediaListImport> {
+    let mut entries = vec![];
+    for entry in mal::get_list_entries(access_token).await? {
+        if last_triggered_on.is_some_and(|t| entry.updated_at <= t) {
+            continue;
+        }
+        let lot = entry.lot;
+        if find_metadata(db, lot, MediaSource::Mal, &entry.identifier)
+            .await?
+            .is_none()
+        {
+            // Use the Anilist version of the media if that is the one present in Ryot
+            match anilist::get_media_identifiers(lot, None, Some(&entry.identifier)).await {
+                Ok(Some(ids)) => {
+                    if find_metadata(db, lot, MediaSource::Anilist, &ids.identifier)
+                        .await?
+                        .is_some()
+                    {
+                        entries.push((
+                            MediaSource::Anilist,
+                            MediaListEntry {
+                                identifier: ids.identifier,
+                                ..entry
+                            },
+                        ));
+                        continue;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => ryot_log!(debug, "Error finding {} on Anilist: {:?}", entry.title, e),
+            }
+        }
+        entries.push((MediaSource::Mal, entry));
+    }
+    get_media_list_import(db, user_id, PROVIDER_NAME, SCORE_STEP, entries).await
+}
@@ -0,0 +1,285 @@
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use application_utils::get_base_http_client;
+use common_models::{ApplicationCacheKey, DefaultCollection, SteamGameMapping};
+use common_utils::ryot_log;
+use database_models::{
+    metadata,
+    prelude::{Metadata, Seen},
+    seen,
+};
+use dependent_models::{ApplicationCacheValue, ImportCompletedItem, ImportResult};
+use enum_models::{MediaLot, MediaSource, SeenState};
+use media_models::{ImportOrExportMetadataItem, ImportOrExportMetadataItemSeen};
+use providers::{igdb::IgdbService, rawg::RawgService};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sea_orm::{
+    prelude::DateTimeUtc, ColumnTrait, Condition, EntityTrait, QueryFilter, QuerySelect,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use supporting_service::SupportingService;
+
+static URL: &str = "https://api.steampowered.com/IPlayerService/GetOwnedGames/v1";
+/// Steam does not know how far into a game the user is, so games that were played
+/// are marked as in progress with this value.
+const PLAYED_PROGRESS: Decimal = dec!(50);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OwnedGame {
+    appid: i64,
+    name: String,
+    /// The total playtime in minutes.
+    playtime_forever: i64,
+    /// The playtime in the last two weeks in minutes.
+    #[serde(default)]
+    playtime_2weeks: i64,
+    /// The unix timestamp of when the game was last played.
+    #[serde(default)]
+    rtime_last_played: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OwnedGamesResponse {
+    game_count: Option<i32>,
+    #[serde(default)]
+    games: Vec<OwnedGame>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Response {
+    response: OwnedGamesResponse,
+}
+
+async fn get_owned_games(api_key: &str, steam_user_id: &str) -> Result<Vec<OwnedGame>> {
+    let data: Response = get_base_http_client(None)
+        .get(URL)
+        .query(&json!({
+            "key": api_key,
+            "steamid": steam_user_id,
+            "include_appinfo": 1,
+            "include_played_free_games": 1,
+        }))
+        .send()
+        .await
+        .map_err(|e| anyhow!(e))?
+        .error_for_status()?
+        .json()
+        .await
+        .map_err(|e| anyhow!(e))?;
+    // The response is empty when the game details of the profile are not public
+    if data.response.game_count.is_none() {
+        bail!("The game details of this Steam profile are not public");
+    }
+    Ok(data.response.games)
+}
+
+/// Find the identifiers of the games on the configured video game provider. The
+/// games that were found are cached since they have to be looked up for the whole
+/// library.
+async fn get_metadata_identifiers(
+    games: &[OwnedGame],
+    ss: &Arc<SupportingService>,
+) -> Result<(MediaSource, HashMap<i64, String>)> {
+    let source = if ss.config.video_games.is_igdb_enabled() {
+        MediaSource::Igdb
+    } else if ss.config.video_games.is_rawg_enabled() {
+        MediaSource::Rawg
+    } else {
+        bail!("A video game provider needs to be configured to sync games from Steam");
+    };
+    let cache_key =
+        |app_id| ApplicationCacheKey::SteamGameMapping(SteamGameMapping { app_id, source });
+    let cached = ss
+        .cache_service
+        .get_values(games.iter().map(|g| cache_key(g.appid)).collect())
+        .await
+        .unwrap_or_default();
+    let mut identifiers = HashMap::new();
+    let mut missing = vec![];
+    for game in games {
+        match cached.get(&cache_key(game.appid)) {
+            Some(ApplicationCacheValue::SteamGameMapping(identifier)) => {
+                identifiers.extend(identifier.clone().map(|i| (game.appid, i)));
+            }
+            _ => missing.push(game),
+        }
+    }
+    let found = match source {
+        MediaSource::Igdb => {
+            let app_ids = missing.iter().map(|g| g.appid).collect::<Vec<_>>();
+            let found = IgdbService::new(ss.clone())
+                .await
+                .get_steam_game_ids(&app_ids)
+                .await?;
+            missing
+                .iter()
+                .map(|g| (g.appid, found.get(&g.appid).cloned()))
+                .collect::<Vec<_>>()
+        }
+        _ => {
+            let service = RawgService::new(&ss.config.video_games.rawg).await;
+            let mut found = vec![];
+            for game in missing {
+                match service.find_steam_game(&game.name).await {
+                    Ok(identifier) => found.push((game.appid, identifier)),
+                    Err(e) => ryot_log!(debug, "Error finding game {}: {:?}", game.name, e),
+                }
+            }
+            found
+        }
+    };
+    // Games that were not found are looked up again on the next sync since they might
+    // have been added to the provider in the meantime
+    ss.cache_service
+        .set_keys(
+            found
+                .iter()
+                .filter(|(_, identifier)| identifier.is_some())
+                .map(|(app_id, identifier)| {
+                    (
+                        cache_key(*app_id),
+                        ApplicationCacheValue::SteamGameMapping(identifier.clone()),
+                    )
+                })
+                .collect(),
+        )
+        .await
+        .ok();
+    identifiers.extend(
+        found
+            .into_iter()
+            .filter_map(|(app_id, identifier)| identifier.map(|i| (app_id, i))),
+    );
+    Ok((source, identifiers))
+}
+
+/// The time spent (in seconds) on the finished and dropped seen entries of the games,
+/// by the identifier of the game. The entry in progress is not included since it is
+/// updated with the new time instead.
+async fn get_recorded_time_spent(
+    user_id: &String,
+    source: MediaSource,
+    identifiers: Vec<String>,
+    ss: &Arc<SupportingService>,
+) -> Result<HashMap<String, Decimal>> {
+    let recorded: Vec<(String, Option<Decimal>)> = Seen::find()
+        .select_only()
+        .column(metadata::Column::Identifier)
+        .column_as(seen::Column::ManualTimeSpent.sum(), "time_spent")
+        .inner_join(Metadata)
+        .filter(seen::Column::UserId.eq(user_id))
+        .filter(metadata::Column::Source.eq(source))
+        .filter(metadata::Column::Lot.eq(MediaLot::VideoGame))
+        .filter(metadata::Column::Identifier.is_in(identifiers))
+        .filter(
+            Condition::any()
+                .add(seen::Column::Progress.gte(100))
+                .add(seen::Column::State.eq(SeenState::Dropped)),
+        )
+        .group_by(metadata::Column::Identifier)
+        .into_tuple()
+        .all(&ss.db)
+        .await?;
+    Ok(recorded
+        .into_iter()
+        .filter_map(|(identifier, time_spent)| time_spent.map(|t| (identifier, t)))
+        .collect())
+}
+
+fn to_import_items(
+    games: Vec<OwnedGame>,
+    source: MediaSource,
+    identifiers: HashMap<i64, String>,
+    to_item: impl Fn(&OwnedGame) -> ImportOrExportMetadataItem,
+) -> ImportResult {
+    let mut result = ImportResult::default();
+    for game in games {
+        let Some(identifier) = identifiers.get(&game.appid) else {
+            ryot_log!(debug, "No metadata found for Steam game {}", game.name);
+            continue;
+        };
+        result
+            .completed
+            .push(ImportCompletedItem::Metadata(ImportOrExportMetadataItem {
+                source,
+                lot: MediaLot::VideoGame,
+                identifier: identifier.clone(),
+                source_id: game.name.clone(),
+                ..to_item(&game)
+            }));
+    }
+    result
+}
+
+/// Sync the playtime of the games played since the last sync. If the integration
+/// has never run, the games played in the last two weeks are synced. Steam only
+/// reports the total playtime, so the time already recorded on earlier playthroughs
+/// is subtracted from it.
+pub async fn yank_progress(
+    user_id: &String,
+    api_key: String,
+    steam_user_id: String,
+    last_triggered_on: Option<DateTimeUtc>,
+    ss: &Arc<SupportingService>,
+) -> Result<ImportResult> {
+    let games = get_owned_games(&api_key, &steam_user_id)
+        .await?
+        .into_iter()
+        .filter(|g| {
+            g.playtime_forever > 0
+                && match last_triggered_on {
+                    Some(t) => g.rtime_last_played > t.timestamp(),
+                    None => g.playtime_2weeks > 0,
+                }
+        })
+        .collect::<Vec<_>>();
+    let (source, identifiers) = get_metadata_identifiers(&games, ss).await?;
+    let recorded =
+        get_recorded_time_spent(user_id, source, identifiers.values().cloned().collect(), ss)
+            .await?;
+    let time_spent = games
+        .iter()
+        .filter_map(|game| {
+            let recorded = identifiers
+                .get(&game.appid)
+                .and_then(|i| recorded.get(i))
+                .copied()
+                .unwrap_or_default();
+            let time_spent = Decimal::from(game.playtime_forever * 60) - recorded;
+            (time_spent > Decimal::ZERO).then_some((game.appid, time_spent))
+        })
+        .collect::<HashMap<_, _>>();
+    let games = games
+        .into_iter()
+        .filter(|g| time_spent.contains_key(&g.appid))
+        .collect();
+    Ok(to_import_items(games, source, identifiers, |game| {
+        ImportOrExportMetadataItem {
+            seen_history: vec![ImportOrExportMetadataItemSeen {
+                progress: Some(PLAYED_PROGRESS),
+                provider_watched_on: Some("Steam".to_owned()),
+                manual_time_spent: time_spent.get(&game.appid).copied(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }))
+}
+
+pub async fn sync_to_owned_collection(
+    api_key: String,
+    steam_user_id: String,
+    ss: &Arc<SupportingService>,
+) -> Result<ImportResult> {
+    let games = get_owned_games(&api_key, &steam_user_id).await?;
+    let (source, identifiers) = get_metadata_identifiers(&games, ss).await?;
+    Ok(to_import_items(games, source, identifiers, |_| {
+        ImportOrExportMetadataItem {
+            collections: vec![DefaultCollection::Owned.to_string()],
+            ..Default::default()
+        }
+    }))
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use common_utils::ryot_log;
+use database_models::{
+    metadata,
+    prelude::{Metadata, Review, Seen},
+    review, seen,
+};
+use dependent_models::{ImportCompletedItem, ImportResult};
+use enum_models::{MediaLot, MediaSource, SeenState};
+use external_utils::trakt::{get_authenticated_client, API_URL};
+use media_models::{
+    ImportOrExportItemRating, ImportOrExportItemReview, ImportOrExportMetadataItem,
+    ImportOrExportMetadataItemSeen,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sea_orm::{prelude::DateTimeUtc, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::get_trakt_watched_at;
+
+pub const PROVIDER_NAME: &str = "Trakt";
+const PAGE_SIZE: i32 = 100;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Id {
+    tmdb: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Item {
+    title: Option<String>,
+    season: Option<i32>,
+    number: Option<i32>,
+    ids: Id,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HistoryItem {
+    watched_at: DateTimeUtc,
+    movie: Option<Item>,
+    show: Option<Item>,
+    episode: Option<Item>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RatingItem {
+    rated_at: DateTimeUtc,
+    rating: Decimal,
+    movie: Option<Item>,
+    show: Option<Item>,
+}
+
+fn get_metadata_item<'a>(
+    items: &'a mut HashMap<(MediaLot, String), ImportOrExportMetadataItem>,
+    movie: &Option<Item>,
+    show: &Option<Item>,
+) -> Option<&'a mut ImportOrExportMetadataItem> {
+    let (lot, item) = match (movie, show) {
+        (Some(movie), _) => (MediaLot::Movie, movie),
+        (_, Some(show)) => (MediaLot::Show, show),
+        _ => return None,
+    };
+    let Some(tmdb_id) = item.ids.tmdb else {
+        ryot_log!(debug, "No TMDB ID found for Trakt item {:?}", item.title);
+        return None;
+    };
+    let identifier = tmdb_id.to_string();
+    Some(
+        items
+            .entry((lot, identifier.clone()))
+            .or_insert_with(|| ImportOrExportMetadataItem {
+                lot,
+                identifier,
+                source: MediaSource::Tmdb,
+                source_id: item.title.clone().unwrap_or_default(),
+                ..Default::default()
+            }),
+    )
+}
+
+async fn get_history(
+    client: &reqwest::Client,
+    start: Option<DateTimeUtc>,
+) -> Result<Vec<HistoryItem>> {
+    let mut history = vec![];
+    let mut page = 1;
+    loop {
+        let mut query = vec![("page", page.to_string()), ("limit", PAGE_SIZE.to_string())];
+        if let Some(start) = start {
+            query.push(("start_at", start.to_rfc3339()));
+        }
+        let response = client
+            .get(format!("{}/sync/history", API_URL))
+            .query(&query)
+            .send()
+            .await?
+            .error_for_status()?;
+        let page_count = response
+            .headers()
+            .get("x-pagination-page-count")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<i32>().ok())
+            .unwrap_or(1);
+        history.extend(response.json::<Vec<HistoryItem>>().await?);
+        if page >= page_count {
+            break;
+        }
+        page += 1;
+    }
+    Ok(history)
+}
+
+async fn get_ratings(
+    client: &reqwest::Client,
+    start: Option<DateTimeUtc>,
+) -> Result<Vec<RatingItem>> {
+    let mut ratings = vec![];
+    for kind in ["movies", "shows"] {
+        let items = client
+            .get(format!("{}/sync/ratings/{}", API_URL, kind))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Vec<RatingItem>>()
+            .await?;
+        ratings.extend(
+            items
+                .into_iter()
+                .filter(|r| start.is_none_or(|start| r.rated_at > start)),
+        );
+    }
+    Ok(ratings)
+}
+
+/// Remove the entries that are already present in Ryot. These are usually the ones
+/// that were pushed to Trakt by this integration in the first place.
+async fn remove_existing_entries(
+    item: &mut ImportOrExportMetadataItem,
+    user_id: &String,
+    db: &DatabaseConnection,
+) -> Result<()> {
+    let Some(metadata) = Metadata::find()
+        .filter(metadata::Column::Lot.eq(item.lot))
+        .filter(metadata::Column::Source.eq(item.source))
+        .filter(metadata::Column::Identifier.eq(&item.identifier))
+        .one(db)
+        .await?
+    else {
+        return Ok(());
+    };
+    let seen_history = Seen::find()
+        .filter(seen::Column::UserId.eq(user_id))
+        .filter(seen::Column::MetadataId.eq(&metadata.id))
+        .filter(seen::Column::State.eq(SeenState::Completed))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|s| {
+            let watched_on = get_trakt_watched_at(&s).date_naive();
+            let show = s.show_extra_information.map(|e| (e.season, e.episode));
+            (Some(watched_on), show)
+        })
+        .collect::<Vec<(Option<NaiveDate>, Option<(i32, i32)>)>>();
+    item.seen_history.retain(|s| {
+        let show = s.show_season_number.zip(s.show_episode_number);
+        !seen_history.contains(&(s.ended_on, show))
+    });
+    let ratings = Review::find()
+        .filter(review::Column::UserId.eq(user_id))
+        .filter(review::Column::MetadataId.eq(&metadata.id))
+        .filter(review::Column::ShowExtraInformation.is_null())
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|r| r.rating.map(|r| (r / dec!(10)).round()))
+        .collect::<Vec<_>>();
+    item.reviews.retain(|r| {
+        r.rating
+            .is_none_or(|rating| !ratings.contains(&(rating / dec!(10)).round()))
+    });
+    Ok(())
+}
+
+/// Sync the watch history and ratings added on Trakt since the given time, or all of
+/// them if the integration has not been triggered yet.
+pub async fn yank_progress(
+    client_id: &str,
+    access_token: String,
+    start: Option<DateTimeUtc>,
+    user_id: &String,
+    db: &DatabaseConnection,
+) -> Result<ImportResult> {
+    let client = get_authenticated_client(client_id, &access_token)?;
+    let mut items = HashMap::new();
+    for entry in get_history(&client, start).await? {
+        let Some(item) = get_metadata_item(&mut items, &entry.movie, &entry.show) else {
+            continue;
+        };
+        let (show_season_number, show_episode_number) = match &entry.episode {
+            Some(episode) => (episode.season, episode.number),
+            None => (None, None),
+        };
+        item.seen_history.push(ImportOrExportMetadataItemSeen {
+            show_season_number,
+            show_episode_number,
+            ended_on: Some(entry.watched_at.date_naive()),
+            provider_watched_on: Some(PROVIDER_NAME.to_owned()),
+            ..Default::default()
+        });
+    }
+    for entry in get_ratings(&client, start).await? {
+        let Some(item) = get_metadata_item(&mut items, &entry.movie, &entry.show) else {
+            continue;
+        };
+        item.reviews.push(ImportOrExportItemRating {
+            rating: Some(entry.rating * dec!(10)),
+            review: Some(ImportOrExportItemReview {
+                date: Some(entry.rated_at),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+    let mut result = ImportResult::default();
+    for mut item in items.into_values() {
+        remove_existing_entries(&mut item, user_id, db).await?;
+        if item.seen_history.is_empty() && item.reviews.is_empty() {
+            continue;
+        }
+        result.completed.push(ImportCompletedItem::Metadata(item));
+    }
+    Ok(result)
+}
@@ -6,35 +6,38 @@ use std::{
 
 use application_utils::{
     get_current_date, get_podcast_episode_by_number, get_show_episode_by_numbers,
+    remove_expired_provider_responses,
 };
 use async_graphql::{Error, Result};
 use background_models::{ApplicationJob, HpApplicationJob, MpApplicationJob};
-use chrono::{Days, NaiveDate, Utc};
+use chrono::{Datelike, Days, Duration as ChronoDuration, NaiveDate, Timelike, Utc};
 use common_models::{
     ApplicationCacheKey, BackgroundJob, ChangeCollectionToEntityInput, DefaultCollection,
     IdAndNamedObject, MetadataGroupSearchInput, MetadataSearchInput, PeopleSearchInput,
     ProgressUpdateCacheInput, SearchDetails, SearchInput, StoredUrl, StringIdObject,
-    UserLevelCacheKey, UserNotificationContent,
+    UserLevelCacheKey, UserNotificationContent, UserNotificationPayload,
 };
 use common_utils::{
-    get_first_and_last_day_of_month, ryot_log, PAGE_SIZE, SHOW_SPECIAL_SEASON_NAMES,
+    get_first_and_last_day_of_month, ryot_log, METADATA_LOT_MAPPINGS, PAGE_SIZE,
+    SHOW_SPECIAL_SEASON_NAMES,
 };
 use database_models::{
     access_link, application_cache, calendar_event, collection, collection_to_entity,
     functions::{associate_user_with_entity, get_user_to_entity_association},
     genre, import_report, metadata, metadata_group, metadata_group_to_person, metadata_to_genre,
     metadata_to_metadata, metadata_to_metadata_group, metadata_to_person, monitored_entity,
-    notification_platform, person,
+    notification_delivery, notification_platform, person,
     prelude::{
         AccessLink, ApplicationCache, CalendarEvent, Collection, CollectionToEntity, Genre,
         ImportReport, Metadata, MetadataGroup, MetadataGroupToPerson, MetadataToGenre,
         MetadataToMetadata, MetadataToMetadataGroup, MetadataToPerson, MonitoredEntity,
-        NotificationPlatform, Person, Review, Seen, User, UserNotification, UserToEntity,
+        NotificationDelivery, NotificationPlatform, Person, Review, Seen, User, UserNotification,
+        UserToEntity,
     },
     review, seen, user, user_notification, user_to_entity,
 };
 use database_utils::{
-    calculate_user_activities_and_summary, entity_in_collections,
+    admin_account_guard, calculate_user_activities_and_summary, entity_in_collections,
     entity_in_collections_with_collection_to_entity_ids, ilike_sql, item_reviews,
     revoke_access_link, user_by_id,
 };
@@ -48,46 +51,56 @@ use dependent_utils::{
     add_entity_to_collection, change_metadata_associations, commit_metadata, commit_metadata_group,
     commit_person, create_notification_for_user, create_partial_metadata, create_user_notification,
     deploy_after_handle_media_seen_tasks, deploy_background_job, deploy_update_metadata_group_job,
-    deploy_update_metadata_job, deploy_update_person_job, first_metadata_image_as_url,
-    get_entity_recently_consumed, get_google_books_service, get_hardcover_service,
-    get_metadata_provider, get_openlibrary_service, get_tmdb_non_media_service,
+    deploy_update_metadata_job, deploy_update_person_job, details_from_provider,
+    first_metadata_image_as_url, get_entity_details_frontend_url, get_entity_recently_consumed,
+    get_google_books_service, get_hardcover_service, get_metadata_provider,
+    get_notification_payload, get_openlibrary_service, get_show_episode_numbers_in_ordering,
+    get_show_specifics_in_ordering, get_tmdb_non_media_service,
     get_users_and_cte_monitoring_entity, get_users_monitoring_entity,
-    handle_after_media_seen_tasks, is_metadata_finished_by_user, metadata_groups_list,
-    metadata_images_as_urls, metadata_list, people_list, post_review, progress_update,
-    refresh_collection_to_entity_association, remove_entity_from_collection,
+    get_users_preferred_languages, handle_after_media_seen_tasks, is_metadata_finished_by_user,
+    metadata_groups_list, metadata_images_as_urls, metadata_list, people_list, post_review,
+    progress_update, refresh_collection_to_entity_association, remove_entity_from_collection,
     update_metadata_and_notify_users,
 };
 use enum_models::{
-    EntityLot, MediaLot, MediaSource, MetadataToMetadataRelation, SeenState, UserNotificationLot,
-    UserToMediaReason,
+    EntityLot, MediaLot, MediaSource, MetadataToMetadataRelation, NotificationDeliveryStatus,
+    SeenState, UserNotificationLot, UserToMediaReason,
 };
 use futures::{future::join_all, TryStreamExt};
 use itertools::Itertools;
 use markdown::{to_html_with_options as markdown_to_html_opts, CompileOptions, Options};
 use media_models::{
-    CommitMediaInput, CommitPersonInput, CreateCustomMetadataInput, CreateOrUpdateReviewInput,
-    CreateReviewCommentInput, GenreDetailsInput, GenreListItem, GraphqlCalendarEvent,
-    GraphqlMediaAssets, GraphqlMetadataDetails, GraphqlMetadataGroup, GraphqlVideoAsset,
-    GroupedCalendarEvent, ImportOrExportItemReviewComment, MarkEntityAsPartialInput,
-    MediaAssociatedPersonStateChanges, MetadataCreator, MetadataCreatorGroupedByRole,
-    MetadataFreeCreator, MetadataGroupsListInput, MetadataImage, MetadataListInput,
+    CalendarEventMonitoredEntity, CommitMediaInput, CommitPersonInput, CreateCustomMetadataInput,
+    CreateOrUpdateReviewInput, CreateReviewCommentInput, GenreDetailsInput, GenreListItem,
+    GraphqlCalendarEvent, GraphqlMediaAssets, GraphqlMetadataDetails, GraphqlMetadataGroup,
+    GraphqlVideoAsset, GroupedCalendarEvent, ImportOrExportItemReviewComment,
+    MarkEntityAsPartialInput, MediaAssociatedPersonStateChanges, MetadataCreator,
+    MetadataCreatorGroupedByRole, MetadataDetails, MetadataFreeCreator, MetadataGroupsListInput,
+    MetadataImage, MetadataLinkedSource, MetadataListInput, MetadataLocalizedDetails,
     MetadataPartialDetails, MetadataVideo, MetadataVideoSource, PartialMetadata,
     PartialMetadataWithoutId, PeopleListInput, PersonDetailsGroupedByRole,
     PersonDetailsItemWithCharacter, PodcastSpecifics, ProgressUpdateInput, ReviewPostedEvent,
-    SeenAnimeExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
-    ShowSpecifics, UniqueMediaIdentifier, UpdateCustomMetadataInput, UpdateSeenItemInput,
-    UserCalendarEventInput, UserMediaNextEntry, UserMetadataDetailsEpisodeProgress,
-    UserMetadataDetailsShowSeasonProgress, UserUpcomingCalendarEventInput,
+    ScrapeCustomMetadataInput, SeenAnimeExtraInformation, SeenPodcastExtraInformation,
+    SeenShowExtraInformation, ShowSpecifics, SwitchMetadataSourceInput, UniqueMediaIdentifier,
+    UpdateCustomMetadataInput, UpdateSeenItemInput, UserCalendarEventInput, UserCalendarFeedInput,
+    UserMediaNextEntry, UserMetadataDetailsEpisodeProgress, UserMetadataDetailsShowSeasonProgress,
+    UserUpcomingCalendarEventInput,
 };
 use migrations::{
     AliasedCalendarEvent, AliasedMetadata, AliasedMetadataToGenre, AliasedSeen, AliasedUserToEntity,
 };
 use nanoid::nanoid;
-use notification_service::send_notification;
+use notification_service::{
+    is_transient_notification_error, send_notification, NotificationMessage, NotificationPriority,
+};
 use providers::{
-    anilist::NonMediaAnilistService, audible::AudibleService, igdb::IgdbService,
+    anilist::NonMediaAnilistService, audible::AudibleService,
+    board_game_geek::BoardGameGeekService, comic_vine::ComicVineService, igdb::IgdbService,
     itunes::ITunesService, listennotes::ListennotesService, mal::NonMediaMalService,
-    manga_updates::MangaUpdatesService, vndb::VndbService, youtube_music::YoutubeMusicService,
+    manga_updates::MangaUpdatesService, music_brainz::MusicBrainzService,
+    podcast_index::PodcastIndexService, rawg::RawgService, remote::RemoteService, rss::RssService,
+    tvdb::NonMediaTvdbService, vndb::VndbService, web_page::WebPageService,
+    youtube_music::YoutubeMusicService,
 };
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -101,16 +114,34 @@ use sea_query::{
     extension::postgres::PgExpr, Alias, Asterisk, Condition, Expr, Func, PgFunc,
     PostgresQueryBuilder, Query, SelectStatement,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
 use supporting_service::SupportingService;
 use traits::{MediaProvider, TraceOk};
-use user_models::DashboardElementLot;
+use user_models::{DashboardElementLot, UserNotificationDigest};
 use uuid::Uuid;
 
 static ENTITY_UPDATE_CHUNK_SIZE: usize = 5;
+static MAX_NOTIFICATION_DELIVERY_ATTEMPTS: i32 = 5;
+static MAX_NOTIFICATION_PLATFORM_CONSECUTIVE_FAILURES: i32 = 10;
 
 type Provider = Box<(dyn MediaProvider + Send + Sync)>;
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct UserMediaReminder {
+    reminder: NaiveDate,
+    text: String,
+}
+
+#[derive(Debug)]
+struct CalendarFeedEvent {
+    uid: String,
+    url: String,
+    date: NaiveDate,
+    summary: String,
+    description: Option<String>,
+}
+
 pub struct MiscellaneousService(pub Arc<SupportingService>);
 
 impl MiscellaneousService {
@@ -357,8 +388,38 @@ ORDER BY RANDOM() LIMIT 10;
         })
     }
 
+    /// Replace the title and description of a metadata with the variant in the language
+    /// preferred by the user, if one exists.
+    async fn localize_metadata_details(
+        &self,
+        user_id: Option<String>,
+        localized_details: Option<Vec<MetadataLocalizedDetails>>,
+        title: &mut String,
+        description: &mut Option<String>,
+    ) -> Result<()> {
+        let Some(user_id) = user_id else {
+            return Ok(());
+        };
+        let language = user_by_id(&user_id, &self.0)
+            .await?
+            .preferences
+            .general
+            .language;
+        let Some(localized) = get_localized_details(localized_details, language.as_ref()) else {
+            return Ok(());
+        };
+        if let Some(localized_title) = localized.title {
+            *title = localized_title;
+        }
+        if localized.description.is_some() {
+            *description = localized.description;
+        }
+        Ok(())
+    }
+
     pub async fn metadata_partial_details(
         &self,
+        user_id: Option<String>,
         metadata_id: &String,
     ) -> Result<MetadataPartialDetails> {
         let mut metadata = Metadata::find_by_id(metadata_id)
@@ -369,6 +430,7 @@ ORDER BY RANDOM() LIMIT 10;
                 metadata::Column::Title,
                 metadata::Column::Images,
                 metadata::Column::PublishYear,
+                metadata::Column::LocalizedDetails,
             ])
             .into_model::<MetadataPartialDetails>()
             .one(&self.0.db)
@@ -377,6 +439,13 @@ ORDER BY RANDOM() LIMIT 10;
             .ok_or_else(|| Error::new("The record does not exist".to_owned()))?;
         metadata.image =
             first_metadata_image_as_url(&metadata.images, &self.0.file_storage_service).await;
+        self.localize_metadata_details(
+            user_id,
+            metadata.localized_details.take(),
+            &mut metadata.title,
+            &mut None,
+        )
+        .await?;
         Ok(metadata)
     }
 
@@ -384,9 +453,13 @@ ORDER BY RANDOM() LIMIT 10;
         deploy_update_metadata_job(metadata_id, &self.0).await
     }
 
-    pub async fn metadata_details(&self, metadata_id: &String) -> Result<GraphqlMetadataDetails> {
+    pub async fn metadata_details(
+        &self,
+        user_id: Option<String>,
+        metadata_id: &String,
+    ) -> Result<GraphqlMetadataDetails> {
         let MetadataBaseData {
-            model,
+            mut model,
             creators,
             assets,
             genres,
@@ -408,7 +481,28 @@ ORDER BY RANDOM() LIMIT 10;
             });
         }
 
-        let watch_providers = model.watch_providers.unwrap_or_default();
+        let mut watch_providers = model.watch_providers.unwrap_or_default();
+        if let Some(user_id) = &user_id {
+            let preferences = user_by_id(user_id, &self.0).await?.preferences.general;
+            if let Some(region) = preferences.region {
+                watch_providers.retain(|p| p.languages.contains(&region));
+            }
+            model.show_specifics = model
+                .show_specifics
+                .map(|s| get_show_specifics_in_ordering(s, preferences.show_episode_ordering));
+        }
+        self.localize_metadata_details(
+            user_id,
+            model.localized_details.take(),
+            &mut model.title,
+            &mut model.description,
+        )
+        .await?;
+        let linked_sources = model
+            .external_identifiers
+            .clone()
+            .map(|e| e.linked_sources)
+            .unwrap_or_default();
 
         let resp = GraphqlMetadataDetails {
             group,
@@ -416,6 +510,7 @@ ORDER BY RANDOM() LIMIT 10;
             genres,
             creators,
             suggestions,
+            linked_sources,
             id: model.id,
             lot: model.lot,
             watch_providers,
@@ -433,6 +528,8 @@ ORDER BY RANDOM() LIMIT 10;
             movie_specifics: model.movie_specifics,
             music_specifics: model.music_specifics,
             manga_specifics: model.manga_specifics,
+            comic_specifics: model.comic_specifics,
+            board_game_specifics: model.board_game_specifics,
             anime_specifics: model.anime_specifics,
             provider_rating: model.provider_rating,
             production_status: model.production_status,
@@ -452,12 +549,35 @@ ORDER BY RANDOM() LIMIT 10;
         user_id: String,
         metadata_id: String,
     ) -> Result<UserMetadataDetails> {
-        let media_details = self.generic_metadata(&metadata_id).await?;
+        let mut media_details = self.generic_metadata(&metadata_id).await?;
         let collections =
             entity_in_collections(&self.0.db, &user_id, &metadata_id, EntityLot::Metadata).await?;
         let reviews =
             item_reviews(&user_id, &metadata_id, EntityLot::Metadata, true, &self.0).await?;
-        let (_, history) = is_metadata_finished_by_user(&user_id, &metadata_id, &self.0.db).await?;
+        let (_, mut history) =
+            is_metadata_finished_by_user(&user_id, &metadata_id, &self.0.db).await?;
+        // The episodes are always stored in the aired ordering, so the seen items are
+        // converted to the ordering the user has chosen to track the show in.
+        if let Some(show_specifics) = media_details.model.show_specifics.take() {
+            let ordering = user_by_id(&user_id, &self.0)
+                .await?
+                .preferences
+                .general
+                .show_episode_ordering;
+            if let Some(numbers) = get_show_episode_numbers_in_ordering(&show_specifics, ordering) {
+                for info in history
+                    .iter_mut()
+                    .filter_map(|h| h.show_extra_information.as_mut())
+                {
+                    if let Some((season, episode)) = numbers.get(&(info.season, info.episode)) {
+                        info.season = *season;
+                        info.episode = *episode;
+                    }
+                }
+            }
+            media_details.model.show_specifics =
+                Some(get_show_specifics_in_ordering(show_specifics, ordering));
+        }
         let in_progress = history
             .iter()
             .find(|h| h.state == SeenState::InProgress || h.state == SeenState::OnAHold)
@@ -691,6 +811,130 @@ ORDER BY RANDOM() LIMIT 10;
         })
     }
 
+    /// The media that would already be part of the calendar of the user.
+    fn tracked_metadata_query(user_id: &String, only_monitored: bool) -> SelectStatement {
+        UserToEntity::find()
+            .select_only()
+            .column(user_to_entity::Column::MetadataId)
+            .filter(user_to_entity::Column::UserId.eq(user_id))
+            .filter(user_to_entity::Column::MetadataId.is_not_null())
+            .apply_if(only_monitored.then_some(()), |query, _| {
+                query.filter(
+                    Expr::val(UserToMediaReason::Monitoring.to_string())
+                        .eq(PgFunc::any(Expr::col(user_to_entity::Column::MediaReason))),
+                )
+            })
+            .into_query()
+    }
+
+    fn monitored_entities_query(user_id: &String, lot: EntityLot) -> SelectStatement {
+        MonitoredEntity::find()
+            .select_only()
+            .column(monitored_entity::Column::EntityId)
+            .filter(monitored_entity::Column::UserId.eq(user_id))
+            .filter(monitored_entity::Column::EntityLot.eq(lot))
+            .into_query()
+    }
+
+    /// Matches the media in `column` that are associated with the people and groups
+    /// monitored by the user and that are not already part of their calendar.
+    fn untracked_metadata_of_monitored_entities_condition(
+        column: impl ColumnTrait,
+        user_id: &String,
+        only_monitored: bool,
+    ) -> Condition {
+        let people = MetadataToPerson::find()
+            .select_only()
+            .column(metadata_to_person::Column::MetadataId)
+            .filter(
+                metadata_to_person::Column::PersonId
+                    .in_subquery(Self::monitored_entities_query(user_id, EntityLot::Person)),
+            )
+            .into_query();
+        let groups = MetadataToMetadataGroup::find()
+            .select_only()
+            .column(metadata_to_metadata_group::Column::MetadataId)
+            .filter(
+                metadata_to_metadata_group::Column::MetadataGroupId.in_subquery(
+                    Self::monitored_entities_query(user_id, EntityLot::MetadataGroup),
+                ),
+            )
+            .into_query();
+        Condition::all()
+            .add(
+                Condition::any()
+                    .add(column.in_subquery(people))
+                    .add(column.in_subquery(groups)),
+            )
+            .add(column.not_in_subquery(Self::tracked_metadata_query(user_id, only_monitored)))
+    }
+
+    /// The media that are associated with the people and groups monitored by the user,
+    /// along with the entity they are associated with. Media that would already be
+    /// part of the calendar of the user are left out.
+    async fn get_untracked_metadata_of_monitored_entities(
+        &self,
+        user_id: &String,
+        only_monitored: bool,
+    ) -> Result<HashMap<String, CalendarEventMonitoredEntity>> {
+        #[derive(Debug, FromQueryResult)]
+        struct Association {
+            name: String,
+            entity_id: String,
+            metadata_id: String,
+            entity_lot: EntityLot,
+        }
+
+        let people = MetadataToPerson::find()
+            .select_only()
+            .column(metadata_to_person::Column::MetadataId)
+            .column_as(person::Column::Id, "entity_id")
+            .column_as(person::Column::Name, "name")
+            .column_as(Expr::val(EntityLot::Person), "entity_lot")
+            .inner_join(Person)
+            .filter(
+                metadata_to_person::Column::PersonId
+                    .in_subquery(Self::monitored_entities_query(user_id, EntityLot::Person)),
+            )
+            .filter(
+                metadata_to_person::Column::MetadataId
+                    .not_in_subquery(Self::tracked_metadata_query(user_id, only_monitored)),
+            )
+            .into_model::<Association>()
+            .all(&self.0.db)
+            .await?;
+        let groups = MetadataToMetadataGroup::find()
+            .select_only()
+            .column(metadata_to_metadata_group::Column::MetadataId)
+            .column_as(metadata_group::Column::Id, "entity_id")
+            .column_as(metadata_group::Column::Title, "name")
+            .column_as(Expr::val(EntityLot::MetadataGroup), "entity_lot")
+            .inner_join(MetadataGroup)
+            .filter(
+                metadata_to_metadata_group::Column::MetadataGroupId.in_subquery(
+                    Self::monitored_entities_query(user_id, EntityLot::MetadataGroup),
+                ),
+            )
+            .filter(
+                metadata_to_metadata_group::Column::MetadataId
+                    .not_in_subquery(Self::tracked_metadata_query(user_id, only_monitored)),
+            )
+            .into_model::<Association>()
+            .all(&self.0.db)
+            .await?;
+        let mut associations = HashMap::new();
+        for association in people.into_iter().chain(groups) {
+            associations
+                .entry(association.metadata_id)
+                .or_insert(CalendarEventMonitoredEntity {
+                    name: association.name,
+                    entity_id: association.entity_id,
+                    entity_lot: association.entity_lot,
+                });
+        }
+        Ok(associations)
+    }
+
     async fn get_calendar_events(
         &self,
         user_id: String,
@@ -708,6 +952,7 @@ ORDER BY RANDOM() LIMIT 10;
             m_title: String,
             metadata_id: String,
             m_images: Option<Vec<MetadataImage>>,
+            m_localized_details: Option<Vec<MetadataLocalizedDetails>>,
             m_show_specifics: Option<ShowSpecifics>,
             m_podcast_specifics: Option<PodcastSpecifics>,
             metadata_show_extra_information: Option<SeenShowExtraInformation>,
@@ -715,6 +960,14 @@ ORDER BY RANDOM() LIMIT 10;
             metadata_podcast_extra_information: Option<SeenPodcastExtraInformation>,
         }
 
+        let language = user_by_id(&user_id, &self.0)
+            .await?
+            .preferences
+            .general
+            .language;
+        let monitored_entities = self
+            .get_untracked_metadata_of_monitored_entities(&user_id, only_monitored)
+            .await?;
         let stmt = Query::select()
             .column(Asterisk)
             .from_subquery(
@@ -742,6 +995,10 @@ ORDER BY RANDOM() LIMIT 10;
                         Expr::col((AliasedMetadata::Table, AliasedMetadata::Images)),
                         "m_images",
                     )
+                    .column_as(
+                        Expr::col((AliasedMetadata::Table, metadata::Column::LocalizedDetails)),
+                        "m_localized_details",
+                    )
                     .column_as(
                         Expr::col((AliasedMetadata::Table, AliasedMetadata::ShowSpecifics)),
                         "m_show_specifics",
@@ -751,27 +1008,42 @@ ORDER BY RANDOM() LIMIT 10;
                         "m_podcast_specifics",
                     )
                     .filter(
-                        Expr::col((AliasedUserToEntity::Table, AliasedUserToEntity::UserId))
-                            .eq(&user_id),
+                        Condition::any()
+                            .add(
+                                Expr::col((
+                                    AliasedUserToEntity::Table,
+                                    AliasedUserToEntity::UserId,
+                                ))
+                                .is_not_null(),
+                            )
+                            .add(Self::untracked_metadata_of_monitored_entities_condition(
+                                calendar_event::Column::MetadataId,
+                                &user_id,
+                                only_monitored,
+                            )),
                     )
                     .inner_join(Metadata)
                     .join_rev(
-                        JoinType::Join,
+                        JoinType::LeftJoin,
                         UserToEntity::belongs_to(CalendarEvent)
                             .from(user_to_entity::Column::MetadataId)
                             .to(calendar_event::Column::MetadataId)
                             .on_condition(move |left, _right| {
-                                Condition::all().add_option(match only_monitored {
-                                    true => Some(
-                                        Expr::val(UserToMediaReason::Monitoring.to_string()).eq(
-                                            PgFunc::any(Expr::col((
-                                                left,
-                                                user_to_entity::Column::MediaReason,
-                                            ))),
+                                Condition::all()
+                                    .add(
+                                        Expr::col((left.clone(), user_to_entity::Column::UserId))
+                                            .eq(user_id.clone()),
+                                    )
+                                    .add_option(match only_monitored {
+                                        true => Some(
+                                            Expr::val(UserToMediaReason::Monitoring.to_string())
+                                                .eq(PgFunc::any(Expr::col((
+                                                    left,
+                                                    user_to_entity::Column::MediaReason,
+                                                )))),
                                         ),
-                                    ),
-                                    false => None,
-                                })
+                                        false => None,
+                                    })
                             })
                             .into(),
                     )
@@ -797,7 +1069,9 @@ ORDER BY RANDOM() LIMIT 10;
                 date: evt.date,
                 metadata_lot: evt.m_lot,
                 calendar_event_id: evt.id,
-                metadata_title: evt.m_title,
+                metadata_title: get_localized_details(evt.m_localized_details, language.as_ref())
+                    .and_then(|l| l.title)
+                    .unwrap_or(evt.m_title),
                 metadata_id: evt.metadata_id,
                 ..Default::default()
             };
@@ -830,6 +1104,7 @@ ORDER BY RANDOM() LIMIT 10;
             }
             calc.metadata_image = image;
             calc.episode_name = title;
+            calc.monitored_entity = monitored_entities.get(&calc.metadata_id).cloned();
             events.push(calc);
         }
         Ok(events)
@@ -886,6 +1161,140 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(events)
     }
 
+    /// Render the calendar events and reminders of the user that owns the token as an
+    /// iCalendar document, so that it can be subscribed to from calendar applications.
+    pub async fn user_calendar_feed(
+        &self,
+        token: String,
+        input: UserCalendarFeedInput,
+    ) -> Result<String> {
+        let Some(user) = User::find()
+            .filter(Expr::cust_with_values(
+                "extra_information ->> 'calendar_feed_token' = $1",
+                [token],
+            ))
+            .one(&self.0.db)
+            .await?
+        else {
+            return Err(Error::new("Invalid calendar feed token"));
+        };
+        let lots = input
+            .lots
+            .iter()
+            .flat_map(|l| l.split(','))
+            .map(|l| MediaLot::deserialize(l.trim().into_deserializer()))
+            .collect::<Result<Vec<_>, serde::de::value::Error>>()?;
+        let collection_ids = input
+            .collections
+            .iter()
+            .flat_map(|c| c.split(','))
+            .map(|c| c.trim().to_owned())
+            .filter(|c| !c.is_empty())
+            .collect_vec();
+        let entities_in_collections = match collection_ids.is_empty() {
+            true => None,
+            false => Some(
+                CollectionToEntity::find()
+                    .select_only()
+                    .column(collection_to_entity::Column::EntityId)
+                    .filter(collection_to_entity::Column::CollectionId.is_in(collection_ids))
+                    .into_tuple::<String>()
+                    .all(&self.0.db)
+                    .await?
+                    .into_iter()
+                    .collect::<HashSet<_>>(),
+            ),
+        };
+        let is_included = |entity_id: &String, lot: Option<MediaLot>| {
+            let lot_matches = lots.is_empty() || lot.is_some_and(|l| lots.contains(&l));
+            let collection_matches = entities_in_collections
+                .as_ref()
+                .is_none_or(|e| e.contains(entity_id));
+            lot_matches && collection_matches
+        };
+        let language = user.preferences.general.language.clone();
+        let start_date = get_current_date(&self.0.timezone) - Days::new(30);
+        let mut events = self
+            .get_calendar_events(user.id.clone(), false, Some(start_date), None, None, None)
+            .await?
+            .into_iter()
+            .filter(|e| is_included(&e.metadata_id, Some(e.metadata_lot)))
+            .map(|e| {
+                let episode = match (
+                    e.show_extra_information,
+                    e.podcast_extra_information,
+                    e.anime_extra_information.and_then(|a| a.episode),
+                ) {
+                    (Some(s), _, _) => Some(format!("S{:02}E{:02}", s.season, s.episode)),
+                    (_, Some(p), _) => Some(format!("EP{}", p.episode)),
+                    (_, _, Some(a)) => Some(format!("EP{}", a)),
+                    _ => None,
+                };
+                CalendarFeedEvent {
+                    date: e.date,
+                    uid: e.calendar_event_id,
+                    description: e.episode_name,
+                    summary: match episode {
+                        Some(episode) => format!("{} {}", e.metadata_title, episode),
+                        None => e.metadata_title,
+                    },
+                    url: get_entity_details_frontend_url(
+                        e.metadata_id,
+                        EntityLot::Metadata,
+                        None,
+                        &self.0,
+                    ),
+                }
+            })
+            .collect_vec();
+        let reminder_collections = Collection::find()
+            .select_only()
+            .column(collection::Column::Id)
+            .filter(collection::Column::Name.eq(DefaultCollection::Reminders.to_string()))
+            .filter(
+                collection::Column::Id.in_subquery(
+                    UserToEntity::find()
+                        .select_only()
+                        .column(user_to_entity::Column::CollectionId)
+                        .filter(user_to_entity::Column::UserId.eq(&user.id))
+                        .filter(user_to_entity::Column::CollectionId.is_not_null())
+                        .into_query(),
+                ),
+            )
+            .into_query();
+        for (cte, metadata) in CollectionToEntity::find()
+            .find_also_related(Metadata)
+            .filter(collection_to_entity::Column::CollectionId.in_subquery(reminder_collections))
+            .all(&self.0.db)
+            .await?
+        {
+            let Some(information) = cte.information else {
+                continue;
+            };
+            let Ok(reminder) = serde_json::from_value::<UserMediaReminder>(information) else {
+                continue;
+            };
+            if reminder.reminder < start_date
+                || !is_included(&cte.entity_id, metadata.as_ref().map(|m| m.lot))
+            {
+                continue;
+            }
+            events.push(CalendarFeedEvent {
+                uid: cte.id.to_string(),
+                date: reminder.reminder,
+                summary: reminder.text,
+                description: metadata.map(|m| {
+                    get_localized_details(m.localized_details, language.as_ref())
+                        .and_then(|l| l.title)
+                        .unwrap_or(m.title)
+                }),
+                url: get_entity_details_frontend_url(cte.entity_id, cte.entity_lot, None, &self.0),
+            });
+        }
+        events.sort_by_key(|e| e.date);
+        Ok(get_calendar_feed(events))
+    }
+
     pub async fn metadata_list(
         &self,
         user_id: String,
@@ -897,8 +1306,41 @@ ORDER BY RANDOM() LIMIT 10;
     pub async fn deploy_bulk_progress_update(
         &self,
         user_id: String,
-        input: Vec<ProgressUpdateInput>,
+        mut input: Vec<ProgressUpdateInput>,
     ) -> Result<bool> {
+        let ordering = user_by_id(&user_id, &self.0)
+            .await?
+            .preferences
+            .general
+            .show_episode_ordering;
+        let mut episode_numbers = HashMap::new();
+        for item in input.iter_mut() {
+            let (Some(season), Some(episode)) = (item.show_season_number, item.show_episode_number)
+            else {
+                continue;
+            };
+            if !episode_numbers.contains_key(&item.metadata_id) {
+                let numbers = Metadata::find_by_id(&item.metadata_id)
+                    .one(&self.0.db)
+                    .await?
+                    .and_then(|m| m.show_specifics)
+                    .and_then(|s| get_show_episode_numbers_in_ordering(&s, ordering))
+                    .map(|numbers| {
+                        numbers
+                            .into_iter()
+                            .map(|(aired, ordered)| (ordered, aired))
+                            .collect::<HashMap<_, _>>()
+                    });
+                episode_numbers.insert(item.metadata_id.clone(), numbers);
+            }
+            if let Some((season, episode)) = episode_numbers[&item.metadata_id]
+                .as_ref()
+                .and_then(|n| n.get(&(season, episode)))
+            {
+                item.show_season_number = Some(*season);
+                item.show_episode_number = Some(*episode);
+            }
+        }
         self.0
             .perform_application_job(ApplicationJob::Hp(HpApplicationJob::BulkProgressUpdate(
                 user_id, input,
@@ -1269,6 +1711,172 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(true)
     }
 
+    pub async fn switch_metadata_source(
+        &self,
+        user_id: &String,
+        input: SwitchMetadataSourceInput,
+    ) -> Result<bool> {
+        admin_account_guard(user_id, &self.0).await?;
+        let SwitchMetadataSourceInput {
+            metadata_id,
+            source,
+            identifier,
+        } = input;
+        let Some(metadata) = Metadata::find_by_id(&metadata_id).one(&self.0.db).await? else {
+            return Err(Error::new("Metadata with the given ID does not exist"));
+        };
+        if metadata.source == MediaSource::Custom
+            || !METADATA_LOT_MAPPINGS
+                .iter()
+                .any(|(l, s)| *l == metadata.lot && s.contains(&source))
+        {
+            return Err(Error::new("This source can not be used for this media"));
+        }
+        let already_exists = Metadata::find()
+            .filter(metadata::Column::Lot.eq(metadata.lot))
+            .filter(metadata::Column::Source.eq(source))
+            .filter(metadata::Column::Identifier.eq(&identifier))
+            .count(&self.0.db)
+            .await?
+            > 0;
+        if already_exists {
+            return Err(Error::new(
+                "This media already exists with the given source, merge it instead",
+            ));
+        }
+        let details = details_from_provider(metadata.lot, source, &identifier, &self.0)
+            .await
+            .map_err(|e| {
+                Error::new(format!(
+                    "Could not find the media with the given identifier on this source: {}",
+                    e.message
+                ))
+            })?;
+        self.remap_episodes_to_new_source(&metadata, &details)
+            .await?;
+        let mut external_identifiers = metadata.external_identifiers.clone().unwrap_or_default();
+        external_identifiers
+            .linked_sources
+            .retain(|l| l.source != source && l.source != metadata.source);
+        external_identifiers
+            .linked_sources
+            .push(MetadataLinkedSource {
+                source: metadata.source,
+                identifier: metadata.identifier.clone(),
+            });
+        let mut meta: metadata::ActiveModel = metadata.into();
+        meta.source = ActiveValue::Set(source);
+        meta.identifier = ActiveValue::Set(identifier);
+        meta.is_partial = ActiveValue::Set(Some(true));
+        meta.external_identifiers = ActiveValue::Set(Some(external_identifiers));
+        meta.update(&self.0.db).await?;
+        deploy_update_metadata_job(&metadata_id, &self.0).await?;
+        Ok(true)
+    }
+
+    /// Seasons and episodes are numbered differently across providers, so the episodes
+    /// in the history and reviews are matched by name or release date to the episodes
+    /// of the new source. Episodes that can not be matched are left unchanged.
+    async fn remap_episodes_to_new_source(
+        &self,
+        metadata: &metadata::Model,
+        details: &MetadataDetails,
+    ) -> Result<()> {
+        let show_specifics = metadata
+            .show_specifics
+            .as_ref()
+            .zip(details.show_specifics.as_ref());
+        let podcast_specifics = metadata
+            .podcast_specifics
+            .as_ref()
+            .zip(details.podcast_specifics.as_ref());
+        if show_specifics.is_none() && podcast_specifics.is_none() {
+            return Ok(());
+        }
+        let mut unmatched = 0;
+        let seen_items = Seen::find()
+            .filter(seen::Column::MetadataId.eq(&metadata.id))
+            .all(&self.0.db)
+            .await?;
+        for seen in seen_items {
+            let mut show_extra_information = seen.show_extra_information.clone();
+            let mut podcast_extra_information = seen.podcast_extra_information.clone();
+            if let (Some(info), Some((old, new))) = (&mut show_extra_information, show_specifics) {
+                match find_matching_show_episode(old, new, info.season, info.episode) {
+                    Some((season, episode)) => {
+                        info.season = season;
+                        info.episode = episode;
+                    }
+                    None => unmatched += 1,
+                }
+            }
+            if let (Some(info), Some((old, new))) =
+                (&mut podcast_extra_information, podcast_specifics)
+            {
+                match find_matching_podcast_episode(old, new, info.episode) {
+                    Some(episode) => info.episode = episode,
+                    None => unmatched += 1,
+                }
+            }
+            if show_extra_information == seen.show_extra_information
+                && podcast_extra_information == seen.podcast_extra_information
+            {
+                continue;
+            }
+            let mut seen: seen::ActiveModel = seen.into();
+            seen.show_extra_information = ActiveValue::Set(show_extra_information);
+            seen.podcast_extra_information = ActiveValue::Set(podcast_extra_information);
+            seen.update(&self.0.db).await?;
+        }
+        let reviews = Review::find()
+            .filter(review::Column::MetadataId.eq(&metadata.id))
+            .all(&self.0.db)
+            .await?;
+        for review in reviews {
+            let mut show_extra_information = review.show_extra_information.clone();
+            let mut podcast_extra_information = review.podcast_extra_information.clone();
+            if let (Some(info), Some((old, new))) = (&mut show_extra_information, show_specifics) {
+                if let (Some(season), Some(episode)) = (info.season, info.episode) {
+                    match find_matching_show_episode(old, new, season, episode) {
+                        Some((season, episode)) => {
+                            info.season = Some(season);
+                            info.episode = Some(episode);
+                        }
+                        None => unmatched += 1,
+                    }
+                }
+            }
+            if let (Some(info), Some((old, new))) =
+                (&mut podcast_extra_information, podcast_specifics)
+            {
+                if let Some(episode) = info.episode {
+                    match find_matching_podcast_episode(old, new, episode) {
+                        Some(episode) => info.episode = Some(episode),
+                        None => unmatched += 1,
+                    }
+                }
+            }
+            if show_extra_information == review.show_extra_information
+                && podcast_extra_information == review.podcast_extra_information
+            {
+                continue;
+            }
+            let mut review: review::ActiveModel = review.into();
+            review.show_extra_information = ActiveValue::Set(show_extra_information);
+            review.podcast_extra_information = ActiveValue::Set(podcast_extra_information);
+            review.update(&self.0.db).await?;
+        }
+        if unmatched > 0 {
+            ryot_log!(
+                warn,
+                "Could not match {} episodes of {} to the new source",
+                unmatched,
+                metadata.id
+            );
+        }
+        Ok(())
+    }
+
     pub async fn commit_metadata(&self, input: CommitMediaInput) -> Result<StringIdObject> {
         commit_metadata(input, &self.0).await
     }
@@ -1339,9 +1947,34 @@ ORDER BY RANDOM() LIMIT 10;
         }
         let preferences = user_by_id(user_id, &self.0).await?.preferences;
         let provider = get_metadata_provider(input.lot, input.source, &self.0).await?;
-        let results = provider
+        let mut results = provider
             .metadata_search(&query, input.search.page, preferences.general.display_nsfw)
             .await?;
+        if let Some(language) = preferences.general.language {
+            // Only the items that were already fetched can have localized titles
+            let localized_titles: HashMap<String, String> = Metadata::find()
+                .filter(metadata::Column::Lot.eq(input.lot))
+                .filter(metadata::Column::Source.eq(input.source))
+                .filter(metadata::Column::LocalizedDetails.is_not_null())
+                .filter(
+                    metadata::Column::Identifier
+                        .is_in(results.items.iter().map(|i| i.identifier.clone())),
+                )
+                .all(&self.0.db)
+                .await?
+                .into_iter()
+                .filter_map(|m| {
+                    get_localized_details(m.localized_details, Some(&language))
+                        .and_then(|l| l.title)
+                        .map(|title| (m.identifier, title))
+                })
+                .collect();
+            for item in results.items.iter_mut() {
+                if let Some(title) = localized_titles.get(&item.identifier) {
+                    item.title = title.clone();
+                }
+            }
+        }
         cc.set_key(
             cache_key,
             ApplicationCacheValue::MetadataSearch(results.clone()),
@@ -1419,7 +2052,14 @@ ORDER BY RANDOM() LIMIT 10;
         let err = || Err(Error::new("This source is not supported".to_owned()));
         let service: Provider = match source {
             MediaSource::YoutubeMusic => Box::new(YoutubeMusicService::new().await),
+            MediaSource::MusicBrainz => Box::new(MusicBrainzService::new().await),
             MediaSource::Hardcover => Box::new(get_hardcover_service(&self.0.config).await?),
+            MediaSource::ComicVine => {
+                Box::new(ComicVineService::new(&self.0.config.comics.comic_vine).await)
+            }
+            MediaSource::BoardGameGeek => Box::new(
+                BoardGameGeekService::new(&self.0.config.board_games.board_game_geek).await,
+            ),
             MediaSource::Vndb => Box::new(VndbService::new(&self.0.config.visual_novels).await),
             MediaSource::Openlibrary => Box::new(get_openlibrary_service(&self.0.config).await?),
             MediaSource::Itunes => {
@@ -1430,15 +2070,24 @@ ORDER BY RANDOM() LIMIT 10;
                 Box::new(AudibleService::new(&self.0.config.audio_books.audible).await)
             }
             MediaSource::Listennotes => Box::new(ListennotesService::new(self.0.clone()).await),
+            MediaSource::PodcastIndex => {
+                Box::new(PodcastIndexService::new(&self.0.config.podcasts.podcast_index).await)
+            }
+            MediaSource::Rss => Box::new(RssService::new().await),
+            MediaSource::Rawg => Box::new(RawgService::new(&self.0.config.video_games.rawg).await),
             MediaSource::Igdb => Box::new(IgdbService::new(self.0.clone()).await),
             MediaSource::MangaUpdates => Box::new(
                 MangaUpdatesService::new(&self.0.config.anime_and_manga.manga_updates).await,
             ),
             MediaSource::Tmdb => Box::new(get_tmdb_non_media_service(&self.0).await?),
+            MediaSource::Tvdb => Box::new(NonMediaTvdbService::new(self.0.clone()).await?),
             MediaSource::Anilist => {
                 Box::new(NonMediaAnilistService::new(&self.0.config.anime_and_manga.anilist).await)
             }
             MediaSource::Mal => Box::new(NonMediaMalService::new().await),
+            MediaSource::Remote => {
+                Box::new(RemoteService::new_non_media(&self.0.config.media).await)
+            }
             MediaSource::Custom => return err(),
         };
         Ok(service)
@@ -1551,7 +2200,10 @@ ORDER BY RANDOM() LIMIT 10;
             .unwrap_or_default()
             .into_iter()
             .map(|i| MetadataImage {
-                url: StoredUrl::S3(i),
+                url: match i.starts_with("http://") || i.starts_with("https://") {
+                    true => StoredUrl::Url(i),
+                    false => StoredUrl::S3(i),
+                },
             })
             .collect_vec();
         let videos = input
@@ -1579,6 +2231,8 @@ ORDER BY RANDOM() LIMIT 10;
             MediaLot::Music => input.music_specifics.is_none(),
             MediaLot::Anime => input.anime_specifics.is_none(),
             MediaLot::Manga => input.manga_specifics.is_none(),
+            MediaLot::Comic => input.comic_specifics.is_none(),
+            MediaLot::BoardGame => input.board_game_specifics.is_none(),
             MediaLot::Movie => input.movie_specifics.is_none(),
             MediaLot::Podcast => input.podcast_specifics.is_none(),
             MediaLot::AudioBook => input.audio_book_specifics.is_none(),
@@ -1597,6 +2251,8 @@ ORDER BY RANDOM() LIMIT 10;
             show_specifics: ActiveValue::Set(input.show_specifics),
             book_specifics: ActiveValue::Set(input.book_specifics),
             manga_specifics: ActiveValue::Set(input.manga_specifics),
+            comic_specifics: ActiveValue::Set(input.comic_specifics),
+            board_game_specifics: ActiveValue::Set(input.board_game_specifics),
             anime_specifics: ActiveValue::Set(input.anime_specifics),
             movie_specifics: ActiveValue::Set(input.movie_specifics),
             music_specifics: ActiveValue::Set(input.music_specifics),
@@ -1621,6 +2277,17 @@ ORDER BY RANDOM() LIMIT 10;
         }
     }
 
+    pub async fn scrape_custom_metadata(
+        &self,
+        input: ScrapeCustomMetadataInput,
+    ) -> Result<CreateCustomMetadataInput> {
+        let service = WebPageService::new().await;
+        let metadata = service
+            .scrape_custom_metadata(&input.url, input.lot)
+            .await?;
+        Ok(metadata)
+    }
+
     pub async fn create_custom_metadata(
         &self,
         user_id: String,
@@ -1730,6 +2397,7 @@ ORDER BY RANDOM() LIMIT 10;
             "Users to be notified for metadata state changes: {:?}",
             m_map
         );
+        let languages = get_users_preferred_languages(&self.0).await;
         let chunks = m_map.keys().chunks(ENTITY_UPDATE_CHUNK_SIZE);
         let items = chunks
             .into_iter()
@@ -1738,7 +2406,7 @@ ORDER BY RANDOM() LIMIT 10;
         for chunk in items {
             let promises = chunk
                 .into_iter()
-                .map(|m| self.update_metadata_and_notify_users(m));
+                .map(|m| update_metadata_and_notify_users(m, &languages, &self.0));
             join_all(promises).await;
         }
         Ok(())
@@ -1962,12 +2630,6 @@ ORDER BY RANDOM() LIMIT 10;
     }
 
     async fn queue_pending_reminders(&self) -> Result<()> {
-        #[derive(Debug, Serialize, Deserialize)]
-        #[serde(rename_all = "PascalCase")]
-        struct UserMediaReminder {
-            reminder: NaiveDate,
-            text: String,
-        }
         for (cte, col) in CollectionToEntity::find()
             .find_also_related(Collection)
             .filter(collection::Column::Name.eq(DefaultCollection::Reminders.to_string()))
@@ -1980,12 +2642,21 @@ ORDER BY RANDOM() LIMIT 10;
                 let col = col.unwrap();
                 let related_users = col.find_related(UserToEntity).all(&self.0.db).await?;
                 if get_current_date(&self.0.timezone) == reminder.reminder {
+                    let payload =
+                        get_notification_payload(&cte.entity_id, cte.entity_lot, None, &self.0)
+                            .await
+                            .ok();
+                    let notification = (
+                        reminder.text.clone(),
+                        UserNotificationContent::EntityReminderDue,
+                    );
                     for user in related_users {
-                        create_user_notification(
-                            &reminder.text,
+                        create_notification_for_user(
                             &user.user_id,
-                            &self.0.db,
+                            &notification,
+                            payload.as_ref(),
                             UserNotificationLot::Queued,
+                            &self.0,
                         )
                         .await?;
                         remove_entity_from_collection(
@@ -2054,8 +2725,84 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(true)
     }
 
+    /// Media associated with monitored people and groups are usually not tracked by
+    /// anyone, so they are not kept up to date like the rest. Refresh the ones that are
+    /// yet to be released so that their calendar events can be calculated.
+    async fn refresh_upcoming_metadata_of_monitored_entities(
+        &self,
+        updated_before: NaiveDate,
+    ) -> Result<()> {
+        let today = get_current_date(&self.0.timezone);
+        let monitored = |lot: EntityLot| {
+            MonitoredEntity::find()
+                .select_only()
+                .column(monitored_entity::Column::EntityId)
+                .filter(monitored_entity::Column::EntityLot.eq(lot))
+                .into_query()
+        };
+        let metadata_ids = Metadata::find()
+            .select_only()
+            .column(metadata::Column::Id)
+            .filter(
+                Condition::any()
+                    .add(
+                        metadata::Column::Id.in_subquery(
+                            MetadataToPerson::find()
+                                .select_only()
+                                .column(metadata_to_person::Column::MetadataId)
+                                .filter(
+                                    metadata_to_person::Column::PersonId
+                                        .in_subquery(monitored(EntityLot::Person)),
+                                )
+                                .into_query(),
+                        ),
+                    )
+                    .add(
+                        metadata::Column::Id.in_subquery(
+                            MetadataToMetadataGroup::find()
+                                .select_only()
+                                .column(metadata_to_metadata_group::Column::MetadataId)
+                                .filter(
+                                    metadata_to_metadata_group::Column::MetadataGroupId
+                                        .in_subquery(monitored(EntityLot::MetadataGroup)),
+                                )
+                                .into_query(),
+                        ),
+                    ),
+            )
+            .filter(
+                Condition::any()
+                    .add(metadata::Column::IsPartial.eq(true))
+                    .add(metadata::Column::PublishDate.gte(today))
+                    .add(
+                        metadata::Column::PublishDate.is_null().and(
+                            metadata::Column::PublishYear
+                                .is_null()
+                                .or(metadata::Column::PublishYear.gte(today.year())),
+                        ),
+                    ),
+            )
+            .filter(metadata::Column::LastUpdatedOn.lt(updated_before))
+            .into_tuple::<String>()
+            .all(&self.0.db)
+            .await?;
+        ryot_log!(
+            debug,
+            "Refreshing {} upcoming media of monitored entities",
+            metadata_ids.len()
+        );
+        for metadata_id in metadata_ids {
+            deploy_update_metadata_job(&metadata_id, &self.0)
+                .await
+                .trace_ok();
+        }
+        Ok(())
+    }
+
     pub async fn recalculate_calendar_events(&self) -> Result<()> {
         let date_to_calculate_from = get_current_date(&self.0.timezone).pred_opt().unwrap();
+        self.refresh_upcoming_metadata_of_monitored_entities(date_to_calculate_from)
+            .await?;
 
         let selected_metadata = Metadata::find()
             .filter(metadata::Column::LastUpdatedOn.gte(date_to_calculate_from))
@@ -2206,23 +2953,23 @@ ORDER BY RANDOM() LIMIT 10;
             .into_iter()
             .map(|(cal_event, meta)| {
                 let meta = meta.unwrap();
-                let url = self.get_entity_details_frontend_url(
-                    meta.id.to_string(),
-                    EntityLot::Metadata,
-                    None,
-                );
                 let notification = if let Some(show) = cal_event.metadata_show_extra_information {
                     format!(
-                        "S{}E{} of {} ({}) has been released today.",
-                        show.season, show.episode, meta.title, url
+                        "S{}E{} of {} has been released today.",
+                        show.season, show.episode, meta.title
                     )
                 } else if let Some(podcast) = cal_event.metadata_podcast_extra_information {
                     format!(
-                        "E{} of {} ({}) has been released today.",
-                        podcast.episode, meta.title, url
+                        "E{} of {} has been released today.",
+                        podcast.episode, meta.title
                     )
+                } else if let Some((issue, volume)) = meta
+                    .comic_specifics
+                    .and_then(|c| c.issue_number.zip(c.volume))
+                {
+                    format!("Issue #{} of {} has been released today.", issue, volume)
                 } else {
-                    format!("{} ({}) has been released today.", meta.title, url)
+                    format!("{} has been released today.", meta.title)
                 };
                 (
                     meta.id.to_string(),
@@ -2233,10 +2980,13 @@ ORDER BY RANDOM() LIMIT 10;
         for (metadata_id, notification) in notifications.into_iter() {
             let users_to_notify =
                 get_users_monitoring_entity(&metadata_id, EntityLot::Metadata, &self.0.db).await?;
+            let payload =
+                get_notification_payload(&metadata_id, EntityLot::Metadata, None, &self.0).await?;
             for user in users_to_notify {
                 create_notification_for_user(
                     &user,
                     &notification,
+                    Some(&payload),
                     UserNotificationLot::Queued,
                     &self.0,
                 )
@@ -2403,7 +3153,8 @@ ORDER BY RANDOM() LIMIT 10;
     }
 
     pub async fn update_metadata_and_notify_users(&self, metadata_id: &String) -> Result<()> {
-        update_metadata_and_notify_users(metadata_id, &self.0).await
+        let languages = get_users_preferred_languages(&self.0).await;
+        update_metadata_and_notify_users(metadata_id, &languages, &self.0).await
     }
 
     pub async fn update_person_and_notify_users(&self, person_id: &String) -> Result<()> {
@@ -2412,11 +3163,15 @@ ORDER BY RANDOM() LIMIT 10;
             let users_to_notify =
                 get_users_and_cte_monitoring_entity(person_id, EntityLot::Person, &self.0.db)
                     .await?;
+            let payload = get_notification_payload(person_id, EntityLot::Person, None, &self.0)
+                .await
+                .ok();
             for notification in notifications {
                 for (user_id, cte_id) in users_to_notify.iter() {
                     create_notification_for_user(
                         user_id,
                         &notification,
+                        payload.as_ref(),
                         UserNotificationLot::Queued,
                         &self.0,
                     )
@@ -2482,17 +3237,29 @@ ORDER BY RANDOM() LIMIT 10;
             .into_tuple::<String>()
             .all(&self.0.db)
             .await?;
+        let url = get_entity_details_frontend_url(
+            event.obj_id.clone(),
+            event.entity_lot,
+            Some("reviews"),
+            &self.0,
+        );
+        let payload = get_notification_payload(
+            &event.obj_id,
+            event.entity_lot,
+            Some(UserNotificationContent::ReviewPosted),
+            &self.0,
+        )
+        .await?;
         for user_id in users {
-            let url = self.get_entity_details_frontend_url(
-                event.obj_id.clone(),
-                event.entity_lot,
-                Some("reviews"),
-            );
             create_user_notification(
                 &format!(
-                    "New review posted for {} ({}, {}) by {}.",
-                    event.obj_title, event.entity_lot, url, event.username
+                    "New review posted for {} ({}) by {}.",
+                    event.obj_title, event.entity_lot, event.username
                 ),
+                Some(UserNotificationPayload {
+                    action_url: Some(url.clone()),
+                    ..payload.clone()
+                }),
                 &user_id,
                 &self.0.db,
                 UserNotificationLot::Queued,
@@ -2502,29 +3269,6 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(())
     }
 
-    fn get_entity_details_frontend_url(
-        &self,
-        id: String,
-        entity_lot: EntityLot,
-        default_tab: Option<&str>,
-    ) -> String {
-        let mut url = match entity_lot {
-            EntityLot::Metadata => format!("media/item/{}", id),
-            EntityLot::Collection => format!("collections/{}", id),
-            EntityLot::Person => format!("media/people/item/{}", id),
-            EntityLot::Workout => format!("fitness/workouts/{}", id),
-            EntityLot::Exercise => format!("fitness/exercises/{}", id),
-            EntityLot::MetadataGroup => format!("media/groups/item/{}", id),
-            EntityLot::WorkoutTemplate => format!("fitness/templates/{}", id),
-            EntityLot::Review | EntityLot::UserMeasurement => unreachable!(),
-        };
-        url = format!("{}/{}", self.0.config.frontend.url, url);
-        if let Some(tab) = default_tab {
-            url += format!("?defaultTab={}", tab).as_str()
-        }
-        url
-    }
-
     async fn invalidate_import_jobs(&self) -> Result<()> {
         let all_jobs = ImportReport::find()
             .filter(
@@ -2634,6 +3378,30 @@ ORDER BY RANDOM() LIMIT 10;
         ryot_log!(debug, "Deleting all addressed user notifications");
         UserNotification::delete_many()
             .filter(user_notification::Column::IsAddressed.eq(true))
+            .filter(
+                user_notification::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(notification_delivery::Column::UserNotificationId)
+                        .from(NotificationDelivery)
+                        .and_where(
+                            notification_delivery::Column::Status
+                                .eq(NotificationDeliveryStatus::Failed)
+                                .and(
+                                    notification_delivery::Column::UserNotificationId.is_not_null(),
+                                ),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .exec(&self.0.db)
+            .await?;
+        ryot_log!(debug, "Deleting old notification deliveries");
+        NotificationDelivery::delete_many()
+            .filter(notification_delivery::Column::Status.ne(NotificationDeliveryStatus::Failed))
+            .filter(
+                notification_delivery::Column::LastAttemptedOn
+                    .lt(Utc::now() - ChronoDuration::days(30)),
+            )
             .exec(&self.0.db)
             .await?;
         ryot_log!(debug, "Deleting revoked access tokens");
@@ -2646,6 +3414,8 @@ ORDER BY RANDOM() LIMIT 10;
             .filter(application_cache::Column::ExpiresAt.lt(Utc::now()))
             .exec(&self.0.db)
             .await?;
+        let removed = remove_expired_provider_responses().await;
+        ryot_log!(debug, "Deleted {} expired provider responses", removed);
         Ok(())
     }
 
@@ -2726,9 +3496,219 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(notifications)
     }
 
+    /// Send the notifications to all enabled platforms of the user. Each platform only
+    /// receives the notifications that are routed to it, which are combined into a single
+    /// message using `build_message`.
+    async fn send_notifications_to_user_platforms<F>(
+        &self,
+        user_id: &String,
+        notifications: &[user_notification::Model],
+        build_message: F,
+    ) -> Result<()>
+    where
+        F: Fn(Vec<user_notification::Model>) -> NotificationMessage,
+    {
+        let platforms = NotificationPlatform::find()
+            .filter(notification_platform::Column::UserId.eq(user_id))
+            .all(&self.0.db)
+            .await?;
+        for platform in platforms {
+            if platform.is_disabled.unwrap_or_default() {
+                ryot_log!(
+                    debug,
+                    "Skipping sending notification to user: {} for platform: {} since it is disabled",
+                    user_id,
+                    platform.lot
+                );
+                continue;
+            }
+            let routed = notifications
+                .iter()
+                .filter(|n| is_notification_routed_to_platform(n, &platform))
+                .cloned()
+                .collect_vec();
+            if routed.is_empty() {
+                continue;
+            }
+            let msg = build_message(routed.clone());
+            let result = self
+                .deliver_notification_to_platform(&platform, &msg)
+                .await?;
+            let (status, error, next_attempt_on) = get_notification_delivery_outcome(&result, 1);
+            let rendered_message = serde_json::to_value(&msg).ok();
+            let deliveries = routed
+                .into_iter()
+                .map(|n| notification_delivery::ActiveModel {
+                    attempts: ActiveValue::Set(1),
+                    message: ActiveValue::Set(n.message),
+                    status: ActiveValue::Set(status),
+                    error: ActiveValue::Set(error.clone()),
+                    last_attempted_on: ActiveValue::Set(Utc::now()),
+                    next_attempt_on: ActiveValue::Set(next_attempt_on),
+                    rendered_message: ActiveValue::Set(rendered_message.clone()),
+                    user_notification_id: ActiveValue::Set(Some(n.id)),
+                    notification_platform_id: ActiveValue::Set(platform.id.clone()),
+                    ..Default::default()
+                });
+            NotificationDelivery::insert_many(deliveries)
+                .exec_without_returning(&self.0.db)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Send a message to the platform and keep track of how many deliveries to it have
+    /// failed in a row. The platform is disabled once there are too many of them.
+    async fn deliver_notification_to_platform(
+        &self,
+        platform: &notification_platform::Model,
+        msg: &NotificationMessage,
+    ) -> Result<anyhow::Result<()>> {
+        let result =
+            send_notification(platform.platform_specifics.clone(), &self.0.config, msg).await;
+        let previous_failures = platform.consecutive_failures.unwrap_or_default();
+        let consecutive_failures = match &result {
+            Ok(_) => 0,
+            Err(err) => {
+                ryot_log!(debug, "Error sending notification: {:?}", err);
+                previous_failures + 1
+            }
+        };
+        if consecutive_failures != previous_failures {
+            let mut to_update: notification_platform::ActiveModel = platform.clone().into();
+            to_update.consecutive_failures = ActiveValue::Set(Some(consecutive_failures));
+            if consecutive_failures >= MAX_NOTIFICATION_PLATFORM_CONSECUTIVE_FAILURES {
+                ryot_log!(
+                    warn,
+                    "Disabling notification platform {} after {} consecutive failures",
+                    platform.id,
+                    consecutive_failures
+                );
+                to_update.is_disabled = ActiveValue::Set(Some(true));
+            }
+            to_update.update(&self.0.db).await?;
+        }
+        Ok(result)
+    }
+
+    /// Give up on deliveries that can not be retried so that they are not picked up again.
+    async fn abandon_notification_deliveries(
+        &self,
+        deliveries: Vec<notification_delivery::Model>,
+        error: &str,
+    ) -> Result<()> {
+        NotificationDelivery::update_many()
+            .filter(notification_delivery::Column::Id.is_in(deliveries.into_iter().map(|d| d.id)))
+            .col_expr(
+                notification_delivery::Column::Status,
+                Expr::value(NotificationDeliveryStatus::Abandoned),
+            )
+            .col_expr(notification_delivery::Column::Error, Expr::value(error))
+            .col_expr(
+                notification_delivery::Column::NextAttemptOn,
+                Expr::value(Option::<DateTimeUtc>::None),
+            )
+            .exec(&self.0.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Retry the failed deliveries that are due. The deliveries that were sent together
+    /// share the same rendered message, which is sent again as is so that a digest keeps
+    /// its format. Deliveries to platforms that no longer exist or are disabled are
+    /// abandoned.
+    pub async fn retry_failed_notification_deliveries(&self) -> Result<()> {
+        let due = NotificationDelivery::find()
+            .filter(notification_delivery::Column::Status.eq(NotificationDeliveryStatus::Failed))
+            .filter(notification_delivery::Column::NextAttemptOn.lte(Utc::now()))
+            .all(&self.0.db)
+            .await?;
+        let by_platform = due
+            .into_iter()
+            .into_group_map_by(|d| d.notification_platform_id.clone());
+        for (platform_id, deliveries) in by_platform {
+            let by_message = deliveries
+                .into_iter()
+                .into_group_map_by(|d| d.rendered_message.as_ref().map(|m| m.to_string()));
+            for (rendered_message, deliveries) in by_message {
+                // DEV: The platform is fetched again for every message since its failure
+                // count changes with every delivery.
+                let platform = NotificationPlatform::find_by_id(&platform_id)
+                    .one(&self.0.db)
+                    .await?;
+                let Some(platform) = platform.filter(|p| !p.is_disabled.unwrap_or_default()) else {
+                    self.abandon_notification_deliveries(
+                        deliveries,
+                        "The notification platform is disabled",
+                    )
+                    .await?;
+                    continue;
+                };
+                let msg = match rendered_message.and_then(|m| serde_json::from_str(&m).ok()) {
+                    Some(msg) => msg,
+                    None => {
+                        let notifications =
+                            UserNotification::find()
+                                .filter(user_notification::Column::Id.is_in(
+                                    deliveries.iter().filter_map(|d| d.user_notification_id),
+                                ))
+                                .all(&self.0.db)
+                                .await?;
+                        if notifications.is_empty() {
+                            self.abandon_notification_deliveries(
+                                deliveries,
+                                "The notifications no longer exist",
+                            )
+                            .await?;
+                            continue;
+                        }
+                        get_queued_notifications_message(notifications)
+                    }
+                };
+                ryot_log!(
+                    debug,
+                    "Retrying {} notification deliveries to platform {}",
+                    deliveries.len(),
+                    platform_id
+                );
+                let result = self
+                    .deliver_notification_to_platform(&platform, &msg)
+                    .await?;
+                for delivery in deliveries {
+                    let attempts = delivery.attempts + 1;
+                    let (status, error, next_attempt_on) =
+                        get_notification_delivery_outcome(&result, attempts);
+                    let mut delivery: notification_delivery::ActiveModel = delivery.into();
+                    delivery.attempts = ActiveValue::Set(attempts);
+                    delivery.status = ActiveValue::Set(status);
+                    delivery.error = ActiveValue::Set(error);
+                    delivery.last_attempted_on = ActiveValue::Set(Utc::now());
+                    delivery.next_attempt_on = ActiveValue::Set(next_attempt_on);
+                    delivery.update(&self.0.db).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn mark_notifications_as_addressed(
+        &self,
+        notifications: &[user_notification::Model],
+    ) -> Result<()> {
+        UserNotification::update_many()
+            .filter(user_notification::Column::Id.is_in(notifications.iter().map(|n| n.id)))
+            .col_expr(user_notification::Column::IsAddressed, Expr::value(true))
+            .exec(&self.0.db)
+            .await?;
+        Ok(())
+    }
+
     async fn send_pending_queued_notifications(&self) -> Result<()> {
         let users = User::find().all(&self.0.db).await?;
         for user_details in users {
+            if user_details.preferences.notifications.digest != UserNotificationDigest::Immediate {
+                continue;
+            }
             ryot_log!(debug, "Sending notification to user: {:?}", user_details.id);
             let notifications = self
                 .get_pending_notifications_for_user(&user_details.id, UserNotificationLot::Queued)
@@ -2736,37 +3716,62 @@ ORDER BY RANDOM() LIMIT 10;
             if notifications.is_empty() {
                 continue;
             }
-            let notification_ids = notifications.iter().map(|n| n.id).collect_vec();
-            let msg = notifications
-                .into_iter()
-                .map(|n| n.message)
-                .collect::<Vec<String>>()
-                .join("\n");
-            let platforms = NotificationPlatform::find()
-                .filter(notification_platform::Column::UserId.eq(&user_details.id))
-                .all(&self.0.db)
-                .await?;
-            for notification in platforms {
-                if notification.is_disabled.unwrap_or_default() {
-                    ryot_log!(
-                        debug,
-                        "Skipping sending notification to user: {} for platform: {} since it is disabled",
-                        user_details.id,
-                        notification.lot
-                    );
-                    continue;
-                }
-                if let Err(err) =
-                    send_notification(notification.platform_specifics, &self.0.config, &msg).await
-                {
-                    ryot_log!(trace, "Error sending notification: {:?}", err);
+            self.send_notifications_to_user_platforms(
+                &user_details.id,
+                &notifications,
+                get_queued_notifications_message,
+            )
+            .await?;
+            self.mark_notifications_as_addressed(&notifications).await?;
+        }
+        Ok(())
+    }
+
+    /// Send the queued notifications of users who have opted for a daily or weekly digest,
+    /// once their chosen hour has passed and a digest has not been sent for the day yet.
+    pub async fn send_pending_notification_digests(&self) -> Result<()> {
+        let now = Utc::now();
+        let local_now = now.with_timezone(&self.0.timezone);
+        let users = User::find().all(&self.0.db).await?;
+        for user_details in users {
+            let preferences = &user_details.preferences.notifications;
+            let is_due = match preferences.digest {
+                UserNotificationDigest::Immediate => false,
+                UserNotificationDigest::Daily => local_now.hour() >= preferences.digest_hour,
+                UserNotificationDigest::Weekly => {
+                    local_now.hour() >= preferences.digest_hour
+                        && local_now.weekday().number_from_monday() == preferences.digest_weekday
                 }
+            };
+            let mut extra_information = user_details.extra_information.clone().unwrap_or_default();
+            let already_sent_today = extra_information
+                .last_notification_digest_sent_on
+                .map(|l| l.with_timezone(&self.0.timezone).date_naive() == local_now.date_naive())
+                .unwrap_or_default();
+            if !is_due || already_sent_today {
+                continue;
             }
-            UserNotification::update_many()
-                .filter(user_notification::Column::Id.is_in(notification_ids))
-                .col_expr(user_notification::Column::IsAddressed, Expr::value(true))
-                .exec(&self.0.db)
+            let notifications = self
+                .get_pending_notifications_for_user(&user_details.id, UserNotificationLot::Queued)
                 .await?;
+            if notifications.is_empty() {
+                continue;
+            }
+            ryot_log!(
+                debug,
+                "Sending notification digest to user: {:?}",
+                user_details.id
+            );
+            let digest = preferences.digest;
+            self.send_notifications_to_user_platforms(&user_details.id, &notifications, |n| {
+                get_notification_digest_message(digest, n)
+            })
+            .await?;
+            self.mark_notifications_as_addressed(&notifications).await?;
+            extra_information.last_notification_digest_sent_on = Some(now);
+            let mut user: user::ActiveModel = user_details.into();
+            user.extra_information = ActiveValue::Set(Some(extra_information));
+            user.update(&self.0.db).await?;
         }
         Ok(())
     }
@@ -2781,38 +3786,15 @@ ORDER BY RANDOM() LIMIT 10;
                     UserNotificationLot::Immediate,
                 )
                 .await?;
-            let notification_ids = notifications.iter().map(|n| n.id).collect_vec();
-            let platforms = NotificationPlatform::find()
-                .filter(notification_platform::Column::UserId.eq(&user_details.id))
-                .all(&self.0.db)
+            for notification in notifications.iter() {
+                self.send_notifications_to_user_platforms(
+                    &user_details.id,
+                    std::slice::from_ref(notification),
+                    get_queued_notifications_message,
+                )
                 .await?;
-            for notification in notifications {
-                for platform in platforms.iter() {
-                    if platform.is_disabled.unwrap_or_default() {
-                        ryot_log!(
-                        debug,
-                        "Skipping sending notification to user: {} for platform: {} since it is disabled",
-                        user_details.id,
-                        platform.lot
-                    );
-                        continue;
-                    }
-                    if let Err(err) = send_notification(
-                        platform.platform_specifics.to_owned(),
-                        &self.0.config,
-                        &notification.message,
-                    )
-                    .await
-                    {
-                        ryot_log!(trace, "Error sending notification: {:?}", err);
-                    }
-                }
             }
-            UserNotification::update_many()
-                .filter(user_notification::Column::Id.is_in(notification_ids))
-                .col_expr(user_notification::Column::IsAddressed, Expr::value(true))
-                .exec(&self.0.db)
-                .await?;
+            self.mark_notifications_as_addressed(&notifications).await?;
         }
         Ok(())
     }
@@ -2885,3 +3867,273 @@ ORDER BY RANDOM() LIMIT 10;
         Ok(true)
     }
 }
+
+/// Failures that are likely to go away are retried with an exponential back-off starting
+/// at five minutes, until the maximum number of attempts is reached.
+fn get_notification_delivery_outcome(
+    result: &anyhow::Result<()>,
+    attempts: i32,
+) -> (
+    NotificationDeliveryStatus,
+    Option<String>,
+    Option<DateTimeUtc>,
+) {
+    match result {
+        Ok(_) => (NotificationDeliveryStatus::Delivered, None, None),
+        Err(err)
+            if is_transient_notification_error(err)
+                && attempts < MAX_NOTIFICATION_DELIVERY_ATTEMPTS =>
+        {
+            let delay = ChronoDuration::minutes(5 * 2_i64.pow(attempts as u32 - 1));
+            (
+                NotificationDeliveryStatus::Failed,
+                Some(err.to_string()),
+                Some(Utc::now() + delay),
+            )
+        }
+        Err(err) => (
+            NotificationDeliveryStatus::Abandoned,
+            Some(err.to_string()),
+            None,
+        ),
+    }
+}
+
+/// A notification is routed to a platform unless the platform restricts the types of
+/// notifications or the lots of media it receives and the notification does not match.
+/// Notifications that are not about media are not affected by the media lot filter.
+fn is_notification_routed_to_platform(
+    notification: &user_notification::Model,
+    platform: &notification_platform::Model,
+) -> bool {
+    let payload = notification.payload.as_ref();
+    let content_matches = match (&platform.content_types, payload.and_then(|p| p.content)) {
+        (Some(types), Some(content)) => types.contains(&content),
+        _ => true,
+    };
+    let lot_matches = match (&platform.media_lots, payload.and_then(|p| p.media_lot)) {
+        (Some(lots), Some(lot)) => lots.contains(&lot),
+        _ => true,
+    };
+    content_matches && lot_matches
+}
+
+fn get_queued_notifications_message(
+    notifications: Vec<user_notification::Model>,
+) -> NotificationMessage {
+    match notifications.len() {
+        1 => {
+            let notification = notifications.into_iter().next().unwrap();
+            NotificationMessage::new(notification.message, notification.payload)
+        }
+        count => {
+            let priority = get_max_notification_priority(&notifications);
+            NotificationMessage {
+                priority,
+                title: format!("{} new notifications", count),
+                body: notifications
+                    .into_iter()
+                    .map(|n| match n.payload.and_then(|p| p.action_url) {
+                        Some(url) => format!("{} ({})", n.message, url),
+                        None => n.message,
+                    })
+                    .join("\n"),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+fn get_max_notification_priority(
+    notifications: &[user_notification::Model],
+) -> NotificationPriority {
+    notifications
+        .iter()
+        .filter_map(|n| n.payload.as_ref().and_then(|p| p.content))
+        .map(NotificationPriority::from)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Combine all notifications into a single message with one section per entity.
+/// Notifications that are not about a specific entity are listed at the end.
+fn get_notification_digest_message(
+    digest: UserNotificationDigest,
+    notifications: Vec<user_notification::Model>,
+) -> NotificationMessage {
+    let priority = get_max_notification_priority(&notifications);
+    let count = notifications.len();
+    let body = notifications
+        .into_iter()
+        .into_group_map_by(|n| {
+            n.payload
+                .as_ref()
+                .map(|p| (p.title.clone(), p.action_url.clone()))
+                .unwrap_or_default()
+        })
+        .into_iter()
+        .sorted_by_key(|((title, _), _)| (title.is_none(), title.clone()))
+        .map(|((title, url), items)| {
+            let heading = match (title, url) {
+                (Some(title), Some(url)) => format!("{} ({})", title, url),
+                (Some(title), None) => title,
+                (None, _) => "Other".to_owned(),
+            };
+            let lines = items.into_iter().map(|n| format!("- {}", n.message));
+            std::iter::once(heading).chain(lines).join("\n")
+        })
+        .join("\n\n");
+    let period = match digest {
+        UserNotificationDigest::Weekly => "weekly",
+        _ => "daily",
+    };
+    NotificationMessage {
+        body,
+        priority,
+        title: format!("Your {} digest: {} new notifications", period, count),
+        ..Default::default()
+    }
+}
+
+/// Render the events as an iCalendar document where every event spans the whole day.
+/// The title and description of a metadata in the language, if they exist.
+fn get_localized_details(
+    localized_details: Option<Vec<MetadataLocalizedDetails>>,
+    language: Option<&String>,
+) -> Option<MetadataLocalizedDetails> {
+    let language = language?;
+    localized_details?
+        .into_iter()
+        .find(|l| &l.language == language)
+}
+
+fn get_calendar_feed(events: Vec<CalendarFeedEvent>) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_owned(),
+        "VERSION:2.0".to_owned(),
+        "PRODID:-//Ryot//Calendar//EN".to_owned(),
+        "CALSCALE:GREGORIAN".to_owned(),
+        "X-WR-CALNAME:Ryot".to_owned(),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_owned());
+        lines.push(format!("UID:{}@ryot", event.uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            event.date.format("%Y%m%d")
+        ));
+        lines.push(format!(
+            "DTEND;VALUE=DATE:{}",
+            (event.date + Days::new(1)).format("%Y%m%d")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_calendar_text(&event.summary)));
+        if let Some(description) = event.description {
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                escape_calendar_text(&description)
+            ));
+        }
+        lines.push(format!("URL:{}", event.url));
+        lines.push("END:VEVENT".to_owned());
+    }
+    lines.push("END:VCALENDAR".to_owned());
+    lines
+        .into_iter()
+        .map(|line| fold_calendar_line(&line) + "\r\n")
+        .collect()
+}
+
+fn escape_calendar_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\r', "")
+        .replace('\n', "\\n")
+}
+
+/// Lines longer than 75 octets have to be split, with every continuation line
+/// starting with a space.
+fn fold_calendar_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded
+}
+
+/// Find the season and episode numbers of an episode on another provider, first by the
+/// name of the episode and then by its release date. Returns `None` if there is no
+/// single match.
+fn find_matching_show_episode(
+    old: &ShowSpecifics,
+    new: &ShowSpecifics,
+    season: i32,
+    episode: i32,
+) -> Option<(i32, i32)> {
+    let old_episode = old
+        .seasons
+        .iter()
+        .find(|s| s.season_number == season)?
+        .episodes
+        .iter()
+        .find(|e| e.episode_number == episode)?;
+    let episodes = new
+        .seasons
+        .iter()
+        .flat_map(|s| s.episodes.iter().map(|e| (s.season_number, e)))
+        .collect_vec();
+    let by_name = episodes
+        .iter()
+        .filter(|(_, e)| {
+            !old_episode.name.is_empty() && e.name.eq_ignore_ascii_case(&old_episode.name)
+        })
+        .collect_vec();
+    if let [(season, e)] = by_name.as_slice() {
+        return Some((*season, e.episode_number));
+    }
+    let by_date = episodes
+        .iter()
+        .filter(|(_, e)| {
+            old_episode.publish_date.is_some() && e.publish_date == old_episode.publish_date
+        })
+        .collect_vec();
+    match by_date.as_slice() {
+        [(season, e)] => Some((*season, e.episode_number)),
+        _ => None,
+    }
+}
+
+/// Find the number of a podcast episode on another provider, first by its title and
+/// then by its release date. Returns `None` if there is no single match.
+fn find_matching_podcast_episode(
+    old: &PodcastSpecifics,
+    new: &PodcastSpecifics,
+    episode: i32,
+) -> Option<i32> {
+    let old_episode = old.episodes.iter().find(|e| e.number == episode)?;
+    let by_title = new
+        .episodes
+        .iter()
+        .filter(|e| e.title.eq_ignore_ascii_case(&old_episode.title))
+        .collect_vec();
+    if let [e] = by_title.as_slice() {
+        return Some(e.number);
+    }
+    let by_date = new
+        .episodes
+        .iter()
+        .filter(|e| e.publish_date == old_episode.publish_date)
+        .collect_vec();
+    match by_date.as_slice() {
+        [e] => Some(e.number),
+        _ => None,
+    }
+}
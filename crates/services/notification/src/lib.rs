@@ -2,6 +2,7 @@ use std::env;
 
 use anyhow::{anyhow, Result};
 use askama::Template;
+use common_models::{UserNotificationContent, UserNotificationPayload};
 use common_utils::{ryot_log, APPLICATION_JSON_HEADER, AVATAR_URL, PROJECT_NAME};
 use config::AppConfig;
 use convert_case::{Case, Casing};
@@ -10,75 +11,264 @@ use lettre::{
     transport::smtp::authentication::Credentials,
     Message, SmtpTransport, Transport,
 };
+use nanoid::nanoid;
 use reqwest::{
     header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
-    Client,
+    Client, StatusCode, Url,
 };
 use serde::{Deserialize, Serialize};
-use user_models::NotificationPlatformSpecifics;
+use user_models::{NotificationPlatformSmtpSettings, NotificationPlatformSpecifics};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum NotificationPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl From<UserNotificationContent> for NotificationPriority {
+    fn from(content: UserNotificationContent) -> Self {
+        match content {
+            UserNotificationContent::MetadataPublished
+            | UserNotificationContent::EntityReminderDue
+            | UserNotificationContent::MetadataEpisodeReleased => Self::High,
+            UserNotificationContent::NewWorkoutCreated
+            | UserNotificationContent::MetadataEpisodeNameChanged
+            | UserNotificationContent::MetadataEpisodeImagesChanged => Self::Low,
+            _ => Self::Normal,
+        }
+    }
+}
+
+fn title_for_content(content: UserNotificationContent) -> &'static str {
+    match content {
+        UserNotificationContent::ReviewPosted => "New review posted",
+        UserNotificationContent::EntityReminderDue => "Reminder",
+        UserNotificationContent::MetadataPublished => "Released today",
+        UserNotificationContent::NewWorkoutCreated => "Workout created",
+        UserNotificationContent::MetadataStatusChanged => "Status changed",
+        UserNotificationContent::MetadataEpisodeReleased => "New episode released",
+        UserNotificationContent::PersonMetadataAssociated
+        | UserNotificationContent::PersonMetadataGroupAssociated => "New credit",
+        UserNotificationContent::MetadataReleaseDateChanged => "Release date changed",
+        UserNotificationContent::MetadataEpisodeNameChanged => "Episode renamed",
+        UserNotificationContent::MetadataEpisodeImagesChanged => "Episode image changed",
+        UserNotificationContent::MetadataNumberOfSeasonsChanged => "Number of seasons changed",
+        UserNotificationContent::MetadataChaptersOrEpisodesChanged => "Number of episodes changed",
+    }
+}
+
+/// A notification that is ready to be rendered for a platform.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct NotificationMessage {
+    pub title: String,
+    pub body: String,
+    pub image: Option<String>,
+    pub action_url: Option<String>,
+    pub priority: NotificationPriority,
+}
+
+impl NotificationMessage {
+    pub fn new(body: String, payload: Option<UserNotificationPayload>) -> Self {
+        let payload = payload.unwrap_or_default();
+        let title = match (payload.content, payload.title) {
+            (Some(content), Some(title)) => format!("{}: {}", title_for_content(content), title),
+            (Some(content), None) => title_for_content(content).to_owned(),
+            (None, Some(title)) => title,
+            (None, None) => PROJECT_NAME.to_case(Case::Title),
+        };
+        Self {
+            body,
+            title,
+            image: payload.image,
+            action_url: payload.action_url,
+            priority: payload.content.map(Into::into).unwrap_or_default(),
+        }
+    }
+}
+
+mod filters {
+    /// Escape the characters that have a special meaning in the legacy Markdown mode
+    /// of Telegram.
+    pub fn telegram_escape<T: std::fmt::Display>(s: T) -> askama::Result<String> {
+        let mut escaped = String::new();
+        for c in s.to_string().chars() {
+            if matches!(c, '_' | '*' | '[' | '`') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        Ok(escaped)
+    }
+}
+
+#[derive(Template)]
+#[template(
+    ext = "txt",
+    source = "{{ body }}{% if let Some(url) = action_url %}\n\n{{ url }}{% endif %}"
+)]
+struct PlainTextTemplate<'a> {
+    body: &'a str,
+    action_url: &'a Option<String>,
+}
+
+#[derive(Template)]
+#[template(
+    ext = "txt",
+    source = "*{{ title|telegram_escape }}*\n{{ body|telegram_escape }}{% if let Some(url) = action_url %}\n\n[Open in Ryot]({{ url }}){% endif %}"
+)]
+struct TelegramTemplate<'a> {
+    title: &'a str,
+    body: &'a str,
+    action_url: &'a Option<String>,
+}
+
+#[derive(Template)]
+#[template(
+    ext = "html",
+    source = "<strong>{{ title }}</strong><br/>{{ body|escape|linebreaksbr|safe }}{% if let Some(url) = action_url %}<br/><br/><a href=\"{{ url }}\">Open in Ryot</a>{% endif %}"
+)]
+struct MatrixTemplate<'a> {
+    title: &'a str,
+    body: &'a str,
+    action_url: &'a Option<String>,
+}
+
+const SLACK_HEADER_MAX_LENGTH: usize = 150;
+
+/// Escape the characters that have a special meaning in Slack messages.
+fn slack_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[derive(Template)]
+#[template(path = "Notification.html")]
+struct NotificationHtml<'a> {
+    title: &'a str,
+    body: &'a str,
+    image: &'a str,
+    action_url: &'a str,
+}
+
+/// Whether sending a notification failed because of a problem that is likely to go away,
+/// like the platform being unreachable or rate limiting us.
+pub fn is_transient_notification_error(error: &anyhow::Error) -> bool {
+    if let Some(err) = error.downcast_ref::<reqwest::Error>() {
+        return match err.status() {
+            Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+            None => err.is_timeout() || err.is_connect() || err.is_request(),
+        };
+    }
+    if let Some(err) = error.downcast_ref::<lettre::transport::smtp::Error>() {
+        return !err.is_permanent();
+    }
+    false
+}
 
-// TODO: Allow formatting messages
 pub async fn send_notification(
     specifics: NotificationPlatformSpecifics,
     config: &AppConfig,
-    msg: &str,
+    notification: &NotificationMessage,
 ) -> Result<()> {
     let project_name = PROJECT_NAME.to_case(Case::Title);
     let client = Client::new();
     if env::var("DISABLE_NOTIFICATIONS").is_ok() {
-        ryot_log!(warn, "Notification not sent. Body was: {:#?}", msg);
+        ryot_log!(warn, "Notification not sent. Body was: {:#?}", notification);
         return Ok(());
     }
+    let NotificationMessage {
+        title,
+        body,
+        image,
+        action_url,
+        priority,
+    } = notification;
+    let msg = PlainTextTemplate { body, action_url }.render()?;
     match specifics {
         NotificationPlatformSpecifics::Apprise { url, key } => {
+            let mut payload = serde_json::json!({
+                "body": msg,
+                "title": title,
+                "type": match priority {
+                    NotificationPriority::High => "warning",
+                    _ => "info",
+                },
+            });
+            if let Some(image) = image {
+                payload["attach"] = serde_json::json!(image);
+            }
             client
                 .post(format!("{}/notify/{}", url, key))
                 .header(CONTENT_TYPE, APPLICATION_JSON_HEADER.clone())
-                .json(&serde_json::json!({
-                    "body": msg,
-                    "title": project_name,
-                }))
+                .json(&payload)
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::Discord { url } => {
+            let mut embed = serde_json::json!({
+                "title": title,
+                "description": body,
+                "color": match priority {
+                    NotificationPriority::Low => 0x99AAB5,
+                    NotificationPriority::Normal => 0x5865F2,
+                    NotificationPriority::High => 0xED4245,
+                },
+            });
+            if let Some(action_url) = action_url {
+                embed["url"] = serde_json::json!(action_url);
+            }
+            if let Some(image) = image {
+                embed["image"] = serde_json::json!({ "url": image });
+            }
             client
                 .post(url)
                 .json(&serde_json::json!({
-                    "content": msg,
+                    "embeds": [embed],
                     "username": project_name,
                     "avatar_url": AVATAR_URL
                 }))
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::Gotify {
             url,
             token,
-            priority,
+            priority: gotify_priority,
         } => {
+            let mut extras = serde_json::json!({
+                "client::display": { "contentType": "text/plain" },
+                "client::notification": {
+                    "bigImageUrl": image.clone().unwrap_or_else(|| AVATAR_URL.to_owned())
+                }
+            });
+            if let Some(action_url) = action_url {
+                extras["client::notification"]["click"] = serde_json::json!({ "url": action_url });
+            }
             client
                 .post(format!("{}/message", url))
                 .header("X-Gotify-Key", HeaderValue::from_str(&token).unwrap())
                 .json(&serde_json::json!({
                     "message": msg,
-                    "title": project_name,
-                    "priority": priority.unwrap_or(5),
-                    "extras": {
-                        "client::notification": {
-                          "bigImageUrl": AVATAR_URL
-                        }
-                     }
+                    "title": title,
+                    "priority": gotify_priority.unwrap_or(match priority {
+                        NotificationPriority::Low => 2,
+                        NotificationPriority::Normal => 5,
+                        NotificationPriority::High => 8,
+                    }),
+                    "extras": extras
                 }))
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::Ntfy {
             url,
-            priority,
+            priority: ntfy_priority,
             topic,
             auth_header,
         } => {
@@ -88,14 +278,21 @@ pub async fn send_notification(
                     url.clone().unwrap_or_else(|| "https://ntfy.sh".to_owned()),
                     topic
                 ))
-                .header("Title", project_name)
-                .header("Attach", AVATAR_URL)
+                .header("Title", title)
+                .header("Attach", image.as_deref().unwrap_or(AVATAR_URL))
                 .header(
                     "Priority",
-                    priority
-                        .map(|p| p.to_string())
-                        .unwrap_or_else(|| "3".to_owned()),
+                    ntfy_priority
+                        .unwrap_or(match priority {
+                            NotificationPriority::Low => 2,
+                            NotificationPriority::Normal => 3,
+                            NotificationPriority::High => 4,
+                        })
+                        .to_string(),
                 );
+            if let Some(action_url) = action_url {
+                request = request.header("Click", action_url);
+            }
             if let Some(token) = auth_header {
                 request = request.header(
                     AUTHORIZATION,
@@ -103,99 +300,230 @@ pub async fn send_notification(
                 );
             }
             request
-                .body(msg.to_owned())
+                .body(body.to_owned())
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::PushBullet { api_token } => {
+            let payload = match action_url {
+                Some(url) => serde_json::json!({
+                    "url": url,
+                    "body": body,
+                    "title": title,
+                    "type": "link"
+                }),
+                None => serde_json::json!({
+                    "body": body,
+                    "title": title,
+                    "type": "note"
+                }),
+            };
             client
                 .post("https://api.pushbullet.com/v2/pushes")
                 .header("Access-Token", api_token)
-                .json(&serde_json::json!({
-                    "body": msg,
-                    "title": project_name,
-                    "type": "note"
-                }))
+                .json(&payload)
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::PushOver { key, app_key } => {
-            client.post("https://api.pushover.net/1/messages.json")
-                    .query(&serde_json::json!({
-                        "token":  app_key.clone().unwrap_or_else(|| "abd1semr21hv1i5j5kfkm23wf1kd4u".to_owned()),
-                        "user": key,
-                        "message": msg,
-                        "title": project_name
-                    }))
-                    .send()
-                    .await
-                    .map_err(|e| anyhow!(e))?;
+            let mut payload = serde_json::json!({
+                "token":  app_key.clone().unwrap_or_else(|| "abd1semr21hv1i5j5kfkm23wf1kd4u".to_owned()),
+                "user": key,
+                "message": body,
+                "title": title,
+                "priority": match priority {
+                    NotificationPriority::Low => -1,
+                    NotificationPriority::Normal => 0,
+                    NotificationPriority::High => 1,
+                }
+            });
+            if let Some(url) = action_url {
+                payload["url"] = serde_json::json!(url);
+            }
+            client
+                .post("https://api.pushover.net/1/messages.json")
+                .query(&payload)
+                .send()
+                .await?
+                .error_for_status()?;
         }
         NotificationPlatformSpecifics::PushSafer { key } => {
+            let mut payload = serde_json::json!({
+                "k": key,
+                "m": body,
+                "t": title,
+                "pr": match priority {
+                    NotificationPriority::Low => -1,
+                    NotificationPriority::Normal => 0,
+                    NotificationPriority::High => 1,
+                }
+            });
+            if let Some(url) = action_url {
+                payload["u"] = serde_json::json!(url);
+            }
             client
                 .post("https://www.pushsafer.com/api")
-                .query(&serde_json::json!({
-                    "k": key,
-                    "m": msg,
-                    "t": project_name
-                }))
+                .query(&payload)
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
-        NotificationPlatformSpecifics::Email { email } => {
-            #[derive(Template, Serialize, Deserialize, Debug, Clone)]
-            #[template(path = "Generic.html")]
-            pub struct GenericHtml {
-                pub generic_message: String,
-            }
-
-            let body = GenericHtml {
-                generic_message: msg.to_owned(),
+        NotificationPlatformSpecifics::Email { email, smtp } => {
+            let html = NotificationHtml {
+                body,
+                title,
+                image: image.as_deref().unwrap_or(AVATAR_URL),
+                action_url: action_url.as_deref().unwrap_or(&config.frontend.url),
             }
             .render()?;
 
-            let credentials = Credentials::new(
-                config.server.smtp.user.to_owned(),
-                config.server.smtp.password.to_owned(),
-            );
+            let smtp = smtp.unwrap_or_else(|| NotificationPlatformSmtpSettings {
+                server: config.server.smtp.server.to_owned(),
+                user: config.server.smtp.user.to_owned(),
+                password: config.server.smtp.password.to_owned(),
+                mailbox: None,
+            });
+            let credentials = Credentials::new(smtp.user, smtp.password);
 
-            let mailer = SmtpTransport::relay(&config.server.smtp.server)
-                .unwrap()
+            let mailer = SmtpTransport::relay(&smtp.server)?
                 .credentials(credentials)
                 .build();
 
-            let mailbox = config.server.smtp.mailbox.parse().unwrap();
+            let mailbox = smtp
+                .mailbox
+                .unwrap_or_else(|| config.server.smtp.mailbox.to_owned())
+                .parse()?;
             let email = Message::builder()
                 .from(mailbox)
-                .to(email.parse().unwrap())
-                .subject(format!("{} notification", project_name))
+                .to(email.parse()?)
+                .subject(format!("{} - {}", project_name, title))
                 .multipart(
                     MultiPart::mixed().singlepart(
                         SinglePart::builder()
                             .header(header::ContentType::TEXT_HTML)
-                            .body(body),
+                            .body(html),
                     ),
                 )
                 .unwrap();
-            mailer.send(&email).map_err(|e| anyhow!(e))?;
+            mailer.send(&email)?;
         }
         NotificationPlatformSpecifics::Telegram { bot_token, chat_id } => {
+            let text = TelegramTemplate {
+                body,
+                title,
+                action_url,
+            }
+            .render()?;
+            let request = match image {
+                Some(image) => client
+                    .post(format!(
+                        "https://api.telegram.org/bot{}/sendPhoto",
+                        bot_token
+                    ))
+                    .json(&serde_json::json!({
+                        "chat_id": chat_id,
+                        "photo": image,
+                        "caption": text,
+                        "parse_mode": "Markdown"
+                    })),
+                None => client
+                    .post(format!(
+                        "https://api.telegram.org/bot{}/sendMessage",
+                        bot_token
+                    ))
+                    .json(&serde_json::json!({
+                        "chat_id": chat_id,
+                        "text": text,
+                        "parse_mode": "Markdown"
+                    })),
+            };
+            request.send().await?.error_for_status()?;
+        }
+        NotificationPlatformSpecifics::Matrix {
+            url,
+            token,
+            room_id,
+        } => {
+            let formatted_body = MatrixTemplate {
+                body,
+                title,
+                action_url,
+            }
+            .render()?;
+            let text = PlainTextTemplate { body, action_url }.render()?;
+            let txn_id = nanoid!();
+            let mut endpoint = Url::parse(&url)?;
+            endpoint
+                .path_segments_mut()
+                .map_err(|_| anyhow!("Homeserver URL can not be a base"))?
+                .pop_if_empty()
+                .extend([
+                    "_matrix",
+                    "client",
+                    "v3",
+                    "rooms",
+                    &room_id,
+                    "send",
+                    "m.room.message",
+                    &txn_id,
+                ]);
             client
-                .post(format!(
-                    "https://api.telegram.org/bot{}/sendMessage",
-                    bot_token
-                ))
+                .put(endpoint)
+                .bearer_auth(token)
                 .json(&serde_json::json!({
-                    "chat_id": chat_id,
-                    "text": msg,
-                    "parse_mode": "Markdown"
+                    "msgtype": "m.text",
+                    "body": format!("{}\n{}", title, text),
+                    "format": "org.matrix.custom.html",
+                    "formatted_body": formatted_body
+                }))
+                .send()
+                .await?
+                .error_for_status()?;
+        }
+        NotificationPlatformSpecifics::Slack { url } => {
+            let mut blocks = vec![
+                serde_json::json!({
+                    "type": "header",
+                    "text": {
+                        "type": "plain_text",
+                        "text": title.chars().take(SLACK_HEADER_MAX_LENGTH).collect::<String>()
+                    }
+                }),
+                serde_json::json!({
+                    "type": "section",
+                    "text": { "type": "mrkdwn", "text": slack_escape(body) }
+                }),
+            ];
+            if let Some(image) = image {
+                blocks.push(serde_json::json!({
+                    "type": "image",
+                    "image_url": image,
+                    "alt_text": title
+                }));
+            }
+            if let Some(action_url) = action_url {
+                blocks.push(serde_json::json!({
+                    "type": "actions",
+                    "elements": [{
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Open in Ryot" },
+                        "url": action_url
+                    }]
+                }));
+            }
+            client
+                .post(url)
+                .json(&serde_json::json!({
+                    "text": title,
+                    "username": project_name,
+                    "icon_url": AVATAR_URL,
+                    "blocks": blocks
                 }))
                 .send()
-                .await
-                .map_err(|e| anyhow!(e))?;
+                .await?
+                .error_for_status()?;
         }
     }
     Ok(())
@@ -193,6 +193,15 @@ impl StatisticsService {
                 "total_podcast_duration",
             )
             .column_as(daily_user_activity::Column::MangaCount.sum(), "manga_count")
+            .column_as(daily_user_activity::Column::ComicCount.sum(), "comic_count")
+            .column_as(
+                daily_user_activity::Column::BoardGameCount.sum(),
+                "board_game_count",
+            )
+            .column_as(
+                daily_user_activity::Column::BoardGameDuration.sum(),
+                "total_board_game_duration",
+            )
             .column_as(daily_user_activity::Column::MovieCount.sum(), "movie_count")
             .column_as(
                 daily_user_activity::Column::MovieDuration.sum(),
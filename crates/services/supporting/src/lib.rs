@@ -147,7 +147,13 @@ impl SupportingService {
             disable_telemetry: self.config.disable_telemetry,
             smtp_enabled: self.config.server.smtp.is_enabled(),
             signup_allowed: self.config.users.allow_registration,
-            people_search_sources: PEOPLE_SEARCH_SOURCES.to_vec(),
+            people_search_sources: PEOPLE_SEARCH_SOURCES
+                .iter()
+                .copied()
+                .chain(
+                    (!self.config.media.remote_providers.is_empty()).then_some(MediaSource::Remote),
+                )
+                .collect(),
             local_auth_disabled: self.config.users.disable_local_auth,
             token_valid_for_days: self.config.users.token_valid_for_days,
             repository_link: "https://github.com/ignisda/ryot".to_owned(),
@@ -163,7 +169,28 @@ impl SupportingService {
                 .iter()
                 .map(|(lot, sources)| MetadataLotSourceMappings {
                     lot: *lot,
-                    sources: sources.to_vec(),
+                    sources: sources
+                        .iter()
+                        .copied()
+                        .filter(|source| {
+                            // RAWG is only used when configured, and replaces IGDB
+                            // as the default unless Twitch credentials are present.
+                            let video_games = &self.config.video_games;
+                            match source {
+                                MediaSource::Rawg => video_games.is_rawg_enabled(),
+                                MediaSource::Igdb => {
+                                    video_games.is_igdb_enabled() || !video_games.is_rawg_enabled()
+                                }
+                                _ => true,
+                            }
+                        })
+                        .chain(
+                            self.config
+                                .media
+                                .remote_provider_for_lot(*lot)
+                                .map(|_| MediaSource::Remote),
+                        )
+                        .collect(),
                 })
                 .collect(),
             exercise_parameters: ExerciseParameters {
@@ -208,16 +235,29 @@ impl SupportingService {
                                 .collect(),
                             "en".to_owned(),
                         ),
+                        MediaSource::Tvdb => (
+                            isolang::languages()
+                                .map(|l| l.to_639_3().to_owned())
+                                .collect(),
+                            "eng".to_owned(),
+                        ),
                         MediaSource::Mal
+                        | MediaSource::Rss
                         | MediaSource::Igdb
+                        | MediaSource::Rawg
                         | MediaSource::Vndb
                         | MediaSource::Custom
+                        | MediaSource::Remote
                         | MediaSource::Anilist
+                        | MediaSource::ComicVine
                         | MediaSource::Hardcover
                         | MediaSource::GoogleBooks
                         | MediaSource::Listennotes
                         | MediaSource::Openlibrary
-                        | MediaSource::MangaUpdates => (vec!["us".to_owned()], "us".to_owned()),
+                        | MediaSource::MusicBrainz
+                        | MediaSource::PodcastIndex
+                        | MediaSource::MangaUpdates
+                        | MediaSource::BoardGameGeek => (vec!["us".to_owned()], "us".to_owned()),
                     };
                     ProviderLanguageInformation {
                         source,
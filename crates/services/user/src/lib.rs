@@ -7,8 +7,10 @@ use chrono::Utc;
 use common_models::{ApplicationCacheKey, DefaultCollection, StringIdObject, UserLevelCacheKey};
 use common_utils::ryot_log;
 use database_models::{
-    access_link, integration, metadata, notification_platform,
-    prelude::{AccessLink, Integration, Metadata, NotificationPlatform, User},
+    access_link, integration, metadata, notification_delivery, notification_platform,
+    prelude::{
+        AccessLink, Integration, Metadata, NotificationDelivery, NotificationPlatform, User,
+    },
     user,
 };
 use database_utils::{
@@ -17,23 +19,26 @@ use database_utils::{
 };
 use dependent_models::{
     ApplicationCacheValue, UserDetailsResult, UserMetadataRecommendationsResponse,
+    UserNotificationPlatform,
 };
-use dependent_utils::create_or_update_collection;
+use dependent_utils::{create_or_update_collection, deploy_update_metadata_jobs_for_language};
 use enum_meta::Meta;
 use enum_models::{IntegrationLot, IntegrationProvider, NotificationPlatformLot, UserLot};
+use external_utils::{anilist, mal, trakt};
 use itertools::Itertools;
 use jwt_service::{sign, AccessLinkClaims};
 use media_models::{
     AuthUserInput, CreateAccessLinkInput, CreateOrUpdateCollectionInput,
-    CreateUserIntegrationInput, CreateUserNotificationPlatformInput, LoginError, LoginErrorVariant,
-    LoginResponse, LoginResult, OidcTokenOutput, PasswordUserInput, ProcessAccessLinkError,
-    ProcessAccessLinkErrorVariant, ProcessAccessLinkInput, ProcessAccessLinkResponse,
-    ProcessAccessLinkResult, RegisterError, RegisterErrorVariant, RegisterResult,
-    RegisterUserInput, UpdateUserIntegrationInput, UpdateUserNotificationPlatformInput,
-    UserDetailsError, UserDetailsErrorVariant,
+    CreateUserIntegrationInput, CreateUserNotificationPlatformInput, IntegrationProviderSpecifics,
+    LoginError, LoginErrorVariant, LoginResponse, LoginResult, MalAuthorizationUrl,
+    OidcTokenOutput, PasswordUserInput, ProcessAccessLinkError, ProcessAccessLinkErrorVariant,
+    ProcessAccessLinkInput, ProcessAccessLinkResponse, ProcessAccessLinkResult, RegisterError,
+    RegisterErrorVariant, RegisterResult, RegisterUserInput, TraktDeviceCode,
+    UpdateUserIntegrationInput, UpdateUserNotificationPlatformInput, UserDetailsError,
+    UserDetailsErrorVariant,
 };
 use nanoid::nanoid;
-use notification_service::send_notification;
+use notification_service::{send_notification, NotificationMessage};
 use openidconnect::{
     core::CoreResponseType, reqwest::async_http_client, AuthenticationFlow, AuthorizationCode,
     CsrfToken, Nonce, Scope, TokenResponse,
@@ -50,6 +55,8 @@ use user_models::{
     DashboardElementLot, NotificationPlatformSpecifics, UpdateUserInput, UserPreferences,
 };
 
+const RECENT_NOTIFICATION_DELIVERIES_COUNT: usize = 20;
+
 fn empty_nonce_verifier(_nonce: Option<&Nonce>) -> Result<(), String> {
     Ok(())
 }
@@ -364,6 +371,17 @@ impl UserService {
         Ok(auth_token)
     }
 
+    pub async fn generate_calendar_feed_token(&self, user_id: String) -> Result<String> {
+        let user = user_by_id(&user_id, &self.0).await?;
+        let token = nanoid!(32);
+        let mut extra_information = user.extra_information.clone().unwrap_or_default();
+        extra_information.calendar_feed_token = Some(token.clone());
+        let mut user: user::ActiveModel = user.into();
+        user.extra_information = ActiveValue::Set(Some(extra_information));
+        user.update(&self.0.db).await?;
+        Ok(token)
+    }
+
     pub async fn login_user(&self, input: AuthUserInput) -> Result<LoginResult> {
         let filter = match input.clone() {
             AuthUserInput::Oidc(input) => user::Column::OidcIssuerId.eq(input.issuer_id),
@@ -441,12 +459,37 @@ impl UserService {
     pub async fn update_user_preference(
         &self,
         user_id: String,
-        input: UserPreferences,
+        mut input: UserPreferences,
     ) -> Result<bool> {
+        // Only the language is used, so "de-DE" is stored as "de"
+        input.general.language = input
+            .general
+            .language
+            .and_then(|l| l.split(['-', '_']).next().map(|l| l.trim().to_lowercase()))
+            .filter(|l| !l.is_empty());
+        input.general.region = input
+            .general
+            .region
+            .map(|r| r.trim().to_uppercase())
+            .filter(|r| !r.is_empty());
+        if input.notifications.digest_hour > 23 {
+            return Err(Error::new("Digest hour must be between 0 and 23"));
+        }
+        if !(1..=7).contains(&input.notifications.digest_weekday) {
+            return Err(Error::new("Digest weekday must be between 1 and 7"));
+        }
         let user_model = user_by_id(&user_id, &self.0).await?;
+        let new_language = input
+            .general
+            .language
+            .clone()
+            .filter(|l| user_model.preferences.general.language.as_ref() != Some(l));
         let mut user_model: user::ActiveModel = user_model.into();
         user_model.preferences = ActiveValue::Set(input);
         user_model.update(&self.0.db).await?;
+        if let Some(language) = new_language {
+            deploy_update_metadata_jobs_for_language(&user_id, &language, &self.0).await?;
+        }
         Ok(true)
     }
 
@@ -484,6 +527,26 @@ impl UserService {
         Ok(true)
     }
 
+    pub async fn initiate_trakt_device_login(&self) -> Result<TraktDeviceCode> {
+        let config = &self.0.config.integration.trakt;
+        if !config.is_enabled() {
+            return Err(Error::new("The Trakt integration has not been configured"));
+        }
+        trakt::get_device_code(config)
+            .await
+            .map_err(|e| Error::new(e.to_string()))
+    }
+
+    pub async fn initiate_mal_login(&self) -> Result<MalAuthorizationUrl> {
+        let config = &self.0.config.integration.mal;
+        if !config.is_enabled() {
+            return Err(Error::new(
+                "The MyAnimeList integration has not been configured",
+            ));
+        }
+        mal::get_authorization_url(config, nanoid!(64)).map_err(|e| Error::new(e.to_string()))
+    }
+
     pub async fn create_user_integration(
         &self,
         user_id: String,
@@ -500,9 +563,64 @@ impl UserService {
                 "Minimum progress cannot be greater than maximum progress",
             ));
         }
+        let mut provider_specifics = input.provider_specifics;
+        match input.provider {
+            IntegrationProvider::Trakt => {
+                let device_code = provider_specifics
+                    .as_ref()
+                    .and_then(|s| s.trakt_device_code.clone())
+                    .ok_or_else(|| Error::new("A Trakt device code is required"))?;
+                let tokens = trakt::get_tokens_for_device_code(
+                    &self.0.config.integration.trakt,
+                    &device_code,
+                )
+                .await
+                .map_err(|e| Error::new(e.to_string()))?;
+                provider_specifics = Some(IntegrationProviderSpecifics {
+                    trakt_device_code: None,
+                    ..tokens
+                });
+            }
+            IntegrationProvider::Anilist => {
+                let token = provider_specifics
+                    .as_ref()
+                    .and_then(|s| s.anilist_token.clone())
+                    .ok_or_else(|| Error::new("An Anilist token is required"))?;
+                anilist::get_viewer_id(&token)
+                    .await
+                    .map_err(|e| Error::new(e.to_string()))?;
+            }
+            IntegrationProvider::Myanimelist => {
+                let (Some(code), Some(code_verifier)) = (
+                    provider_specifics.as_ref().and_then(|s| s.mal_code.clone()),
+                    provider_specifics
+                        .as_ref()
+                        .and_then(|s| s.mal_code_verifier.clone()),
+                ) else {
+                    return Err(Error::new("A MyAnimeList authorization code is required"));
+                };
+                let tokens = mal::get_tokens_for_code(
+                    &self.0.config.integration.mal,
+                    code.trim(),
+                    &code_verifier,
+                )
+                .await
+                .map_err(|e| Error::new(e.to_string()))?;
+                provider_specifics = Some(IntegrationProviderSpecifics {
+                    mal_code: None,
+                    mal_code_verifier: None,
+                    ..tokens
+                });
+            }
+            _ => {}
+        }
         let lot = match input.provider {
             IntegrationProvider::Komga
+            | IntegrationProvider::Steam
+            | IntegrationProvider::Trakt
+            | IntegrationProvider::Anilist
             | IntegrationProvider::PlexYank
+            | IntegrationProvider::Myanimelist
             | IntegrationProvider::YoutubeMusic
             | IntegrationProvider::Audiobookshelf => IntegrationLot::Yank,
             IntegrationProvider::Radarr
@@ -516,7 +634,7 @@ impl UserService {
             provider: ActiveValue::Set(input.provider),
             minimum_progress: ActiveValue::Set(input.minimum_progress),
             maximum_progress: ActiveValue::Set(input.maximum_progress),
-            provider_specifics: ActiveValue::Set(input.provider_specifics),
+            provider_specifics: ActiveValue::Set(provider_specifics),
             sync_to_owned_collection: ActiveValue::Set(input.sync_to_owned_collection),
             ..Default::default()
         };
@@ -574,13 +692,29 @@ impl UserService {
             NotificationPlatformLot::PushSafer => NotificationPlatformSpecifics::PushSafer {
                 key: input.api_token.unwrap(),
             },
-            NotificationPlatformLot::Email => NotificationPlatformSpecifics::Email {
-                email: input.api_token.unwrap(),
-            },
+            NotificationPlatformLot::Email => {
+                if input.smtp.is_none() && !self.0.config.server.smtp.is_enabled() {
+                    return Err(Error::new(
+                        "SMTP is not configured for this instance, please provide your own settings",
+                    ));
+                }
+                NotificationPlatformSpecifics::Email {
+                    email: input.api_token.unwrap(),
+                    smtp: input.smtp,
+                }
+            }
             NotificationPlatformLot::Telegram => NotificationPlatformSpecifics::Telegram {
                 bot_token: input.api_token.unwrap(),
                 chat_id: input.chat_id.unwrap(),
             },
+            NotificationPlatformLot::Matrix => NotificationPlatformSpecifics::Matrix {
+                url: input.base_url.unwrap(),
+                token: input.api_token.unwrap(),
+                room_id: input.chat_id.unwrap(),
+            },
+            NotificationPlatformLot::Slack => NotificationPlatformSpecifics::Slack {
+                url: input.base_url.unwrap(),
+            },
         };
         let description = match &specifics {
             NotificationPlatformSpecifics::Apprise { url, key } => {
@@ -604,18 +738,27 @@ impl UserService {
             NotificationPlatformSpecifics::PushSafer { key } => {
                 format!("Key: {}", key)
             }
-            NotificationPlatformSpecifics::Email { email } => {
-                format!("ID: {}", email)
-            }
+            NotificationPlatformSpecifics::Email { email, smtp } => match smtp {
+                Some(smtp) => format!("ID: {}, SMTP Server: {}", email, smtp.server),
+                None => format!("ID: {}", email),
+            },
             NotificationPlatformSpecifics::Telegram { chat_id, .. } => {
                 format!("Chat ID: {}", chat_id)
             }
+            NotificationPlatformSpecifics::Matrix { url, room_id, .. } => {
+                format!("Homeserver: {}, Room ID: {}", url, room_id)
+            }
+            NotificationPlatformSpecifics::Slack { url } => {
+                format!("Webhook: {}", url)
+            }
         };
         let notification = notification_platform::ActiveModel {
             lot: ActiveValue::Set(input.lot),
             user_id: ActiveValue::Set(user_id),
             platform_specifics: ActiveValue::Set(specifics),
             description: ActiveValue::Set(description),
+            content_types: ActiveValue::Set(input.content_types.filter(|c| !c.is_empty())),
+            media_lots: ActiveValue::Set(input.media_lots.filter(|l| !l.is_empty())),
             ..Default::default()
         };
         let new_notification_id = notification.insert(&self.0.db).await?.id;
@@ -639,6 +782,15 @@ impl UserService {
         let mut db_notification: notification_platform::ActiveModel = db_notification.into();
         if let Some(s) = input.is_disabled {
             db_notification.is_disabled = ActiveValue::Set(Some(s));
+            if !s {
+                db_notification.consecutive_failures = ActiveValue::Set(None);
+            }
+        }
+        if let Some(c) = input.content_types {
+            db_notification.content_types = ActiveValue::Set(Some(c).filter(|c| !c.is_empty()));
+        }
+        if let Some(l) = input.media_lots {
+            db_notification.media_lots = ActiveValue::Set(Some(l).filter(|l| !l.is_empty()));
         }
         db_notification.update(&self.0.db).await?;
         Ok(true)
@@ -671,7 +823,10 @@ impl UserService {
             if platform.is_disabled.unwrap_or_default() {
                 continue;
             }
-            let msg = format!("This is a test notification for platform: {}", platform.lot);
+            let msg = NotificationMessage::new(
+                format!("This is a test notification for platform: {}", platform.lot),
+                None,
+            );
             send_notification(platform.platform_specifics, &self.0.config, &msg).await?;
         }
         Ok(true)
@@ -699,12 +854,33 @@ impl UserService {
     pub async fn user_notification_platforms(
         &self,
         user_id: &String,
-    ) -> Result<Vec<notification_platform::Model>> {
+    ) -> Result<Vec<UserNotificationPlatform>> {
         let all_notifications = NotificationPlatform::find()
             .filter(notification_platform::Column::UserId.eq(user_id))
             .all(&self.0.db)
             .await?;
-        Ok(all_notifications)
+        let mut all_deliveries = NotificationDelivery::find()
+            .filter(
+                notification_delivery::Column::NotificationPlatformId
+                    .is_in(all_notifications.iter().map(|n| n.id.clone())),
+            )
+            .order_by_desc(notification_delivery::Column::LastAttemptedOn)
+            .all(&self.0.db)
+            .await?
+            .into_iter()
+            .into_group_map_by(|d| d.notification_platform_id.clone());
+        let platforms = all_notifications
+            .into_iter()
+            .map(|details| {
+                let mut deliveries = all_deliveries.remove(&details.id).unwrap_or_default();
+                deliveries.truncate(RECENT_NOTIFICATION_DELIVERIES_COUNT);
+                UserNotificationPlatform {
+                    details,
+                    deliveries,
+                }
+            })
+            .collect();
+        Ok(platforms)
     }
 
     pub async fn get_oidc_redirect_url(&self) -> Result<String> {
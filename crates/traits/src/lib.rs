@@ -9,9 +9,11 @@ use common_utils::ryot_log;
 use database_models::metadata_group::MetadataGroupWithoutId;
 use database_utils::check_token;
 use dependent_models::{
-    MetadataGroupSearchResponse, PersonDetails, PeopleSearchResponse, SearchResults,
+    MetadataGroupSearchResponse, PeopleSearchResponse, PersonDetails, SearchResults,
+};
+use media_models::{
+    MetadataDetails, MetadataLocalizedDetails, MetadataSearchItem, PartialMetadataWithoutId,
 };
-use media_models::{MetadataDetails, MetadataSearchItem, PartialMetadataWithoutId};
 use sea_orm::DatabaseConnection;
 
 #[async_trait]
@@ -33,6 +35,16 @@ pub trait MediaProvider {
         bail!("This provider does not support getting media details")
     }
 
+    /// Get the title and description of a media item in the given languages.
+    #[allow(unused_variables)]
+    async fn metadata_localized_details(
+        &self,
+        identifier: &str,
+        languages: &[String],
+    ) -> Result<Vec<MetadataLocalizedDetails>> {
+        Ok(vec![])
+    }
+
     /// Search for people via a query.
     #[allow(unused_variables)]
     async fn people_search(
@@ -19,6 +19,13 @@ use reqwest::{
 };
 use sea_orm::Order;
 
+mod provider_http;
+
+pub use provider_http::{
+    get_provider_http_client, remove_expired_provider_responses, set_provider_http_config,
+    CacheableProviderRequest,
+};
+
 pub fn user_id_from_token(token: &str, jwt_secret: &str) -> Result<String> {
     jwt_service::verify(token, jwt_secret)
         .map(|c| c.sub)
@@ -0,0 +1,366 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use common_utils::{ryot_log, TEMP_DIR};
+use data_encoding::BASE64;
+use enum_models::MediaSource;
+use http::Extensions;
+use reqwest::{
+    header::{HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER},
+    Method, Request, Response, StatusCode,
+};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::get_base_http_client;
+
+const PROVIDER_CACHE_DIR: &str = "provider-cache";
+const MAX_PROVIDER_RETRY_DELAY_SECONDS: u64 = 60;
+
+static PROVIDER_HTTP_CONFIG: OnceLock<config::ProviderHttpConfig> = OnceLock::new();
+static PROVIDER_RATE_LIMITERS: LazyLock<Mutex<HashMap<MediaSource, TokenBucket>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Set the configuration used by all provider clients. This should be called once
+/// during startup, before any provider is constructed.
+pub fn set_provider_http_config(config: config::ProviderHttpConfig) {
+    PROVIDER_HTTP_CONFIG.set(config).ok();
+}
+
+/// Marks a `GET` or read-only `POST` request to a provider as cacheable, so that its
+/// successful response is cached on disk. Only searches and lookups of identifiers
+/// should be marked, details are always fetched again so that refreshes and
+/// monitoring see the latest data.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheableProviderRequest;
+
+/// Get a client for making requests to a provider. All requests made with it share
+/// the rate limit of the source, are retried when rate limited or when the provider
+/// fails. Successful responses to requests marked with [`CacheableProviderRequest`]
+/// are cached on disk.
+pub fn get_provider_http_client(
+    source: MediaSource,
+    headers: Option<Vec<(HeaderName, HeaderValue)>>,
+) -> ClientWithMiddleware {
+    // The default headers are not part of the requests seen by the middleware, so
+    // they are added to the cache key separately.
+    let mut hasher = DefaultHasher::new();
+    hash_headers(
+        headers
+            .iter()
+            .flatten()
+            .map(|(name, value)| (name.as_str(), value.as_bytes())),
+        &mut hasher,
+    );
+    ClientBuilder::new(get_base_http_client(headers))
+        .with(ProviderHttpMiddleware {
+            source,
+            default_headers_hash: hasher.finish(),
+        })
+        .build()
+}
+
+/// Remove cached provider responses that have expired and return how many were
+/// removed.
+pub async fn remove_expired_provider_responses() -> usize {
+    let Ok(mut entries) = tokio::fs::read_dir(get_provider_cache_dir()).await else {
+        return 0;
+    };
+    let mut removed = 0;
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let is_valid = tokio::fs::read_to_string(entry.path())
+            .await
+            .ok()
+            .and_then(|data| serde_json::from_str::<CachedProviderResponse>(&data).ok())
+            .is_some_and(|cached| cached.expires_at > Utc::now());
+        if !is_valid && tokio::fs::remove_file(entry.path()).await.is_ok() {
+            removed += 1;
+        }
+    }
+    removed
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ProviderHttpSettings {
+    burst: f64,
+    max_retries: u32,
+    cache_ttl_seconds: u64,
+    requests_per_second: f64,
+}
+
+fn get_provider_http_settings(source: MediaSource) -> ProviderHttpSettings {
+    // Conservative defaults based on the documented limits of each API.
+    let (requests_per_second, burst) = match source {
+        MediaSource::Tmdb => (40.0, 40),
+        MediaSource::Igdb => (4.0, 4),
+        MediaSource::Vndb => (0.6, 5),
+        MediaSource::Itunes => (0.3, 5),
+        MediaSource::Anilist => (0.5, 5),
+        MediaSource::BoardGameGeek => (0.2, 2),
+        MediaSource::MusicBrainz => (1.0, 1),
+        MediaSource::Mal
+        | MediaSource::Rawg
+        | MediaSource::ComicVine
+        | MediaSource::Openlibrary
+        | MediaSource::MangaUpdates => (1.0, 5),
+        MediaSource::PodcastIndex => (2.0, 5),
+        MediaSource::Custom
+        | MediaSource::Rss
+        | MediaSource::Tvdb
+        | MediaSource::Remote
+        | MediaSource::Audible
+        | MediaSource::Hardcover
+        | MediaSource::GoogleBooks
+        | MediaSource::Listennotes
+        | MediaSource::YoutubeMusic => (5.0, 10),
+    };
+    let config = PROVIDER_HTTP_CONFIG.get().cloned().unwrap_or_default();
+    let source_config = config.overrides.iter().find(|o| o.source == source);
+    ProviderHttpSettings {
+        burst: source_config
+            .and_then(|o| o.burst)
+            .unwrap_or(burst)
+            .max(1)
+            .into(),
+        max_retries: source_config
+            .and_then(|o| o.max_retries)
+            .unwrap_or(config.max_retries),
+        cache_ttl_seconds: source_config.and_then(|o| o.cache_ttl_seconds).unwrap_or(
+            match source {
+                // Feeds are polled to find new episodes, so they should always be fresh.
+                MediaSource::Rss => 0,
+                _ => config.cache_ttl_seconds,
+            },
+        ),
+        requests_per_second: source_config
+            .and_then(|o| o.requests_per_second)
+            .unwrap_or(requests_per_second),
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+async fn acquire_provider_token(source: MediaSource, settings: &ProviderHttpSettings) {
+    if settings.requests_per_second <= 0.0 {
+        return;
+    }
+    loop {
+        let wait = {
+            // The buckets are always left in a valid state, so a panic while holding the
+            // lock can be ignored.
+            let mut limiters = PROVIDER_RATE_LIMITERS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let bucket = limiters.entry(source).or_insert_with(|| TokenBucket {
+                tokens: settings.burst,
+                updated_at: Instant::now(),
+            });
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+            bucket.tokens =
+                (bucket.tokens + elapsed * settings.requests_per_second).min(settings.burst);
+            bucket.updated_at = now;
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                return;
+            }
+            Duration::from_secs_f64((1.0 - bucket.tokens) / settings.requests_per_second)
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+fn get_retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedProviderResponse {
+    status: u16,
+    /// The body encoded as base64, since it is not necessarily valid UTF-8.
+    body: String,
+    expires_at: DateTime<Utc>,
+    content_type: Option<String>,
+}
+
+impl CachedProviderResponse {
+    fn into_response(self) -> Option<Response> {
+        let body = BASE64.decode(self.body.as_bytes()).ok()?;
+        Some(build_provider_response(
+            self.status,
+            self.content_type,
+            body,
+        ))
+    }
+}
+
+fn build_provider_response(status: u16, content_type: Option<String>, body: Vec<u8>) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+    if let Some(value) = content_type.and_then(|c| HeaderValue::from_str(&c).ok()) {
+        response.headers_mut().insert(CONTENT_TYPE, value);
+    }
+    Response::from(response)
+}
+
+fn get_provider_cache_dir() -> PathBuf {
+    PathBuf::from(TEMP_DIR).join(PROVIDER_CACHE_DIR)
+}
+
+fn hash_headers<'a>(headers: impl Iterator<Item = (&'a str, &'a [u8])>, hasher: &mut impl Hasher) {
+    let mut headers = headers.collect::<Vec<_>>();
+    headers.sort_unstable();
+    headers.hash(hasher);
+}
+
+fn get_provider_cache_path(
+    source: MediaSource,
+    default_headers_hash: u64,
+    request: &Request,
+    extensions: &Extensions,
+) -> Option<PathBuf> {
+    let is_cacheable = matches!(*request.method(), Method::GET | Method::POST)
+        && extensions.get::<CacheableProviderRequest>().is_some();
+    if !is_cacheable {
+        return None;
+    }
+    // Streaming bodies can not be inspected, so those requests are never cached.
+    let body = match request.body() {
+        None => &[][..],
+        Some(body) => body.as_bytes()?,
+    };
+    let mut hasher = DefaultHasher::new();
+    request.method().as_str().hash(&mut hasher);
+    request.url().as_str().hash(&mut hasher);
+    body.hash(&mut hasher);
+    // All headers are part of the key so that responses are never shared between
+    // requests made with different credentials.
+    hash_headers(
+        request
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_bytes())),
+        &mut hasher,
+    );
+    default_headers_hash.hash(&mut hasher);
+    let name = format!("{:?}-{:x}.json", source, hasher.finish()).to_lowercase();
+    Some(get_provider_cache_dir().join(name))
+}
+
+async fn get_cached_provider_response(path: &Path) -> Option<Response> {
+    let data = tokio::fs::read_to_string(path).await.ok()?;
+    let cached = serde_json::from_str::<CachedProviderResponse>(&data).ok()?;
+    if cached.expires_at <= Utc::now() {
+        tokio::fs::remove_file(path).await.ok();
+        return None;
+    }
+    cached.into_response()
+}
+
+async fn cache_provider_response(
+    path: &Path,
+    response: Response,
+    cache_ttl_seconds: u64,
+) -> Result<Response> {
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let body = response.bytes().await?.to_vec();
+    let cached = CachedProviderResponse {
+        status,
+        body: BASE64.encode(&body),
+        content_type: content_type.clone(),
+        expires_at: Utc::now() + ChronoDuration::seconds(cache_ttl_seconds as i64),
+    };
+    if let Ok(data) = serde_json::to_string(&cached) {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.ok();
+        }
+        tokio::fs::write(path, data).await.ok();
+    }
+    Ok(build_provider_response(status, content_type, body))
+}
+
+struct ProviderHttpMiddleware {
+    source: MediaSource,
+    default_headers_hash: u64,
+}
+
+#[async_trait]
+impl Middleware for ProviderHttpMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let settings = get_provider_http_settings(self.source);
+        let cache_path = match settings.cache_ttl_seconds {
+            0 => None,
+            _ => get_provider_cache_path(self.source, self.default_headers_hash, &req, extensions),
+        };
+        if let Some(path) = &cache_path {
+            if let Some(response) = get_cached_provider_response(path).await {
+                return Ok(response);
+            }
+        }
+        let mut attempt = 0;
+        let response = loop {
+            acquire_provider_token(self.source, &settings).await;
+            let Some(request) = req.try_clone() else {
+                break next.run(req, extensions).await?;
+            };
+            let result = next.clone().run(request, extensions).await;
+            let should_retry = match &result {
+                Ok(r) => {
+                    r.status() == StatusCode::TOO_MANY_REQUESTS || r.status().is_server_error()
+                }
+                Err(reqwest_middleware::Error::Reqwest(e)) => e.is_timeout() || e.is_connect(),
+                Err(_) => false,
+            };
+            if !should_retry || attempt >= settings.max_retries {
+                break result?;
+            }
+            attempt += 1;
+            let delay = result
+                .as_ref()
+                .ok()
+                .and_then(get_retry_after)
+                .unwrap_or_else(|| Duration::from_secs(2_u64.pow(attempt - 1)))
+                .min(Duration::from_secs(MAX_PROVIDER_RETRY_DELAY_SECONDS));
+            ryot_log!(
+                debug,
+                "Retrying request to {:?} in {:?} (attempt {})",
+                self.source,
+                delay,
+                attempt
+            );
+            tokio::time::sleep(delay).await;
+        };
+        match cache_path {
+            Some(path) if response.status().is_success() => {
+                cache_provider_response(&path, response, settings.cache_ttl_seconds).await
+            }
+            _ => Ok(response),
+        }
+    }
+}
@@ -1,5 +1,15 @@
-use std::{convert::TryInto, fmt};
+use std::{
+    convert::TryInto,
+    fmt,
+    io::{ErrorKind, Read, Write},
+};
 
+use anyhow::{anyhow, bail, Result as AnyhowResult};
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::stream::{DecryptorBE32, EncryptorBE32},
+    ChaCha20Poly1305, KeyInit,
+};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use enum_models::{ExerciseLot, MediaLot, MediaSource, WorkoutSetPersonalBest};
 use env_utils::APP_VERSION;
@@ -61,10 +71,18 @@ pub const METADATA_LOT_MAPPINGS: &[(MediaLot, &[MediaSource])] = &[
     ),
     (
         MediaLot::Podcast,
-        &[MediaSource::Itunes, MediaSource::Listennotes],
+        &[
+            MediaSource::Itunes,
+            MediaSource::Listennotes,
+            MediaSource::PodcastIndex,
+            MediaSource::Rss,
+        ],
+    ),
+    (MediaLot::VideoGame, &[MediaSource::Igdb, MediaSource::Rawg]),
+    (
+        MediaLot::Anime,
+        &[MediaSource::Anilist, MediaSource::Mal, MediaSource::Tvdb],
     ),
-    (MediaLot::VideoGame, &[MediaSource::Igdb]),
-    (MediaLot::Anime, &[MediaSource::Anilist, MediaSource::Mal]),
     (
         MediaLot::Manga,
         &[
@@ -74,13 +92,19 @@ pub const METADATA_LOT_MAPPINGS: &[(MediaLot, &[MediaSource])] = &[
         ],
     ),
     (MediaLot::Movie, &[MediaSource::Tmdb]),
-    (MediaLot::Music, &[MediaSource::YoutubeMusic]),
-    (MediaLot::Show, &[MediaSource::Tmdb]),
+    (
+        MediaLot::Music,
+        &[MediaSource::YoutubeMusic, MediaSource::MusicBrainz],
+    ),
+    (MediaLot::Show, &[MediaSource::Tmdb, MediaSource::Tvdb]),
     (MediaLot::VisualNovel, &[MediaSource::Vndb]),
+    (MediaLot::Comic, &[MediaSource::ComicVine]),
+    (MediaLot::BoardGame, &[MediaSource::BoardGameGeek]),
 ];
 
-pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 9] = [
+pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 12] = [
     MediaSource::Tmdb,
+    MediaSource::Tvdb,
     MediaSource::Anilist,
     MediaSource::Vndb,
     MediaSource::Openlibrary,
@@ -88,14 +112,20 @@ pub const PEOPLE_SEARCH_SOURCES: [MediaSource; 9] = [
     MediaSource::MangaUpdates,
     MediaSource::Igdb,
     MediaSource::YoutubeMusic,
+    MediaSource::MusicBrainz,
+    MediaSource::ComicVine,
     MediaSource::Hardcover,
 ];
 
 pub const METADATA_GROUP_SOURCE_LOT_MAPPINGS: &[(MediaSource, MediaLot)] = &[
     (MediaSource::Tmdb, MediaLot::Movie),
     (MediaSource::Igdb, MediaLot::VideoGame),
+    (MediaSource::Rawg, MediaLot::VideoGame),
     (MediaSource::Hardcover, MediaLot::Book),
     (MediaSource::YoutubeMusic, MediaLot::Music),
+    (MediaSource::MusicBrainz, MediaLot::Music),
+    (MediaSource::ComicVine, MediaLot::Comic),
+    (MediaSource::BoardGameGeek, MediaLot::BoardGame),
 ];
 
 pub fn get_first_and_last_day_of_month(year: i32, month: u32) -> (NaiveDate, NaiveDate) {
@@ -161,6 +191,114 @@ pub async fn sleep_for_n_seconds(sec: u64) {
     sleep(Duration::from_secs(sec)).await;
 }
 
+/// The header that identifies files encrypted by `encrypt_with_passphrase`.
+const ENCRYPTED_FILE_MAGIC: &[u8] = b"RYOTENC1";
+const ENCRYPTION_SALT_LENGTH: usize = 16;
+// The STREAM construction uses the last 5 bytes of the 12 byte nonce for its counter.
+const ENCRYPTION_NONCE_LENGTH: usize = 7;
+const ENCRYPTION_CHUNK_LENGTH: usize = 64 * 1024;
+const ENCRYPTION_TAG_LENGTH: usize = 16;
+
+fn cipher_for_passphrase(passphrase: &str, salt: &[u8]) -> AnyhowResult<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Could not derive key from passphrase: {e}"))?;
+    Ok(ChaCha20Poly1305::new(&key.into()))
+}
+
+/// Read from the reader until the buffer is full or the end of the input is reached.
+fn read_chunk(reader: &mut impl Read, buffer: &mut [u8]) -> AnyhowResult<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(filled)
+}
+
+pub fn is_encrypted_with_passphrase(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_FILE_MAGIC)
+}
+
+/// Encrypt the input with a key derived from the passphrase using Argon2. The output is
+/// laid out as `magic | salt | nonce | chunks` so that it can be decrypted with only the
+/// passphrase. The input is encrypted in chunks so that it never has to be held in
+/// memory as a whole.
+pub fn encrypt_with_passphrase(
+    mut reader: impl Read,
+    mut writer: impl Write,
+    passphrase: &str,
+) -> AnyhowResult<()> {
+    let salt = rand::random::<[u8; ENCRYPTION_SALT_LENGTH]>();
+    let nonce = rand::random::<[u8; ENCRYPTION_NONCE_LENGTH]>();
+    let cipher = cipher_for_passphrase(passphrase, &salt)?;
+    let mut encryptor = EncryptorBE32::from_aead(cipher, (&nonce).into());
+    writer.write_all(ENCRYPTED_FILE_MAGIC)?;
+    writer.write_all(&salt)?;
+    writer.write_all(&nonce)?;
+    let mut buffer = vec![0u8; ENCRYPTION_CHUNK_LENGTH];
+    loop {
+        let read = read_chunk(&mut reader, &mut buffer)?;
+        // Only the last chunk is shorter than the chunk length, even if it is empty
+        if read < ENCRYPTION_CHUNK_LENGTH {
+            let chunk = encryptor
+                .encrypt_last(&buffer[..read])
+                .map_err(|_| anyhow!("Could not encrypt data"))?;
+            writer.write_all(&chunk)?;
+            break;
+        }
+        let chunk = encryptor
+            .encrypt_next(buffer.as_slice())
+            .map_err(|_| anyhow!("Could not encrypt data"))?;
+        writer.write_all(&chunk)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Decrypt the input written by `encrypt_with_passphrase`.
+pub fn decrypt_with_passphrase(
+    mut reader: impl Read,
+    mut writer: impl Write,
+    passphrase: &str,
+) -> AnyhowResult<()> {
+    let mut header = [0u8; ENCRYPTED_FILE_MAGIC.len()];
+    if read_chunk(&mut reader, &mut header)? < header.len() || header != ENCRYPTED_FILE_MAGIC {
+        bail!("Data was not encrypted with a passphrase");
+    }
+    let mut salt = [0u8; ENCRYPTION_SALT_LENGTH];
+    let mut nonce = [0u8; ENCRYPTION_NONCE_LENGTH];
+    if read_chunk(&mut reader, &mut salt)? < salt.len()
+        || read_chunk(&mut reader, &mut nonce)? < nonce.len()
+    {
+        bail!("Encrypted data is truncated");
+    }
+    let cipher = cipher_for_passphrase(passphrase, &salt)?;
+    let mut decryptor = DecryptorBE32::from_aead(cipher, (&nonce).into());
+    let mut buffer = vec![0u8; ENCRYPTION_CHUNK_LENGTH + ENCRYPTION_TAG_LENGTH];
+    loop {
+        let read = read_chunk(&mut reader, &mut buffer)?;
+        if read < buffer.len() {
+            let chunk = decryptor
+                .decrypt_last(&buffer[..read])
+                .map_err(|_| anyhow!("Incorrect passphrase or corrupted data"))?;
+            writer.write_all(&chunk)?;
+            break;
+        }
+        let chunk = decryptor
+            .decrypt_next(buffer.as_slice())
+            .map_err(|_| anyhow!("Incorrect passphrase or corrupted data"))?;
+        writer.write_all(&chunk)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
 #[macro_export]
 macro_rules! ryot_log {
     (info, $($arg:tt)*) => {
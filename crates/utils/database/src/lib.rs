@@ -26,10 +26,11 @@ use itertools::Itertools;
 use jwt_service::{verify, Claims};
 use markdown::to_html as markdown_to_html;
 use media_models::{
-    AnimeSpecifics, AudioBookSpecifics, BookSpecifics, MangaSpecifics, MovieSpecifics,
-    MusicSpecifics, PodcastSpecifics, ReviewItem, SeenAnimeExtraInformation,
-    SeenMangaExtraInformation, SeenPodcastExtraInformation, SeenShowExtraInformation,
-    ShowSpecifics, VideoGameSpecifics, VisualNovelSpecifics,
+    AnimeSpecifics, AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, ComicSpecifics,
+    MangaSpecifics, MovieSpecifics, MusicSpecifics, PodcastSpecifics, ReviewItem,
+    SeenAnimeExtraInformation, SeenBoardGameExtraInformation, SeenMangaExtraInformation,
+    SeenPodcastExtraInformation, SeenShowExtraInformation, ShowSpecifics, VideoGameSpecifics,
+    VisualNovelSpecifics,
 };
 use migrations::AliasedCollectionToEntity;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
@@ -387,6 +388,7 @@ pub async fn calculate_user_activities_and_summary(
         podcast_extra_information: Option<SeenPodcastExtraInformation>,
         anime_extra_information: Option<SeenAnimeExtraInformation>,
         manga_extra_information: Option<SeenMangaExtraInformation>,
+        board_game_extra_information: Option<SeenBoardGameExtraInformation>,
         metadata_id: String,
         finished_on: Option<Date>,
         last_updated_on: DateTimeUtc,
@@ -402,6 +404,8 @@ pub async fn calculate_user_activities_and_summary(
         visual_novel_specifics: Option<VisualNovelSpecifics>,
         anime_specifics: Option<AnimeSpecifics>,
         manga_specifics: Option<MangaSpecifics>,
+        comic_specifics: Option<ComicSpecifics>,
+        board_game_specifics: Option<BoardGameSpecifics>,
     }
 
     let start_from = match calculate_from_beginning {
@@ -483,6 +487,7 @@ pub async fn calculate_user_activities_and_summary(
             seen::Column::PodcastExtraInformation,
             seen::Column::AnimeExtraInformation,
             seen::Column::MangaExtraInformation,
+            seen::Column::BoardGameExtraInformation,
             seen::Column::MetadataId,
             seen::Column::FinishedOn,
             seen::Column::LastUpdatedOn,
@@ -500,6 +505,8 @@ pub async fn calculate_user_activities_and_summary(
             metadata::Column::VisualNovelSpecifics,
             metadata::Column::AnimeSpecifics,
             metadata::Column::MangaSpecifics,
+            metadata::Column::ComicSpecifics,
+            metadata::Column::BoardGameSpecifics,
         ])
         .into_model::<SeenItem>()
         .stream(db)
@@ -553,6 +560,14 @@ pub async fn calculate_user_activities_and_summary(
             if let Some(runtime) = visual_novel_extra.length {
                 activity.visual_novel_duration += runtime;
             }
+        } else if let Some(board_game_extra) = seen.board_game_specifics {
+            if let Some(duration) = seen
+                .board_game_extra_information
+                .and_then(|e| e.duration)
+                .or(board_game_extra.play_time)
+            {
+                activity.board_game_duration += duration;
+            }
         } else if let Some(_video_game_extra) = seen.video_game_specifics {
             if let Some(manual_time_spent) = seen.manual_time_spent {
                 activity.video_game_duration +=
@@ -566,6 +581,8 @@ pub async fn calculate_user_activities_and_summary(
             MediaLot::Anime => activity.anime_count += 1,
             MediaLot::Movie => activity.movie_count += 1,
             MediaLot::Manga => activity.manga_count += 1,
+            MediaLot::Comic => activity.comic_count += 1,
+            MediaLot::BoardGame => activity.board_game_count += 1,
             MediaLot::Podcast => activity.podcast_count += 1,
             MediaLot::VideoGame => activity.video_game_count += 1,
             MediaLot::AudioBook => activity.audio_book_count += 1,
@@ -692,6 +709,8 @@ pub async fn calculate_user_activities_and_summary(
             + activity.podcast_count
             + activity.anime_count
             + activity.manga_count
+            + activity.comic_count
+            + activity.board_game_count
             + activity.music_count
             + activity.audio_book_count
             + activity.book_count
@@ -708,7 +727,8 @@ pub async fn calculate_user_activities_and_summary(
             + activity.show_duration
             + activity.music_duration
             + activity.visual_novel_duration
-            + activity.video_game_duration;
+            + activity.video_game_duration
+            + activity.board_game_duration;
         activity.hour_records.sort_by_key(|hr| hr.hour);
         let mut model: daily_user_activity::ActiveModel = activity.clone().into();
         model.id = ActiveValue::NotSet;
@@ -1,4 +1,10 @@
-use std::{cmp::Reverse, collections::HashMap, future::Future, iter::zip, sync::Arc};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    iter::zip,
+    sync::Arc,
+};
 
 use application_utils::{get_current_date, graphql_to_db_order};
 use async_graphql::{Enum, Error, Result};
@@ -7,11 +13,12 @@ use chrono::Utc;
 use common_models::{
     ApplicationCacheKey, BackgroundJob, ChangeCollectionToEntityInput, DefaultCollection,
     MetadataRecentlyConsumedCacheInput, ProgressUpdateCacheInput, SearchDetails, SearchInput,
-    StoredUrl, StringIdObject, UserLevelCacheKey, UserNotificationContent,
+    StoredUrl, StringIdObject, UserLevelCacheKey, UserNotificationContent, UserNotificationPayload,
 };
 use common_utils::{
     acquire_lock, ryot_log, sleep_for_n_seconds, EXERCISE_LOT_MAPPINGS,
-    MAX_IMPORT_RETRIES_FOR_PARTIAL_STATE, PAGE_SIZE, SHOW_SPECIAL_SEASON_NAMES,
+    MAX_IMPORT_RETRIES_FOR_PARTIAL_STATE, METADATA_LOT_MAPPINGS, PAGE_SIZE,
+    SHOW_SPECIAL_SEASON_NAMES,
 };
 use database_models::{
     collection, collection_to_entity, exercise,
@@ -20,10 +27,11 @@ use database_models::{
     metadata_to_metadata_group, metadata_to_person, monitored_entity, person,
     prelude::{
         Collection, CollectionToEntity, Exercise, Genre, Metadata, MetadataGroup, MetadataToGenre,
-        MetadataToMetadata, MetadataToPerson, MonitoredEntity, Person, Seen, UserToEntity, Workout,
-        WorkoutTemplate,
+        MetadataToMetadata, MetadataToPerson, MonitoredEntity, Person, Seen, User, UserToEntity,
+        Workout, WorkoutTemplate,
     },
-    review, seen, user_measurement, user_notification, user_to_entity, workout, workout_template,
+    review, seen, user, user_measurement, user_notification, user_to_entity, workout,
+    workout_template,
 };
 use database_utils::{
     admin_account_guard, apply_collection_filter, get_cte_column_from_lot, ilike_sql,
@@ -51,18 +59,22 @@ use itertools::Itertools;
 use media_models::{
     CommitMediaInput, CommitPersonInput, CreateOrUpdateCollectionInput, CreateOrUpdateReviewInput,
     ImportOrExportItemRating, MediaGeneralFilter, MediaSortBy, MetadataDetails,
-    MetadataGroupsListInput, MetadataImage, MetadataListInput, PartialMetadata,
-    PartialMetadataPerson, PartialMetadataWithoutId, PeopleListInput,
-    PersonAndMetadataGroupsSortBy, ProgressUpdateError, ProgressUpdateErrorVariant,
-    ProgressUpdateInput, ProgressUpdateResultUnion, ReviewPostedEvent, SeenAnimeExtraInformation,
-    SeenMangaExtraInformation, SeenPodcastExtraInformation, SeenPodcastExtraOptionalInformation,
-    SeenShowExtraInformation, SeenShowExtraOptionalInformation, UniqueMediaIdentifier,
+    MetadataExternalIdentifiers, MetadataGroupsListInput, MetadataImage, MetadataLinkedSource,
+    MetadataListInput, PartialMetadata, PartialMetadataPerson, PartialMetadataWithoutId,
+    PeopleListInput, PersonAndMetadataGroupsSortBy, ProgressUpdateError,
+    ProgressUpdateErrorVariant, ProgressUpdateInput, ProgressUpdateResultUnion, ReviewPostedEvent,
+    SeenAnimeExtraInformation, SeenBoardGameExtraInformation, SeenMangaExtraInformation,
+    SeenPodcastExtraInformation, SeenPodcastExtraOptionalInformation, SeenShowExtraInformation,
+    SeenShowExtraOptionalInformation, ShowEpisode, ShowSeason, ShowSpecifics,
+    UniqueMediaIdentifier,
 };
 use migrations::{AliasedExercise, AliasedReview};
 use nanoid::nanoid;
 use providers::{
     anilist::{AnilistAnimeService, AnilistMangaService},
     audible::AudibleService,
+    board_game_geek::BoardGameGeekService,
+    comic_vine::ComicVineService,
     google_books::GoogleBooksService,
     hardcover::HardcoverService,
     igdb::IgdbService,
@@ -70,8 +82,14 @@ use providers::{
     listennotes::ListennotesService,
     mal::{MalAnimeService, MalMangaService},
     manga_updates::MangaUpdatesService,
+    music_brainz::MusicBrainzService,
     openlibrary::OpenlibraryService,
+    podcast_index::PodcastIndexService,
+    rawg::RawgService,
+    remote::RemoteService,
+    rss::RssService,
     tmdb::{NonMediaTmdbService, TmdbMovieService, TmdbShowService},
+    tvdb::{TvdbAnimeService, TvdbShowService},
     vndb::VndbService,
     youtube_music::YoutubeMusicService,
 };
@@ -92,7 +110,7 @@ use serde::{Deserialize, Serialize};
 use slug::slugify;
 use supporting_service::SupportingService;
 use traits::{MediaProvider, TraceOk};
-use user_models::{UserPreferences, UserReviewScale};
+use user_models::{ShowEpisodeOrdering, UserPreferences, UserReviewScale};
 use uuid::Uuid;
 
 pub type Provider = Box<(dyn MediaProvider + Send + Sync)>;
@@ -151,18 +169,35 @@ pub async fn get_metadata_provider(
     let err = || Err(Error::new("This source is not supported".to_owned()));
     let service: Provider = match source {
         MediaSource::YoutubeMusic => Box::new(YoutubeMusicService::new().await),
+        MediaSource::MusicBrainz => Box::new(MusicBrainzService::new().await),
         MediaSource::Hardcover => Box::new(get_hardcover_service(&ss.config).await?),
+        MediaSource::ComicVine => {
+            Box::new(ComicVineService::new(&ss.config.comics.comic_vine).await)
+        }
+        MediaSource::BoardGameGeek => {
+            Box::new(BoardGameGeekService::new(&ss.config.board_games.board_game_geek).await)
+        }
         MediaSource::Vndb => Box::new(VndbService::new(&ss.config.visual_novels).await),
         MediaSource::Openlibrary => Box::new(get_openlibrary_service(&ss.config).await?),
         MediaSource::Itunes => Box::new(ITunesService::new(&ss.config.podcasts.itunes).await),
         MediaSource::GoogleBooks => Box::new(get_google_books_service(&ss.config).await?),
         MediaSource::Audible => Box::new(AudibleService::new(&ss.config.audio_books.audible).await),
         MediaSource::Listennotes => Box::new(ListennotesService::new(ss.clone()).await),
+        MediaSource::PodcastIndex => {
+            Box::new(PodcastIndexService::new(&ss.config.podcasts.podcast_index).await)
+        }
+        MediaSource::Rss => Box::new(RssService::new().await),
+        MediaSource::Rawg => Box::new(RawgService::new(&ss.config.video_games.rawg).await),
         MediaSource::Tmdb => match lot {
             MediaLot::Show => Box::new(TmdbShowService::new(ss.clone()).await),
             MediaLot::Movie => Box::new(TmdbMovieService::new(ss.clone()).await),
             _ => return err(),
         },
+        MediaSource::Tvdb => match lot {
+            MediaLot::Show => Box::new(TvdbShowService::new(ss.clone()).await?),
+            MediaLot::Anime => Box::new(TvdbAnimeService::new(ss.clone()).await?),
+            _ => return err(),
+        },
         MediaSource::Anilist => match lot {
             MediaLot::Anime => {
                 Box::new(AnilistAnimeService::new(&ss.config.anime_and_manga.anilist).await)
@@ -181,6 +216,10 @@ pub async fn get_metadata_provider(
         MediaSource::MangaUpdates => {
             Box::new(MangaUpdatesService::new(&ss.config.anime_and_manga.manga_updates).await)
         }
+        MediaSource::Remote => match ss.config.media.remote_provider_for_lot(lot) {
+            Some(_) => Box::new(RemoteService::new(&ss.config.media, lot).await),
+            None => return err(),
+        },
         MediaSource::Custom => return err(),
     };
     Ok(service)
@@ -370,8 +409,299 @@ pub async fn change_metadata_associations(
     Ok(())
 }
 
+fn is_source_supported_for_lot(lot: MediaLot, source: MediaSource) -> bool {
+    METADATA_LOT_MAPPINGS
+        .iter()
+        .any(|(l, sources)| *l == lot && sources.contains(&source))
+}
+
+/// Find the equivalent items of a metadata on other providers using the external
+/// identifiers returned by its primary provider. Links found earlier are retained
+/// unless the provider returned a newer one.
+async fn get_linked_sources(
+    lot: MediaLot,
+    source: MediaSource,
+    identifiers: &MetadataExternalIdentifiers,
+    ss: &Arc<SupportingService>,
+) -> Vec<MetadataLinkedSource> {
+    let mut linked_sources = vec![];
+    if let Some(tmdb_id) = identifiers.tmdb_id {
+        linked_sources.push(MetadataLinkedSource {
+            source: MediaSource::Tmdb,
+            identifier: tmdb_id.to_string(),
+        });
+    }
+    if let Some(tvdb_id) = identifiers.tvdb_id {
+        linked_sources.push(MetadataLinkedSource {
+            source: MediaSource::Tvdb,
+            identifier: tvdb_id.to_string(),
+        });
+    }
+    linked_sources.extend(identifiers.linked_sources.clone());
+    if let Some(isbn) = identifiers.isbns.first().filter(|_| lot == MediaLot::Book) {
+        let is_linked =
+            |s: MediaSource| s == source || linked_sources.iter().any(|l| l.source == s);
+        let mut found = vec![];
+        if !is_linked(MediaSource::Hardcover) {
+            if let Ok(service) = get_hardcover_service(&ss.config).await {
+                found.extend(
+                    service
+                        .id_from_isbn(isbn)
+                        .await
+                        .map(|i| (MediaSource::Hardcover, i)),
+                );
+            }
+        }
+        if !is_linked(MediaSource::GoogleBooks) {
+            if let Ok(service) = get_google_books_service(&ss.config).await {
+                found.extend(
+                    service
+                        .id_from_isbn(isbn)
+                        .await
+                        .map(|i| (MediaSource::GoogleBooks, i)),
+                );
+            }
+        }
+        if !is_linked(MediaSource::Openlibrary) {
+            if let Ok(service) = get_openlibrary_service(&ss.config).await {
+                found.extend(
+                    service
+                        .id_from_isbn(isbn)
+                        .await
+                        .map(|i| (MediaSource::Openlibrary, i)),
+                );
+            }
+        }
+        linked_sources.extend(
+            found
+                .into_iter()
+                .map(|(source, identifier)| MetadataLinkedSource { source, identifier }),
+        );
+    }
+    linked_sources
+        .into_iter()
+        .filter(|l| l.source != source && is_source_supported_for_lot(lot, l.source))
+        .unique_by(|l| l.source)
+        .collect()
+}
+
+/// The fields of a metadata that can be filled from a linked provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinkableMetadataField {
+    Genres,
+    Images,
+    Description,
+    PublishYear,
+    BookPages,
+    MovieRuntime,
+    AnimeEpisodes,
+    MangaChapters,
+}
+
+fn get_missing_metadata_fields(details: &MetadataDetails) -> Vec<LinkableMetadataField> {
+    [
+        (
+            LinkableMetadataField::Description,
+            details.description.is_none(),
+        ),
+        (
+            LinkableMetadataField::PublishYear,
+            details.publish_year.is_none(),
+        ),
+        (LinkableMetadataField::Genres, details.genres.is_empty()),
+        (
+            LinkableMetadataField::Images,
+            details.url_images.is_empty() && details.s3_images.is_empty(),
+        ),
+        (
+            LinkableMetadataField::BookPages,
+            details
+                .book_specifics
+                .as_ref()
+                .is_some_and(|s| s.pages.unwrap_or_default() <= 0),
+        ),
+        (
+            LinkableMetadataField::MovieRuntime,
+            details
+                .movie_specifics
+                .as_ref()
+                .is_some_and(|s| s.runtime.is_none()),
+        ),
+        (
+            LinkableMetadataField::AnimeEpisodes,
+            details
+                .anime_specifics
+                .as_ref()
+                .is_some_and(|s| s.episodes.is_none()),
+        ),
+        (
+            LinkableMetadataField::MangaChapters,
+            details
+                .manga_specifics
+                .as_ref()
+                .is_some_and(|s| s.chapters.is_none()),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, is_missing)| is_missing.then_some(field))
+    .collect()
+}
+
+/// The fields that a provider is known to return, so that linked providers are only
+/// queried when they can fill something that is missing.
+fn get_metadata_fields_provided_by_source(source: MediaSource) -> Vec<LinkableMetadataField> {
+    let mut fields = vec![
+        LinkableMetadataField::Genres,
+        LinkableMetadataField::Images,
+        LinkableMetadataField::Description,
+        LinkableMetadataField::PublishYear,
+    ];
+    fields.extend(match source {
+        MediaSource::Tmdb => vec![LinkableMetadataField::MovieRuntime],
+        MediaSource::Hardcover | MediaSource::GoogleBooks | MediaSource::Openlibrary => {
+            vec![LinkableMetadataField::BookPages]
+        }
+        MediaSource::Anilist | MediaSource::Mal => vec![
+            LinkableMetadataField::AnimeEpisodes,
+            LinkableMetadataField::MangaChapters,
+        ],
+        MediaSource::MangaUpdates => vec![LinkableMetadataField::MangaChapters],
+        _ => vec![],
+    });
+    fields
+}
+
+/// Use the details from a linked provider to fill the fields that the primary
+/// provider did not return.
+fn fill_missing_metadata_details(details: &mut MetadataDetails, linked: MetadataDetails) {
+    details.description = details.description.take().or(linked.description);
+    details.publish_year = details.publish_year.or(linked.publish_year);
+    details.publish_date = details.publish_date.or(linked.publish_date);
+    details.original_language = details
+        .original_language
+        .take()
+        .or(linked.original_language);
+    if details.genres.is_empty() {
+        details.genres = linked.genres;
+    }
+    if details.url_images.is_empty() && details.s3_images.is_empty() {
+        details.url_images = linked.url_images;
+    }
+    if let (Some(s), Some(l)) = (details.book_specifics.as_mut(), linked.book_specifics) {
+        s.pages = s.pages.filter(|p| *p > 0).or(l.pages);
+    }
+    if let (Some(s), Some(l)) = (details.movie_specifics.as_mut(), linked.movie_specifics) {
+        s.runtime = s.runtime.or(l.runtime);
+    }
+    if let (Some(s), Some(l)) = (details.anime_specifics.as_mut(), linked.anime_specifics) {
+        s.episodes = s.episodes.or(l.episodes);
+    }
+    if let (Some(s), Some(l)) = (details.manga_specifics.as_mut(), linked.manga_specifics) {
+        s.chapters = s.chapters.or(l.chapters);
+        s.volumes = s.volumes.or(l.volumes);
+    }
+}
+
+/// The languages that users have chosen to display titles and descriptions in.
+pub async fn get_users_preferred_languages(ss: &Arc<SupportingService>) -> Vec<String> {
+    User::find()
+        .select_only()
+        .column(user::Column::Preferences)
+        .into_tuple::<UserPreferences>()
+        .all(&ss.db)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|p| p.general.language)
+        .unique()
+        .collect()
+}
+
+/// The season and episode numbers of the episodes of a show as pairs of the aired and
+/// the ordered numbers. Specials always keep their aired numbers. `None` if the show is
+/// only available in the aired ordering or the ordering is missing for some episodes.
+pub fn get_show_episode_numbers_in_ordering(
+    specifics: &ShowSpecifics,
+    ordering: ShowEpisodeOrdering,
+) -> Option<HashMap<(i32, i32), (i32, i32)>> {
+    if ordering == ShowEpisodeOrdering::Aired {
+        return None;
+    }
+    let mut numbers = vec![];
+    for season in specifics.seasons.iter() {
+        for episode in season.episodes.iter() {
+            let aired = (season.season_number, episode.episode_number);
+            let ordered = match (ordering, season.season_number) {
+                (ShowEpisodeOrdering::Aired, _) | (_, 0) => aired,
+                (ShowEpisodeOrdering::Dvd, _) => {
+                    (episode.dvd_season_number?, episode.dvd_episode_number?)
+                }
+                (ShowEpisodeOrdering::Absolute, _) => (1, episode.absolute_number?),
+            };
+            numbers.push((aired, ordered));
+        }
+    }
+    let is_unique =
+        numbers.iter().map(|(a, _)| a).all_unique() && numbers.iter().map(|(_, o)| o).all_unique();
+    is_unique.then(|| numbers.into_iter().collect())
+}
+
+/// Group the episodes of a show into seasons using the given ordering. The show is
+/// returned as is if it is not available in that ordering.
+pub fn get_show_specifics_in_ordering(
+    specifics: ShowSpecifics,
+    ordering: ShowEpisodeOrdering,
+) -> ShowSpecifics {
+    let Some(numbers) = get_show_episode_numbers_in_ordering(&specifics, ordering) else {
+        return specifics;
+    };
+    let mut grouped: BTreeMap<i32, Vec<ShowEpisode>> = BTreeMap::new();
+    for season in specifics.seasons.iter() {
+        for episode in season.episodes.iter() {
+            let (season_number, episode_number) =
+                numbers[&(season.season_number, episode.episode_number)];
+            grouped.entry(season_number).or_default().push(ShowEpisode {
+                episode_number,
+                ..episode.clone()
+            });
+        }
+    }
+    let seasons = grouped
+        .into_iter()
+        .map(|(season_number, episodes)| {
+            let aired = specifics
+                .seasons
+                .iter()
+                .find(|s| s.season_number == season_number)
+                .filter(|_| season_number == 0 || ordering != ShowEpisodeOrdering::Absolute);
+            ShowSeason {
+                season_number,
+                id: aired.map(|s| s.id).unwrap_or(season_number),
+                name: match (aired, ordering) {
+                    (Some(aired), _) => aired.name.clone(),
+                    (None, ShowEpisodeOrdering::Absolute) => "Absolute".to_owned(),
+                    (None, _) => format!("Season {}", season_number),
+                },
+                poster_images: aired.map(|s| s.poster_images.clone()).unwrap_or_default(),
+                publish_date: episodes.iter().filter_map(|e| e.publish_date).min(),
+                episodes: episodes
+                    .into_iter()
+                    .sorted_by_key(|e| e.episode_number)
+                    .collect(),
+                ..Default::default()
+            }
+        })
+        .collect_vec();
+    ShowSpecifics {
+        total_seasons: Some(seasons.iter().filter(|s| s.season_number != 0).count()),
+        seasons,
+        ..specifics
+    }
+}
+
 pub async fn update_metadata(
     metadata_id: &String,
+    languages: &[String],
     ss: &Arc<SupportingService>,
 ) -> Result<Vec<(String, UserNotificationContent)>> {
     let metadata = Metadata::find_by_id(metadata_id)
@@ -391,7 +721,7 @@ pub async fn update_metadata(
     let maybe_details =
         details_from_provider(metadata.lot, metadata.source, &metadata.identifier, ss).await;
     let notifications = match maybe_details {
-        Ok(details) => {
+        Ok(mut details) => {
             let mut notifications = vec![];
             let meta = Metadata::find_by_id(metadata_id)
                 .one(&ss.db)
@@ -399,6 +729,48 @@ pub async fn update_metadata(
                 .unwrap()
                 .unwrap();
 
+            let mut external_identifiers = details.external_identifiers.take().unwrap_or_default();
+            external_identifiers.linked_sources = meta
+                .external_identifiers
+                .clone()
+                .map(|e| e.linked_sources)
+                .unwrap_or_default();
+            external_identifiers.linked_sources =
+                get_linked_sources(meta.lot, meta.source, &external_identifiers, ss).await;
+            for linked in external_identifiers.linked_sources.iter() {
+                let missing = get_missing_metadata_fields(&details);
+                if missing.is_empty() {
+                    break;
+                }
+                let provided = get_metadata_fields_provided_by_source(linked.source);
+                if !missing.iter().any(|f| provided.contains(f)) {
+                    continue;
+                }
+                match details_from_provider(meta.lot, linked.source, &linked.identifier, ss).await {
+                    Ok(linked_details) => {
+                        fill_missing_metadata_details(&mut details, linked_details)
+                    }
+                    Err(e) => ryot_log!(
+                        debug,
+                        "Could not get details from linked source {:?}: {:?}",
+                        linked,
+                        e
+                    ),
+                }
+            }
+            details.external_identifiers = Some(external_identifiers);
+
+            let mut localized_details = vec![];
+            if !languages.is_empty() {
+                if let Ok(provider) = get_metadata_provider(meta.lot, meta.source, ss).await {
+                    localized_details = provider
+                        .metadata_localized_details(&meta.identifier, languages)
+                        .await
+                        .trace_ok()
+                        .unwrap_or_default();
+                }
+            }
+
             if let (Some(p1), Some(p2)) = (&meta.production_status, &details.production_status) {
                 if p1 != p2 {
                     notifications.push((
@@ -509,35 +881,81 @@ pub async fn update_metadata(
                 }
             };
             if let (Some(p1), Some(p2)) = (&meta.podcast_specifics, &details.podcast_specifics) {
-                if p1.episodes.len() != p2.episodes.len() {
-                    notifications.push((
-                        format!(
-                            "Number of episodes changed from {:#?} to {:#?}",
-                            p1.episodes.len(),
-                            p2.episodes.len()
-                        ),
-                        UserNotificationContent::MetadataEpisodeReleased,
-                    ));
-                } else {
-                    for (before_episode, after_episode) in
-                        zip(p1.episodes.iter(), p2.episodes.iter())
-                    {
-                        if before_episode.title != after_episode.title {
+                match meta.source {
+                    // These providers have stable episode IDs, so episodes can be compared
+                    // even when older ones are removed from the feed
+                    MediaSource::Rss | MediaSource::PodcastIndex => {
+                        let before_episodes: HashMap<_, _> =
+                            p1.episodes.iter().map(|e| (&e.id, e)).collect();
+                        for after_episode in p2.episodes.iter() {
+                            let Some(before_episode) = before_episodes.get(&after_episode.id)
+                            else {
+                                // Nothing to compare against when the podcast had no episodes yet
+                                if !p1.episodes.is_empty() {
+                                    notifications.push((
+                                        format!(
+                                            "Episode {:#?} has been released (EP{})",
+                                            after_episode.title, after_episode.number
+                                        ),
+                                        UserNotificationContent::MetadataEpisodeReleased,
+                                    ));
+                                }
+                                continue;
+                            };
+                            if before_episode.title != after_episode.title {
+                                notifications.push((
+                                    format!(
+                                        "Episode name changed from {:#?} to {:#?} (EP{})",
+                                        before_episode.title,
+                                        after_episode.title,
+                                        after_episode.number
+                                    ),
+                                    UserNotificationContent::MetadataEpisodeNameChanged,
+                                ));
+                            }
+                            if before_episode.thumbnail != after_episode.thumbnail {
+                                notifications.push((
+                                    format!("Episode image changed for EP{}", after_episode.number),
+                                    UserNotificationContent::MetadataEpisodeImagesChanged,
+                                ));
+                            }
+                        }
+                    }
+                    _ => {
+                        if p1.episodes.len() != p2.episodes.len() {
                             notifications.push((
                                 format!(
-                                    "Episode name changed from {:#?} to {:#?} (EP{})",
-                                    before_episode.title,
-                                    after_episode.title,
-                                    before_episode.number
+                                    "Number of episodes changed from {:#?} to {:#?}",
+                                    p1.episodes.len(),
+                                    p2.episodes.len()
                                 ),
-                                UserNotificationContent::MetadataEpisodeNameChanged,
-                            ));
-                        }
-                        if before_episode.thumbnail != after_episode.thumbnail {
-                            notifications.push((
-                                format!("Episode image changed for EP{}", before_episode.number),
-                                UserNotificationContent::MetadataEpisodeImagesChanged,
+                                UserNotificationContent::MetadataEpisodeReleased,
                             ));
+                        } else {
+                            for (before_episode, after_episode) in
+                                zip(p1.episodes.iter(), p2.episodes.iter())
+                            {
+                                if before_episode.title != after_episode.title {
+                                    notifications.push((
+                                        format!(
+                                            "Episode name changed from {:#?} to {:#?} (EP{})",
+                                            before_episode.title,
+                                            after_episode.title,
+                                            before_episode.number
+                                        ),
+                                        UserNotificationContent::MetadataEpisodeNameChanged,
+                                    ));
+                                }
+                                if before_episode.thumbnail != after_episode.thumbnail {
+                                    notifications.push((
+                                        format!(
+                                            "Episode image changed for EP{}",
+                                            before_episode.number
+                                        ),
+                                        UserNotificationContent::MetadataEpisodeImagesChanged,
+                                    ));
+                                }
+                            }
                         }
                     }
                 }
@@ -588,6 +1006,8 @@ pub async fn update_metadata(
             meta.anime_specifics = ActiveValue::Set(details.anime_specifics);
             meta.audio_book_specifics = ActiveValue::Set(details.audio_book_specifics);
             meta.manga_specifics = ActiveValue::Set(details.manga_specifics);
+            meta.comic_specifics = ActiveValue::Set(details.comic_specifics);
+            meta.board_game_specifics = ActiveValue::Set(details.board_game_specifics);
             meta.movie_specifics = ActiveValue::Set(details.movie_specifics);
             meta.podcast_specifics = ActiveValue::Set(details.podcast_specifics);
             meta.show_specifics = ActiveValue::Set(details.show_specifics);
@@ -596,6 +1016,10 @@ pub async fn update_metadata(
             meta.visual_novel_specifics = ActiveValue::Set(details.visual_novel_specifics);
             meta.music_specifics = ActiveValue::Set(details.music_specifics);
             meta.external_identifiers = ActiveValue::Set(details.external_identifiers);
+            meta.localized_details = ActiveValue::Set(match localized_details.is_empty() {
+                true => None,
+                false => Some(localized_details),
+            });
             let metadata = meta.update(&ss.db).await.unwrap();
 
             change_metadata_associations(
@@ -654,14 +1078,91 @@ pub async fn get_users_monitoring_entity(
     )
 }
 
+pub fn get_entity_details_frontend_url(
+    id: String,
+    entity_lot: EntityLot,
+    default_tab: Option<&str>,
+    ss: &Arc<SupportingService>,
+) -> String {
+    let mut url = match entity_lot {
+        EntityLot::Metadata => format!("media/item/{}", id),
+        EntityLot::Collection => format!("collections/{}", id),
+        EntityLot::Person => format!("media/people/item/{}", id),
+        EntityLot::Workout => format!("fitness/workouts/{}", id),
+        EntityLot::Exercise => format!("fitness/exercises/{}", id),
+        EntityLot::MetadataGroup => format!("media/groups/item/{}", id),
+        EntityLot::WorkoutTemplate => format!("fitness/templates/{}", id),
+        EntityLot::Review | EntityLot::UserMeasurement => unreachable!(),
+    };
+    url = format!("{}/{}", ss.config.frontend.url, url);
+    if let Some(tab) = default_tab {
+        url += format!("?defaultTab={}", tab).as_str()
+    }
+    url
+}
+
+/// Get the title, poster and frontend link of an entity to attach to a notification.
+pub async fn get_notification_payload(
+    entity_id: &String,
+    entity_lot: EntityLot,
+    content: Option<UserNotificationContent>,
+    ss: &Arc<SupportingService>,
+) -> Result<UserNotificationPayload> {
+    let (title, images, media_lot) = match entity_lot {
+        EntityLot::Metadata => Metadata::find_by_id(entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|m| (Some(m.title), m.images, Some(m.lot)))
+            .unwrap_or_default(),
+        EntityLot::Person => Person::find_by_id(entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|p| (Some(p.name), p.images, None))
+            .unwrap_or_default(),
+        EntityLot::MetadataGroup => MetadataGroup::find_by_id(entity_id)
+            .one(&ss.db)
+            .await?
+            .map(|g| (Some(g.title), g.images, Some(g.lot)))
+            .unwrap_or_default(),
+        EntityLot::Workout => (
+            Workout::find_by_id(entity_id)
+                .one(&ss.db)
+                .await?
+                .map(|w| w.name),
+            None,
+            None,
+        ),
+        _ => (None, None, None),
+    };
+    let image = first_metadata_image_as_url(&images, &ss.file_storage_service).await;
+    let action_url = match entity_lot {
+        EntityLot::Review | EntityLot::UserMeasurement => None,
+        _ => Some(get_entity_details_frontend_url(
+            entity_id.to_owned(),
+            entity_lot,
+            None,
+            ss,
+        )),
+    };
+    Ok(UserNotificationPayload {
+        title,
+        image,
+        content,
+        media_lot,
+        action_url,
+    })
+}
+
 pub async fn create_user_notification(
     message: &str,
+    payload: Option<UserNotificationPayload>,
     user_id: &String,
     db: &DatabaseConnection,
     lot: UserNotificationLot,
 ) -> Result<bool> {
     let insert_data = user_notification::ActiveModel {
         lot: ActiveValue::Set(lot),
+        payload: ActiveValue::Set(payload),
         message: ActiveValue::Set(message.to_owned()),
         user_id: ActiveValue::Set(user_id.to_owned()),
         ..Default::default()
@@ -678,13 +1179,18 @@ pub async fn create_user_notification(
 pub async fn create_notification_for_user(
     user_id: &String,
     notification: &(String, UserNotificationContent),
+    payload: Option<&UserNotificationPayload>,
     lot: UserNotificationLot,
     ss: &Arc<SupportingService>,
 ) -> Result<()> {
     let (msg, change) = notification;
     let notification_preferences = user_by_id(user_id, ss).await?.preferences.notifications;
     if notification_preferences.enabled && notification_preferences.to_send.contains(change) {
-        create_user_notification(msg, user_id, &ss.db, lot)
+        let payload = UserNotificationPayload {
+            content: Some(*change),
+            ..payload.cloned().unwrap_or_default()
+        };
+        create_user_notification(msg, Some(payload), user_id, &ss.db, lot)
             .await
             .trace_ok();
     } else {
@@ -717,17 +1223,22 @@ pub async fn refresh_collection_to_entity_association(
 
 pub async fn update_metadata_and_notify_users(
     metadata_id: &String,
+    languages: &[String],
     ss: &Arc<SupportingService>,
 ) -> Result<()> {
-    let notifications = update_metadata(metadata_id, ss).await?;
+    let notifications = update_metadata(metadata_id, languages, ss).await?;
     if !notifications.is_empty() {
         let users_to_notify =
             get_users_and_cte_monitoring_entity(metadata_id, EntityLot::Metadata, &ss.db).await?;
+        let payload = get_notification_payload(metadata_id, EntityLot::Metadata, None, ss)
+            .await
+            .ok();
         for notification in notifications {
             for (user_id, cte_id) in users_to_notify.iter() {
                 create_notification_for_user(
                     user_id,
                     &notification,
+                    payload.as_ref(),
                     UserNotificationLot::Queued,
                     ss,
                 )
@@ -839,6 +1350,53 @@ pub async fn deploy_update_metadata_job(
     Ok(true)
 }
 
+/// Queue a refresh of the media tracked by the user that does not have details in the
+/// language yet, so that they are shown localized without waiting for the periodic
+/// refresh.
+pub async fn deploy_update_metadata_jobs_for_language(
+    user_id: &String,
+    language: &str,
+    ss: &Arc<SupportingService>,
+) -> Result<()> {
+    let metadata_ids: Vec<String> = Metadata::find()
+        .select_only()
+        .column(metadata::Column::Id)
+        // These are the only providers that return localized details
+        .filter(metadata::Column::Source.is_in([MediaSource::Tmdb, MediaSource::Anilist]))
+        .filter(
+            metadata::Column::Id.in_subquery(
+                UserToEntity::find()
+                    .select_only()
+                    .column(user_to_entity::Column::MetadataId)
+                    .filter(user_to_entity::Column::UserId.eq(user_id))
+                    .filter(user_to_entity::Column::MetadataId.is_not_null())
+                    .into_query(),
+            ),
+        )
+        .filter(Expr::cust_with_values(
+            r#"NOT COALESCE("metadata"."localized_details", '[]') @> jsonb_build_array(jsonb_build_object('language', $1::TEXT))"#,
+            [language],
+        ))
+        .into_tuple()
+        .all(&ss.db)
+        .await?;
+    ryot_log!(
+        debug,
+        "Refreshing {} metadata for language {}",
+        metadata_ids.len(),
+        language
+    );
+    Metadata::update_many()
+        .filter(metadata::Column::Id.is_in(metadata_ids.clone()))
+        .col_expr(metadata::Column::IsPartial, Expr::value(true))
+        .exec(&ss.db)
+        .await?;
+    for metadata_id in metadata_ids {
+        deploy_update_metadata_job(&metadata_id, ss).await?;
+    }
+    Ok(())
+}
+
 pub async fn deploy_update_metadata_group_job(
     metadata_group_id: &String,
     ss: &Arc<SupportingService>,
@@ -1379,7 +1937,12 @@ pub async fn progress_update(
             let prev_seen = all_prev_seen[0].clone();
             let progress = input.progress.unwrap();
             let watched_on = prev_seen.provider_watched_on.clone();
-            if prev_seen.progress == progress && watched_on == input.provider_watched_on {
+            if prev_seen.progress == progress
+                && watched_on == input.provider_watched_on
+                && input
+                    .manual_time_spent
+                    .is_none_or(|t| prev_seen.manual_time_spent == Some(t))
+            {
                 ryot_log!(debug, "No progress update required");
                 return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
                     error: ProgressUpdateErrorVariant::UpdateWithoutProgressUpdate,
@@ -1396,6 +1959,9 @@ pub async fn progress_update(
             last_seen.updated_at = ActiveValue::Set(updated_at);
             last_seen.provider_watched_on =
                 ActiveValue::Set(input.provider_watched_on.or(watched_on));
+            if let Some(manual_time_spent) = input.manual_time_spent {
+                last_seen.manual_time_spent = ActiveValue::Set(Some(manual_time_spent));
+            }
 
             // This is needed for manga as some of the apps will update in weird orders
             // For example with komga mihon will update out of order to the server
@@ -1488,6 +2054,15 @@ pub async fn progress_update(
             } else {
                 None
             };
+            let board_game_ei = if matches!(meta.lot, MediaLot::BoardGame) {
+                Some(SeenBoardGameExtraInformation {
+                    player_count: input.board_game_player_count,
+                    duration: input.board_game_duration,
+                    winner: input.board_game_winner,
+                })
+            } else {
+                None
+            };
             let finished_on = match action {
                 ProgressUpdateAction::JustStarted => None,
                 _ => input.date,
@@ -1524,7 +2099,9 @@ pub async fn progress_update(
                 anime_extra_information: ActiveValue::Set(anime_ei),
                 manga_extra_information: ActiveValue::Set(manga_ei),
                 podcast_extra_information: ActiveValue::Set(podcast_ei),
+                board_game_extra_information: ActiveValue::Set(board_game_ei),
                 provider_watched_on: ActiveValue::Set(input.provider_watched_on),
+                manual_time_spent: ActiveValue::Set(input.manual_time_spent),
                 ..Default::default()
             };
             seen_insert.insert(&ss.db).await.unwrap()
@@ -1540,7 +2117,12 @@ pub async fn progress_update(
             )
             .await?;
     }
-    if seen.state == SeenState::Completed {
+    // Integrations that sync anime and manga lists also need to know when a media was
+    // dropped or put on hold
+    if matches!(
+        seen.state,
+        SeenState::Completed | SeenState::Dropped | SeenState::OnAHold
+    ) {
         ss.perform_application_job(ApplicationJob::Lp(LpApplicationJob::HandleOnSeenComplete(
             seen.id.clone(),
         )))
@@ -2055,12 +2637,15 @@ pub async fn create_or_update_user_workout(
         Some(_) => schedule_user_for_workout_revision(user_id, ss).await?,
         None => {
             if input.create_workout_id.is_none() {
+                let payload =
+                    get_notification_payload(&data.id, EntityLot::Workout, None, ss).await?;
                 create_notification_for_user(
                     user_id,
                     &(
                         format!("New workout created - {}", data.name),
                         UserNotificationContent::NewWorkoutCreated,
                     ),
+                    Some(&payload),
                     UserNotificationLot::Immediate,
                     ss,
                 )
@@ -2274,6 +2859,10 @@ where
                             manga_chapter_number: seen.manga_chapter_number,
                             podcast_episode_number: seen.podcast_episode_number,
                             provider_watched_on: seen.provider_watched_on.clone(),
+                            board_game_player_count: seen.board_game_player_count,
+                            board_game_duration: seen.board_game_duration,
+                            board_game_winner: seen.board_game_winner.clone(),
+                            manual_time_spent: seen.manual_time_spent,
                             ..Default::default()
                         },
                         ss,
@@ -2786,6 +3375,14 @@ pub async fn metadata_list(
         .unwrap_or(1)
         .try_into()
         .unwrap();
+    // The title in the language preferred by the user, falling back to the original one
+    let title_expr = match &preferences.general.language {
+        Some(language) => Expr::cust_with_values(
+            r#"COALESCE((SELECT "l" ->> 'title' FROM JSONB_ARRAY_ELEMENTS("metadata"."localized_details") AS "l" WHERE "l" ->> 'language' = $1 LIMIT 1), "metadata"."title")"#,
+            [language.clone()],
+        ),
+        None => Expr::col((metadata::Entity, metadata::Column::Title)).into(),
+    };
     let paginator = Metadata::find()
         .select_only()
         .column(metadata::Column::Id)
@@ -2828,6 +3425,7 @@ pub async fn metadata_list(
             query.filter(
                 Condition::any()
                     .add(Expr::col(metadata::Column::Title).ilike(ilike_sql(&v)))
+                    .add(Expr::expr(title_expr.clone()).ilike(ilike_sql(&v)))
                     .add(Expr::col(metadata::Column::Description).ilike(ilike_sql(&v))),
             )
         })
@@ -2864,7 +3462,7 @@ pub async fn metadata_list(
             MediaSortBy::LastUpdated => query
                 .order_by(user_to_entity::Column::LastUpdatedOn, order_by)
                 .group_by(user_to_entity::Column::LastUpdatedOn),
-            MediaSortBy::Title => query.order_by(metadata::Column::Title, order_by),
+            MediaSortBy::Title => query.order_by(title_expr, order_by),
             MediaSortBy::ReleaseDate => query.order_by_with_nulls(
                 metadata::Column::PublishYear,
                 order_by,
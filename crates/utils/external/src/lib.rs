@@ -1,14 +1,15 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use common_utils::{ryot_log, sleep_for_n_seconds, APPLICATION_JSON_HEADER, USER_AGENT_STR};
 use database_models::{metadata, prelude::Metadata};
 use dependent_utils::deploy_update_metadata_job;
-use enum_models::{MediaLot, MediaSource};
+use enum_models::{MediaLot, MediaSource, SeenState};
 use reqwest::{
     header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, USER_AGENT},
     Client, ClientBuilder,
 };
+use rust_decimal::Decimal;
 use sea_orm::{
     prelude::DateTimeUtc,
     {ColumnTrait, EntityTrait, QueryFilter},
@@ -146,3 +147,693 @@ pub mod audiobookshelf {
         get_metadata(identifier, ss).await
     }
 }
+
+pub mod trakt {
+    use chrono::{Duration, Utc};
+    use config::IntegrationTraktConfig;
+    use media_models::{IntegrationProviderSpecifics, TraktDeviceCode};
+    use reqwest::{header::CONTENT_TYPE, StatusCode};
+    use serde_json::json;
+
+    use super::*;
+
+    pub const API_URL: &str = "https://api.trakt.tv";
+    const API_VERSION: &str = "2";
+    const REDIRECT_URI: &str = "urn:ietf:wg:oauth:2.0:oob";
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TokenResponse {
+        expires_in: i64,
+        access_token: String,
+        refresh_token: String,
+    }
+
+    impl TokenResponse {
+        fn into_specifics(self) -> IntegrationProviderSpecifics {
+            IntegrationProviderSpecifics {
+                trakt_access_token: Some(self.access_token),
+                trakt_refresh_token: Some(self.refresh_token),
+                trakt_token_expires_at: Some(Utc::now() + Duration::seconds(self.expires_in)),
+                ..Default::default()
+            }
+        }
+    }
+
+    fn get_client(client_id: &str, access_token: Option<&str>) -> Result<Client> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_STR));
+        headers.insert(CONTENT_TYPE, APPLICATION_JSON_HEADER.clone());
+        headers.insert("trakt-api-key", HeaderValue::from_str(client_id)?);
+        headers.insert("trakt-api-version", HeaderValue::from_static(API_VERSION));
+        if let Some(token) = access_token {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", token))?,
+            );
+        }
+        Ok(ClientBuilder::new().default_headers(headers).build()?)
+    }
+
+    pub fn get_authenticated_client(client_id: &str, access_token: &str) -> Result<Client> {
+        get_client(client_id, Some(access_token))
+    }
+
+    pub async fn get_device_code(config: &IntegrationTraktConfig) -> Result<TraktDeviceCode> {
+        let code = get_client(&config.client_id, None)?
+            .post(format!("{}/oauth/device/code", API_URL))
+            .json(&json!({ "client_id": config.client_id }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(code)
+    }
+
+    /// Exchange a device code that has been approved by the user for the tokens
+    /// needed to access their account.
+    pub async fn get_tokens_for_device_code(
+        config: &IntegrationTraktConfig,
+        device_code: &str,
+    ) -> Result<IntegrationProviderSpecifics> {
+        let response = get_client(&config.client_id, None)?
+            .post(format!("{}/oauth/device/token", API_URL))
+            .json(&json!({
+                "code": device_code,
+                "client_id": config.client_id,
+                "client_secret": config.client_secret,
+            }))
+            .send()
+            .await?;
+        match response.status() {
+            StatusCode::OK => Ok(response.json::<TokenResponse>().await?.into_specifics()),
+            StatusCode::BAD_REQUEST => bail!("The code has not been approved on Trakt yet"),
+            StatusCode::GONE => bail!("The code has expired, please request a new one"),
+            StatusCode::IM_A_TEAPOT => bail!("The code was denied on Trakt"),
+            status => bail!("Could not log in to Trakt: {}", status),
+        }
+    }
+
+    pub async fn refresh_tokens(
+        config: &IntegrationTraktConfig,
+        refresh_token: &str,
+    ) -> Result<IntegrationProviderSpecifics> {
+        let response: TokenResponse = get_client(&config.client_id, None)?
+            .post(format!("{}/oauth/token", API_URL))
+            .json(&json!({
+                "refresh_token": refresh_token,
+                "client_id": config.client_id,
+                "client_secret": config.client_secret,
+                "redirect_uri": REDIRECT_URI,
+                "grant_type": "refresh_token",
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(response.into_specifics())
+    }
+}
+
+/// The progress of the user on an anime or manga in their list on an external tracker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaListEntry {
+    pub lot: MediaLot,
+    pub identifier: String,
+    pub title: String,
+    /// `None` if the media has only been planned and not started yet.
+    pub state: Option<SeenState>,
+    /// The number of episodes watched or chapters read.
+    pub progress: i32,
+    /// The score out of 100, `None` if it has not been scored.
+    pub score: Option<Decimal>,
+    pub updated_at: DateTimeUtc,
+}
+
+pub mod anilist {
+    use chrono::DateTime;
+    use reqwest::{header::CONTENT_TYPE, StatusCode};
+    use serde::de::DeserializeOwned;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const URL: &str = "https://graphql.anilist.co";
+    const ENTRY_FIELDS: &str = "status progress score(format: POINT_100) updatedAt";
+
+    #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum ListStatus {
+        Current,
+        Planning,
+        Completed,
+        Dropped,
+        Paused,
+        Repeating,
+    }
+
+    impl ListStatus {
+        fn to_state(self) -> Option<SeenState> {
+            match self {
+                Self::Planning => None,
+                Self::Current | Self::Repeating => Some(SeenState::InProgress),
+                Self::Completed => Some(SeenState::Completed),
+                Self::Dropped => Some(SeenState::Dropped),
+                Self::Paused => Some(SeenState::OnAHold),
+            }
+        }
+
+        fn from_state(state: Option<SeenState>) -> Self {
+            match state {
+                None => Self::Planning,
+                Some(SeenState::InProgress) => Self::Current,
+                Some(SeenState::Completed) => Self::Completed,
+                Some(SeenState::Dropped) => Self::Dropped,
+                Some(SeenState::OnAHold) => Self::Paused,
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Title {
+        #[serde(rename = "userPreferred")]
+        user_preferred: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Entry {
+        status: ListStatus,
+        progress: Option<i32>,
+        score: Option<Decimal>,
+        updated_at: i64,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Media {
+        id: i64,
+        id_mal: Option<i64>,
+        title: Title,
+        media_list_entry: Option<Entry>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ListEntryResponse {
+        #[serde(flatten)]
+        entry: Entry,
+        media: Media,
+    }
+
+    /// An entry in the list of the user along with the MyAnimeList ID of the media,
+    /// which is used to find it in Ryot if it was added from there.
+    #[derive(Debug, Clone)]
+    pub struct ListEntry {
+        pub entry: MediaListEntry,
+        pub mal_identifier: Option<String>,
+    }
+
+    /// The identifiers of a media on Anilist and MyAnimeList.
+    #[derive(Debug, Clone)]
+    pub struct MediaIdentifiers {
+        pub identifier: String,
+        pub mal_identifier: Option<String>,
+    }
+
+    fn media_type(lot: MediaLot) -> &'static str {
+        match lot {
+            MediaLot::Manga => "MANGA",
+            _ => "ANIME",
+        }
+    }
+
+    fn to_list_entry(lot: MediaLot, media: &Media, entry: Entry) -> MediaListEntry {
+        MediaListEntry {
+            lot,
+            identifier: media.id.to_string(),
+            title: media.title.user_preferred.clone(),
+            state: entry.status.to_state(),
+            progress: entry.progress.unwrap_or_default(),
+            score: entry.score.filter(|s| !s.is_zero()),
+            updated_at: DateTime::from_timestamp(entry.updated_at, 0).unwrap_or_default(),
+        }
+    }
+
+    /// Send a query to the Anilist API. Requests without a token only have access
+    /// to public data. Returns `None` if the requested item does not exist.
+    async fn request<T: DeserializeOwned>(
+        token: Option<&str>,
+        query: &str,
+        variables: Value,
+    ) -> Result<Option<T>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_STR));
+        headers.insert(ACCEPT, APPLICATION_JSON_HEADER.clone());
+        headers.insert(CONTENT_TYPE, APPLICATION_JSON_HEADER.clone());
+        if let Some(token) = token {
+            headers.insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", token))?,
+            );
+        }
+        let response = ClientBuilder::new()
+            .default_headers(headers)
+            .build()?
+            .post(URL)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = response.error_for_status()?.json::<Value>().await?;
+        if let Some(error) = body["errors"][0]["message"].as_str() {
+            bail!("Anilist returned an error: {}", error);
+        }
+        Ok(Some(serde_json::from_value(body["data"].clone())?))
+    }
+
+    pub async fn get_viewer_id(token: &str) -> Result<i64> {
+        let data: Value = request(Some(token), "query { Viewer { id } }", json!({}))
+            .await?
+            .unwrap_or_default();
+        match data["Viewer"]["id"].as_i64() {
+            Some(id) => Ok(id),
+            None => bail!("Could not get the Anilist user for this token"),
+        }
+    }
+
+    pub async fn get_list_entries(token: &str) -> Result<Vec<ListEntry>> {
+        #[derive(Debug, Deserialize)]
+        struct List {
+            entries: Vec<ListEntryResponse>,
+        }
+        #[derive(Debug, Deserialize)]
+        struct Collection {
+            lists: Vec<List>,
+        }
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Response {
+            media_list_collection: Collection,
+        }
+        let user_id = get_viewer_id(token).await?;
+        let query = format!(
+            "query ($userId: Int, $type: MediaType) {{
+                MediaListCollection(userId: $userId, type: $type) {{
+                    lists {{ entries {{
+                        {} media {{ id idMal title {{ userPreferred }} }}
+                    }} }}
+                }}
+            }}",
+            ENTRY_FIELDS
+        );
+        let mut entries = vec![];
+        for lot in [MediaLot::Anime, MediaLot::Manga] {
+            let Some(response) = request::<Response>(
+                Some(token),
+                &query,
+                json!({ "userId": user_id, "type": media_type(lot) }),
+            )
+            .await?
+            else {
+                continue;
+            };
+            for list in response.media_list_collection.lists {
+                for item in list.entries {
+                    // The same media can be part of multiple custom lists
+                    if entries.iter().any(|e: &ListEntry| {
+                        e.entry.lot == lot && e.entry.identifier == item.media.id.to_string()
+                    }) {
+                        continue;
+                    }
+                    entries.push(ListEntry {
+                        mal_identifier: item.media.id_mal.map(|i| i.to_string()),
+                        entry: to_list_entry(lot, &item.media, item.entry),
+                    });
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Find a media by its Anilist or MyAnimeList ID.
+    async fn get_media(
+        token: Option<&str>,
+        lot: MediaLot,
+        identifier: Option<&str>,
+        mal_identifier: Option<&str>,
+    ) -> Result<Option<Media>> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Response {
+            media: Media,
+        }
+        let entry_fields = match token {
+            Some(_) => format!("mediaListEntry {{ {} }}", ENTRY_FIELDS),
+            None => String::new(),
+        };
+        let query = format!(
+            "query ($id: Int, $idMal: Int, $type: MediaType) {{
+                Media(id: $id, idMal: $idMal, type: $type) {{
+                    id idMal title {{ userPreferred }} {}
+                }}
+            }}",
+            entry_fields
+        );
+        let parse = |i: Option<&str>| i.and_then(|i| i.parse::<i64>().ok());
+        let response = request::<Response>(
+            token,
+            &query,
+            json!({
+                "id": parse(identifier),
+                "idMal": parse(mal_identifier),
+                "type": media_type(lot),
+            }),
+        )
+        .await?;
+        Ok(response.map(|r| r.media))
+    }
+
+    /// Get the Anilist and MyAnimeList IDs of a media using either one of them.
+    pub async fn get_media_identifiers(
+        lot: MediaLot,
+        identifier: Option<&str>,
+        mal_identifier: Option<&str>,
+    ) -> Result<Option<MediaIdentifiers>> {
+        let media = get_media(None, lot, identifier, mal_identifier).await?;
+        Ok(media.map(|m| MediaIdentifiers {
+            identifier: m.id.to_string(),
+            mal_identifier: m.id_mal.map(|i| i.to_string()),
+        }))
+    }
+
+    /// Get the entry of a media in the list of the user, `None` if it is not in
+    /// their list.
+    pub async fn get_list_entry(
+        token: &str,
+        lot: MediaLot,
+        identifier: &str,
+    ) -> Result<Option<MediaListEntry>> {
+        let media = get_media(Some(token), lot, Some(identifier), None).await?;
+        Ok(media.and_then(|mut m| {
+            let entry = m.media_list_entry.take()?;
+            Some(to_list_entry(lot, &m, entry))
+        }))
+    }
+
+    pub async fn save_list_entry(token: &str, entry: &MediaListEntry) -> Result<()> {
+        let query = "mutation ($mediaId: Int, $status: MediaListStatus, $progress: Int, $scoreRaw: Int) {
+            SaveMediaListEntry(mediaId: $mediaId, status: $status, progress: $progress, scoreRaw: $scoreRaw) { id }
+        }";
+        request::<Value>(
+            Some(token),
+            query,
+            json!({
+                "mediaId": entry.identifier.parse::<i64>()?,
+                "status": ListStatus::from_state(entry.state),
+                "progress": entry.progress,
+                "scoreRaw": entry.score.and_then(|s| i64::try_from(s.round()).ok()),
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+pub mod mal {
+    use chrono::{Duration, Utc};
+    use config::IntegrationMalConfig;
+    use media_models::{IntegrationProviderSpecifics, MalAuthorizationUrl};
+    use reqwest::Url;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    const AUTH_URL: &str = "https://myanimelist.net/v1/oauth2";
+    const API_URL: &str = "https://api.myanimelist.net/v2";
+    const PAGE_SIZE: i32 = 1000;
+
+    #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+    #[serde(rename_all = "snake_case")]
+    enum ListStatus {
+        #[serde(alias = "reading")]
+        Watching,
+        Completed,
+        OnHold,
+        Dropped,
+        #[serde(alias = "plan_to_read")]
+        PlanToWatch,
+    }
+
+    impl ListStatus {
+        fn to_state(self) -> Option<SeenState> {
+            match self {
+                Self::PlanToWatch => None,
+                Self::Watching => Some(SeenState::InProgress),
+                Self::Completed => Some(SeenState::Completed),
+                Self::Dropped => Some(SeenState::Dropped),
+                Self::OnHold => Some(SeenState::OnAHold),
+            }
+        }
+
+        fn to_api_value(state: Option<SeenState>, lot: MediaLot) -> &'static str {
+            match (state, lot) {
+                (None, MediaLot::Manga) => "plan_to_read",
+                (None, _) => "plan_to_watch",
+                (Some(SeenState::InProgress), MediaLot::Manga) => "reading",
+                (Some(SeenState::InProgress), _) => "watching",
+                (Some(SeenState::Completed), _) => "completed",
+                (Some(SeenState::Dropped), _) => "dropped",
+                (Some(SeenState::OnAHold), _) => "on_hold",
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TokenResponse {
+        expires_in: i64,
+        access_token: String,
+        refresh_token: String,
+    }
+
+    impl TokenResponse {
+        fn into_specifics(self) -> IntegrationProviderSpecifics {
+            IntegrationProviderSpecifics {
+                mal_access_token: Some(self.access_token),
+                mal_refresh_token: Some(self.refresh_token),
+                mal_token_expires_at: Some(Utc::now() + Duration::seconds(self.expires_in)),
+                ..Default::default()
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ListStatusResponse {
+        status: ListStatus,
+        score: Decimal,
+        #[serde(alias = "num_chapters_read")]
+        num_episodes_watched: i32,
+        updated_at: DateTimeUtc,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Node {
+        id: i64,
+        title: String,
+        my_list_status: Option<ListStatusResponse>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ListItem {
+        node: Node,
+        list_status: ListStatusResponse,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Paging {
+        next: Option<String>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct ListResponse {
+        data: Vec<ListItem>,
+        paging: Paging,
+    }
+
+    fn media_type(lot: MediaLot) -> &'static str {
+        match lot {
+            MediaLot::Manga => "manga",
+            _ => "anime",
+        }
+    }
+
+    fn to_list_entry(lot: MediaLot, node: &Node, status: ListStatusResponse) -> MediaListEntry {
+        MediaListEntry {
+            lot,
+            identifier: node.id.to_string(),
+            title: node.title.clone(),
+            state: status.status.to_state(),
+            progress: status.num_episodes_watched,
+            score: Some(status.score * dec!(10)).filter(|s| !s.is_zero()),
+            updated_at: status.updated_at,
+        }
+    }
+
+    fn get_client(access_token: &str) -> Result<Client> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_STR));
+        headers.insert(ACCEPT, APPLICATION_JSON_HEADER.clone());
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", access_token))?,
+        );
+        Ok(ClientBuilder::new().default_headers(headers).build()?)
+    }
+
+    /// MyAnimeList only supports the `plain` PKCE method, so the code verifier is sent
+    /// as the code challenge.
+    pub fn get_authorization_url(
+        config: &IntegrationMalConfig,
+        code_verifier: String,
+    ) -> Result<MalAuthorizationUrl> {
+        let url = Url::parse_with_params(
+            &format!("{}/authorize", AUTH_URL),
+            &[
+                ("response_type", "code"),
+                ("client_id", &config.client_id),
+                ("code_challenge", &code_verifier),
+                ("code_challenge_method", "plain"),
+            ],
+        )?;
+        Ok(MalAuthorizationUrl {
+            code_verifier,
+            url: url.to_string(),
+        })
+    }
+
+    async fn get_tokens(form: serde_json::Value) -> Result<IntegrationProviderSpecifics> {
+        let response = Client::new()
+            .post(format!("{}/token", AUTH_URL))
+            .form(&form)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            bail!(
+                "Could not log in to MyAnimeList: {}",
+                response.text().await?
+            );
+        }
+        Ok(response.json::<TokenResponse>().await?.into_specifics())
+    }
+
+    /// Exchange the authorization code returned by MyAnimeList for the tokens needed
+    /// to access the account of the user.
+    pub async fn get_tokens_for_code(
+        config: &IntegrationMalConfig,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<IntegrationProviderSpecifics> {
+        get_tokens(json!({
+            "code": code,
+            "code_verifier": code_verifier,
+            "client_id": config.client_id,
+            "client_secret": config.client_secret,
+            "grant_type": "authorization_code",
+        }))
+        .await
+    }
+
+    pub async fn refresh_tokens(
+        config: &IntegrationMalConfig,
+        refresh_token: &str,
+    ) -> Result<IntegrationProviderSpecifics> {
+        get_tokens(json!({
+            "refresh_token": refresh_token,
+            "client_id": config.client_id,
+            "client_secret": config.client_secret,
+            "grant_type": "refresh_token",
+        }))
+        .await
+    }
+
+    pub async fn get_list_entries(access_token: &str) -> Result<Vec<MediaListEntry>> {
+        let client = get_client(access_token)?;
+        let mut entries = vec![];
+        for lot in [MediaLot::Anime, MediaLot::Manga] {
+            let mut url = Some(format!(
+                "{}/users/@me/{}list?fields=list_status&nsfw=true&limit={}",
+                API_URL,
+                media_type(lot),
+                PAGE_SIZE
+            ));
+            while let Some(next) = url {
+                let response = client
+                    .get(next)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<ListResponse>()
+                    .await?;
+                entries.extend(
+                    response
+                        .data
+                        .into_iter()
+                        .map(|item| to_list_entry(lot, &item.node, item.list_status)),
+                );
+                url = response.paging.next;
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Get the entry of a media in the list of the user, `None` if it is not in
+    /// their list.
+    pub async fn get_list_entry(
+        access_token: &str,
+        lot: MediaLot,
+        identifier: &str,
+    ) -> Result<Option<MediaListEntry>> {
+        let mut node = get_client(access_token)?
+            .get(format!("{}/{}/{}", API_URL, media_type(lot), identifier))
+            .query(&[("fields", "my_list_status")])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Node>()
+            .await?;
+        Ok(node
+            .my_list_status
+            .take()
+            .map(|status| to_list_entry(lot, &node, status)))
+    }
+
+    pub async fn save_list_entry(access_token: &str, entry: &MediaListEntry) -> Result<()> {
+        let progress_field = match entry.lot {
+            MediaLot::Manga => "num_chapters_read",
+            _ => "num_watched_episodes",
+        };
+        let score = entry
+            .score
+            .map(|s| (s / dec!(10)).round().clamp(dec!(1), dec!(10)))
+            .unwrap_or_default();
+        get_client(access_token)?
+            .patch(format!(
+                "{}/{}/{}/my_list_status",
+                API_URL,
+                media_type(entry.lot),
+                entry.identifier
+            ))
+            .form(&json!({
+                "status": ListStatus::to_api_value(entry.state, entry.lot),
+                "score": score.to_string(),
+                progress_field: entry.progress,
+            }))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
@@ -114,12 +114,20 @@ export type MediaLot = 'book' | 'show' | 'movie' | 'anime' | 'manga' | 'music' |
 export interface ImportOrExportMetadataItemSeen {
 	/** If for an anime, the episode which was seen. */
 	anime_episode_number: number | null;
+	/** If for a board game, the duration of the play in minutes. */
+	board_game_duration: number | null;
+	/** If for a board game, the number of players in the play. */
+	board_game_player_count: number | null;
+	/** If for a board game, the winner of the play. */
+	board_game_winner: string | null;
 	/** The timestamp when finished watching. */
 	ended_on: string | null;
 	/** If for a manga, the chapter which was seen. */
 	manga_chapter_number: string | null;
 	/** If for a manga, the volume which was seen. */
 	manga_volume_number: number | null;
+	/** The time spent on this item in seconds, if tracked manually. */
+	manual_time_spent: string | null;
 	/** If for a podcast, the episode which was seen. */
 	podcast_episode_number: number | null;
 	/** The progress of media done. If none, it is considered as done. */
@@ -102,6 +102,19 @@ export enum BackgroundJob {
   UpdateAllMetadata = 'UPDATE_ALL_METADATA'
 }
 
+export type BoardGameSpecifics = {
+  __typename?: 'BoardGameSpecifics';
+  maxPlayers?: Maybe<Scalars['Int']['output']>;
+  minPlayers?: Maybe<Scalars['Int']['output']>;
+  playTime?: Maybe<Scalars['Int']['output']>;
+};
+
+export type BoardGameSpecificsInput = {
+  maxPlayers?: InputMaybe<Scalars['Int']['input']>;
+  minPlayers?: InputMaybe<Scalars['Int']['input']>;
+  playTime?: InputMaybe<Scalars['Int']['input']>;
+};
+
 export type BookSpecifics = {
   __typename?: 'BookSpecifics';
   isCompilation?: Maybe<Scalars['Boolean']['output']>;
@@ -113,6 +126,14 @@ export type BookSpecificsInput = {
   pages?: InputMaybe<Scalars['Int']['input']>;
 };
 
+/** A monitored person or group that the media of a calendar event is associated with. */
+export type CalendarEventMonitoredEntity = {
+  __typename?: 'CalendarEventMonitoredEntity';
+  entityId: Scalars['String']['output'];
+  entityLot: EntityLot;
+  name: Scalars['String']['output'];
+};
+
 export type ChangeCollectionToEntityInput = {
   collectionName: Scalars['String']['input'];
   creatorUserId: Scalars['String']['input'];
@@ -200,6 +221,19 @@ export type CollectionItem = {
   name: Scalars['String']['output'];
 };
 
+export type ComicSpecifics = {
+  __typename?: 'ComicSpecifics';
+  issueNumber?: Maybe<Scalars['String']['output']>;
+  publisher?: Maybe<Scalars['String']['output']>;
+  volume?: Maybe<Scalars['String']['output']>;
+};
+
+export type ComicSpecificsInput = {
+  issueNumber?: InputMaybe<Scalars['String']['input']>;
+  publisher?: InputMaybe<Scalars['String']['input']>;
+  volume?: InputMaybe<Scalars['String']['input']>;
+};
+
 export type CommitMediaInput = {
   name: Scalars['String']['input'];
   unique: UniqueMediaIdentifier;
@@ -248,7 +282,9 @@ export type CreateAccessLinkInput = {
 export type CreateCustomMetadataInput = {
   animeSpecifics?: InputMaybe<AnimeSpecificsInput>;
   audioBookSpecifics?: InputMaybe<AudioBookSpecificsInput>;
+  boardGameSpecifics?: InputMaybe<BoardGameSpecificsInput>;
   bookSpecifics?: InputMaybe<BookSpecificsInput>;
+  comicSpecifics?: InputMaybe<ComicSpecificsInput>;
   creators?: InputMaybe<Array<Scalars['String']['input']>>;
   description?: InputMaybe<Scalars['String']['input']>;
   genres?: InputMaybe<Array<Scalars['String']['input']>>;
@@ -316,8 +352,11 @@ export type CreateUserNotificationPlatformInput = {
   authHeader?: InputMaybe<Scalars['String']['input']>;
   baseUrl?: InputMaybe<Scalars['String']['input']>;
   chatId?: InputMaybe<Scalars['String']['input']>;
+  contentTypes?: InputMaybe<Array<UserNotificationContent>>;
   lot: NotificationPlatformLot;
+  mediaLots?: InputMaybe<Array<MediaLot>>;
   priority?: InputMaybe<Scalars['Int']['input']>;
+  smtp?: InputMaybe<NotificationPlatformSmtpSettings>;
 };
 
 export type DailyUserActivitiesResponse = {
@@ -353,7 +392,9 @@ export type DailyUserActivityItem = {
   __typename?: 'DailyUserActivityItem';
   animeCount: Scalars['Int']['output'];
   audioBookCount: Scalars['Int']['output'];
+  boardGameCount: Scalars['Int']['output'];
   bookCount: Scalars['Int']['output'];
+  comicCount: Scalars['Int']['output'];
   day: Scalars['NaiveDate']['output'];
   mangaCount: Scalars['Int']['output'];
   movieCount: Scalars['Int']['output'];
@@ -361,6 +402,7 @@ export type DailyUserActivityItem = {
   podcastCount: Scalars['Int']['output'];
   showCount: Scalars['Int']['output'];
   totalAudioBookDuration: Scalars['Int']['output'];
+  totalBoardGameDuration: Scalars['Int']['output'];
   totalBookPages: Scalars['Int']['output'];
   totalCollectionReviewCount: Scalars['Int']['output'];
   totalCount: Scalars['Int']['output'];
@@ -395,6 +437,12 @@ export enum DashboardElementLot {
   Upcoming = 'UPCOMING'
 }
 
+export type DeployExportJobInput = {
+  format?: ExportFormat;
+  /** If set, the export file will be encrypted with a key derived from this passphrase. */
+  passphrase?: InputMaybe<Scalars['String']['input']>;
+};
+
 export type DeployGenericCsvImportInput = {
   csvPath: Scalars['String']['input'];
 };
@@ -419,6 +467,7 @@ export type DeployImportJobInput = {
 
 export type DeployJsonImportInput = {
   export: Scalars['String']['input'];
+  passphrase?: InputMaybe<Scalars['String']['input']>;
 };
 
 export type DeployMalImportInput = {
@@ -646,6 +695,20 @@ export type ExercisesListInput = {
   sortBy?: InputMaybe<ExerciseSortBy>;
 };
 
+/** The different formats in which the data of a user can be exported. */
+export enum ExportFormat {
+  /** A ZIP archive of flat CSV files. */
+  GenericCsv = 'GENERIC_CSV',
+  /** A single JSON file following the `CompleteExport` schema. */
+  GenericJson = 'GENERIC_JSON',
+  /** A Goodreads compatible library CSV containing books. */
+  Goodreads = 'GOODREADS',
+  /** A Letterboxd compatible diary CSV containing movies. */
+  Letterboxd = 'LETTERBOXD',
+  /** A Trakt compatible history JSON containing movies and shows. */
+  Trakt = 'TRAKT'
+}
+
 export type ExportJob = {
   __typename?: 'ExportJob';
   endedAt: Scalars['DateTime']['output'];
@@ -725,6 +788,11 @@ export type GraphqlCalendarEvent = {
   metadataImage?: Maybe<Scalars['String']['output']>;
   metadataLot: MediaLot;
   metadataTitle: Scalars['String']['output'];
+  /**
+   * Set when the media is not tracked by the user and is only shown because it is
+   * associated with a person or group that they monitor.
+   */
+  monitoredEntity?: Maybe<CalendarEventMonitoredEntity>;
   podcastExtraInformation?: Maybe<SeenPodcastExtraInformation>;
   showExtraInformation?: Maybe<SeenShowExtraInformation>;
 };
@@ -740,7 +808,9 @@ export type GraphqlMetadataDetails = {
   animeSpecifics?: Maybe<AnimeSpecifics>;
   assets: GraphqlMediaAssets;
   audioBookSpecifics?: Maybe<AudioBookSpecifics>;
+  boardGameSpecifics?: Maybe<BoardGameSpecifics>;
   bookSpecifics?: Maybe<BookSpecifics>;
+  comicSpecifics?: Maybe<ComicSpecifics>;
   createdByUserId?: Maybe<Scalars['String']['output']>;
   creators: Array<MetadataCreatorGroupedByRole>;
   description?: Maybe<Scalars['String']['output']>;
@@ -750,6 +820,7 @@ export type GraphqlMetadataDetails = {
   identifier: Scalars['String']['output'];
   isNsfw?: Maybe<Scalars['Boolean']['output']>;
   isPartial?: Maybe<Scalars['Boolean']['output']>;
+  linkedSources: Array<MetadataLinkedSource>;
   lot: MediaLot;
   mangaSpecifics?: Maybe<MangaSpecifics>;
   movieSpecifics?: Maybe<MovieSpecifics>;
@@ -912,6 +983,7 @@ export enum IntegrationLot {
 }
 
 export enum IntegrationProvider {
+  Anilist = 'ANILIST',
   Audiobookshelf = 'AUDIOBOOKSHELF',
   Emby = 'EMBY',
   GenericJson = 'GENERIC_JSON',
@@ -919,14 +991,18 @@ export enum IntegrationProvider {
   JellyfinSink = 'JELLYFIN_SINK',
   Kodi = 'KODI',
   Komga = 'KOMGA',
+  Myanimelist = 'MYANIMELIST',
   PlexSink = 'PLEX_SINK',
   PlexYank = 'PLEX_YANK',
   Radarr = 'RADARR',
   Sonarr = 'SONARR',
+  Steam = 'STEAM',
+  Trakt = 'TRAKT',
   YoutubeMusic = 'YOUTUBE_MUSIC'
 }
 
 export type IntegrationSourceSpecificsInput = {
+  anilistToken?: InputMaybe<Scalars['String']['input']>;
   audiobookshelfBaseUrl?: InputMaybe<Scalars['String']['input']>;
   audiobookshelfToken?: InputMaybe<Scalars['String']['input']>;
   jellyfinPushBaseUrl?: InputMaybe<Scalars['String']['input']>;
@@ -936,6 +1012,13 @@ export type IntegrationSourceSpecificsInput = {
   komgaPassword?: InputMaybe<Scalars['String']['input']>;
   komgaProvider?: InputMaybe<MediaSource>;
   komgaUsername?: InputMaybe<Scalars['String']['input']>;
+  /**
+   * The authorization code that was returned by MyAnimeList along with the code
+   * verifier it was requested with. They are exchanged for the tokens below when
+   * the integration is created.
+   */
+  malCode?: InputMaybe<Scalars['String']['input']>;
+  malCodeVerifier?: InputMaybe<Scalars['String']['input']>;
   plexSinkUsername?: InputMaybe<Scalars['String']['input']>;
   plexYankBaseUrl?: InputMaybe<Scalars['String']['input']>;
   plexYankToken?: InputMaybe<Scalars['String']['input']>;
@@ -949,6 +1032,13 @@ export type IntegrationSourceSpecificsInput = {
   sonarrProfileId?: InputMaybe<Scalars['Int']['input']>;
   sonarrRootFolderPath?: InputMaybe<Scalars['String']['input']>;
   sonarrSyncCollectionIds?: InputMaybe<Array<Scalars['String']['input']>>;
+  steamApiKey?: InputMaybe<Scalars['String']['input']>;
+  steamUserId?: InputMaybe<Scalars['String']['input']>;
+  /**
+   * The device code that was approved on Trakt. It is exchanged for the tokens
+   * below when the integration is created.
+   */
+  traktDeviceCode?: InputMaybe<Scalars['String']['input']>;
   youtubeMusicAuthCookie?: InputMaybe<Scalars['String']['input']>;
 };
 
@@ -971,6 +1061,14 @@ export type LoginResponse = {
 
 export type LoginResult = LoginError | LoginResponse;
 
+/** The page where the user can authorize Ryot to access their MyAnimeList account. */
+export type MalAuthorizationUrl = {
+  __typename?: 'MalAuthorizationUrl';
+  /** Has to be sent back along with the authorization code. */
+  codeVerifier: Scalars['String']['output'];
+  url: Scalars['String']['output'];
+};
+
 export type MangaSpecifics = {
   __typename?: 'MangaSpecifics';
   chapters?: Maybe<Scalars['Decimal']['output']>;
@@ -1013,7 +1111,9 @@ export enum MediaGeneralFilter {
 export enum MediaLot {
   Anime = 'ANIME',
   AudioBook = 'AUDIO_BOOK',
+  BoardGame = 'BOARD_GAME',
   Book = 'BOOK',
+  Comic = 'COMIC',
   Manga = 'MANGA',
   Movie = 'MOVIE',
   Music = 'MUSIC',
@@ -1041,6 +1141,8 @@ export type MediaSortInput = {
 export enum MediaSource {
   Anilist = 'ANILIST',
   Audible = 'AUDIBLE',
+  BoardGameGeek = 'BOARD_GAME_GEEK',
+  ComicVine = 'COMIC_VINE',
   Custom = 'CUSTOM',
   GoogleBooks = 'GOOGLE_BOOKS',
   Hardcover = 'HARDCOVER',
@@ -1049,8 +1151,14 @@ export enum MediaSource {
   Listennotes = 'LISTENNOTES',
   Mal = 'MAL',
   MangaUpdates = 'MANGA_UPDATES',
+  MusicBrainz = 'MUSIC_BRAINZ',
   Openlibrary = 'OPENLIBRARY',
+  PodcastIndex = 'PODCAST_INDEX',
+  Rawg = 'RAWG',
+  Remote = 'REMOTE',
+  Rss = 'RSS',
   Tmdb = 'TMDB',
+  Tvdb = 'TVDB',
   Vndb = 'VNDB',
   YoutubeMusic = 'YOUTUBE_MUSIC'
 }
@@ -1122,6 +1230,13 @@ export type MetadataGroupsListInput = {
   sort?: InputMaybe<PersonSortInput>;
 };
 
+/** An equivalent item of a metadata on another provider. */
+export type MetadataLinkedSource = {
+  __typename?: 'MetadataLinkedSource';
+  identifier: Scalars['String']['output'];
+  source: MediaSource;
+};
+
 export type MetadataListInput = {
   filter?: InputMaybe<MediaFilter>;
   invertCollection?: InputMaybe<Scalars['Boolean']['input']>;
@@ -1256,6 +1371,12 @@ export type MutationRoot = {
   deployExportJob: Scalars['Boolean']['output'];
   /** Add job to import data from various sources. */
   deployImportJob: Scalars['Boolean']['output'];
+  /**
+   * Deploy a job to create a backup of the entire instance. The backup will be
+   * encrypted with the passphrase and appear in the exports of the admin that
+   * requested it.
+   */
+  deployInstanceBackupJob: Scalars['Boolean']['output'];
   /** Deploy a job to update a metadata group's details. */
   deployUpdateMetadataGroupJob: Scalars['Boolean']['output'];
   /** Deploy a job to update a media item's metadata. */
@@ -1274,6 +1395,21 @@ export type MutationRoot = {
   disassociateMetadata: Scalars['Boolean']['output'];
   /** Generate an auth token without any expiry. */
   generateAuthToken: Scalars['String']['output'];
+  /**
+   * Generate a token to subscribe to the calendar feed of the user. Any token
+   * generated previously stops working.
+   */
+  generateCalendarFeedToken: Scalars['String']['output'];
+  /**
+   * Get the page where the user can authorize Ryot to access their MyAnimeList
+   * account. The code it redirects to is used to create the integration.
+   */
+  initiateMalLogin: MalAuthorizationUrl;
+  /**
+   * Start logging in to Trakt. The returned code has to be approved on Trakt before
+   * creating the integration with it.
+   */
+  initiateTraktDeviceLogin: TraktDeviceCode;
   /** Login a user using their username and password and return an auth token. */
   loginUser: LoginResult;
   /** Mark an entity as partial. */
@@ -1300,6 +1436,18 @@ export type MutationRoot = {
   removeEntityFromCollection: StringIdObject;
   /** Revoke an access link. */
   revokeAccessLink: Scalars['Boolean']['output'];
+  /**
+   * Extract the details of a media item from an arbitrary web page so that they
+   * can be reviewed before creating a custom media item.
+   */
+  scrapeCustomMetadata: ScrapedCustomMetadata;
+  /**
+   * Switch the primary provider of a media item to one of its equivalent items on
+   * another provider. All history, reviews and collections are retained and the
+   * episodes they refer to are matched to the episodes of the new provider.
+   * Admin only.
+   */
+  switchMetadataSource: Scalars['Boolean']['output'];
   /** Test all notification platforms for the currently logged in user. */
   testUserNotificationPlatforms: Scalars['Boolean']['output'];
   /** Update a custom exercise. */
@@ -1458,11 +1606,21 @@ export type MutationRootDeployBulkProgressUpdateArgs = {
 };
 
 
+export type MutationRootDeployExportJobArgs = {
+  input?: DeployExportJobInput;
+};
+
+
 export type MutationRootDeployImportJobArgs = {
   input: DeployImportJobInput;
 };
 
 
+export type MutationRootDeployInstanceBackupJobArgs = {
+  passphrase: Scalars['String']['input'];
+};
+
+
 export type MutationRootDeployUpdateMetadataGroupJobArgs = {
   metadataGroupId: Scalars['String']['input'];
 };
@@ -1530,6 +1688,16 @@ export type MutationRootRevokeAccessLinkArgs = {
 };
 
 
+export type MutationRootScrapeCustomMetadataArgs = {
+  input: ScrapeCustomMetadataInput;
+};
+
+
+export type MutationRootSwitchMetadataSourceArgs = {
+  input: SwitchMetadataSourceInput;
+};
+
+
 export type MutationRootUpdateCustomExerciseArgs = {
   input: UpdateCustomExerciseInput;
 };
@@ -1574,27 +1742,51 @@ export type MutationRootUpdateUserWorkoutAttributesArgs = {
   input: UpdateUserWorkoutAttributesInput;
 };
 
-export type NotificationPlatform = {
-  __typename?: 'NotificationPlatform';
+export type NotificationDelivery = {
+  __typename?: 'NotificationDelivery';
+  attempts: Scalars['Int']['output'];
   createdOn: Scalars['DateTime']['output'];
-  description: Scalars['String']['output'];
-  id: Scalars['String']['output'];
-  isDisabled?: Maybe<Scalars['Boolean']['output']>;
-  lot: NotificationPlatformLot;
-};
+  error?: Maybe<Scalars['String']['output']>;
+  lastAttemptedOn: Scalars['DateTime']['output'];
+  message: Scalars['String']['output'];
+  nextAttemptOn?: Maybe<Scalars['DateTime']['output']>;
+  status: NotificationDeliveryStatus;
+};
+
+export enum NotificationDeliveryStatus {
+  /** The delivery failed permanently or ran out of attempts. */
+  Abandoned = 'ABANDONED',
+  Delivered = 'DELIVERED',
+  /** The delivery failed and will be attempted again. */
+  Failed = 'FAILED'
+}
 
 export enum NotificationPlatformLot {
   Apprise = 'APPRISE',
   Discord = 'DISCORD',
   Email = 'EMAIL',
   Gotify = 'GOTIFY',
+  Matrix = 'MATRIX',
   Ntfy = 'NTFY',
   PushBullet = 'PUSH_BULLET',
   PushOver = 'PUSH_OVER',
   PushSafer = 'PUSH_SAFER',
+  Slack = 'SLACK',
   Telegram = 'TELEGRAM'
 }
 
+/**
+ * The SMTP server to send emails from, used instead of the one configured for the
+ * instance.
+ */
+export type NotificationPlatformSmtpSettings = {
+  /** Defaults to the mailbox configured for the instance. */
+  mailbox?: InputMaybe<Scalars['String']['input']>;
+  password: Scalars['String']['input'];
+  server: Scalars['String']['input'];
+  user: Scalars['String']['input'];
+};
+
 export type OidcTokenOutput = {
   __typename?: 'OidcTokenOutput';
   email: Scalars['String']['output'];
@@ -1687,6 +1879,7 @@ export type PersonSortInput = {
 export type PersonSourceSpecificsInput = {
   isAnilistStudio?: InputMaybe<Scalars['Boolean']['input']>;
   isHardcoverPublisher?: InputMaybe<Scalars['Boolean']['input']>;
+  isMusicBrainzLabel?: InputMaybe<Scalars['Boolean']['input']>;
   isTmdbCompany?: InputMaybe<Scalars['Boolean']['input']>;
 };
 
@@ -1772,6 +1965,9 @@ export type ProcessedExercise = {
 
 export type ProgressUpdateInput = {
   animeEpisodeNumber?: InputMaybe<Scalars['Int']['input']>;
+  boardGameDuration?: InputMaybe<Scalars['Int']['input']>;
+  boardGamePlayerCount?: InputMaybe<Scalars['Int']['input']>;
+  boardGameWinner?: InputMaybe<Scalars['String']['input']>;
   changeState?: InputMaybe<SeenState>;
   date?: InputMaybe<Scalars['NaiveDate']['input']>;
   mangaChapterNumber?: InputMaybe<Scalars['Decimal']['input']>;
@@ -1862,7 +2058,7 @@ export type QueryRoot = {
   /** Get metadata recommendations for the currently logged in user. */
   userMetadataRecommendations: Array<Scalars['String']['output']>;
   /** Get all the notification platforms for the currently logged in user. */
-  userNotificationPlatforms: Array<NotificationPlatform>;
+  userNotificationPlatforms: Array<UserNotificationPlatform>;
   /** Get details that can be displayed to a user for a creator. */
   userPersonDetails: UserPersonDetails;
   /** Get upcoming calendar events for the given filter. */
@@ -2075,6 +2271,37 @@ export type ReviewItem = {
   visibility: Visibility;
 };
 
+export type ScrapeCustomMetadataInput = {
+  /** The type of media on the page. Detected from the page if not provided. */
+  lot?: InputMaybe<MediaLot>;
+  url: Scalars['String']['input'];
+};
+
+export type ScrapedCustomMetadata = {
+  __typename?: 'ScrapedCustomMetadata';
+  animeSpecifics?: Maybe<AnimeSpecifics>;
+  audioBookSpecifics?: Maybe<AudioBookSpecifics>;
+  boardGameSpecifics?: Maybe<BoardGameSpecifics>;
+  bookSpecifics?: Maybe<BookSpecifics>;
+  comicSpecifics?: Maybe<ComicSpecifics>;
+  creators?: Maybe<Array<Scalars['String']['output']>>;
+  description?: Maybe<Scalars['String']['output']>;
+  genres?: Maybe<Array<Scalars['String']['output']>>;
+  images?: Maybe<Array<Scalars['String']['output']>>;
+  isNsfw?: Maybe<Scalars['Boolean']['output']>;
+  lot: MediaLot;
+  mangaSpecifics?: Maybe<MangaSpecifics>;
+  movieSpecifics?: Maybe<MovieSpecifics>;
+  musicSpecifics?: Maybe<MusicSpecifics>;
+  podcastSpecifics?: Maybe<PodcastSpecifics>;
+  publishYear?: Maybe<Scalars['Int']['output']>;
+  showSpecifics?: Maybe<ShowSpecifics>;
+  title: Scalars['String']['output'];
+  videoGameSpecifics?: Maybe<VideoGameSpecifics>;
+  videos?: Maybe<Array<Scalars['String']['output']>>;
+  visualNovelSpecifics?: Maybe<VisualNovelSpecifics>;
+};
+
 export type SearchDetails = {
   __typename?: 'SearchDetails';
   nextPage?: Maybe<Scalars['Int']['output']>;
@@ -2090,6 +2317,7 @@ export type SearchInput = {
 export type Seen = {
   __typename?: 'Seen';
   animeExtraInformation?: Maybe<SeenAnimeExtraInformation>;
+  boardGameExtraInformation?: Maybe<SeenBoardGameExtraInformation>;
   finishedOn?: Maybe<Scalars['NaiveDate']['output']>;
   id: Scalars['String']['output'];
   lastUpdatedOn: Scalars['DateTime']['output'];
@@ -2112,6 +2340,14 @@ export type SeenAnimeExtraInformation = {
   episode?: Maybe<Scalars['Int']['output']>;
 };
 
+export type SeenBoardGameExtraInformation = {
+  __typename?: 'SeenBoardGameExtraInformation';
+  /** The duration of the play in minutes. */
+  duration?: Maybe<Scalars['Int']['output']>;
+  playerCount?: Maybe<Scalars['Int']['output']>;
+  winner?: Maybe<Scalars['String']['output']>;
+};
+
 export type SeenMangaExtraInformation = {
   __typename?: 'SeenMangaExtraInformation';
   chapter?: Maybe<Scalars['Decimal']['output']>;
@@ -2183,6 +2419,12 @@ export type SetStatisticInput = {
 
 export type ShowEpisode = {
   __typename?: 'ShowEpisode';
+  /** The number of the episode when all regular episodes are numbered sequentially. */
+  absoluteNumber?: Maybe<Scalars['Int']['output']>;
+  /** The number of the episode in the DVD or Blu-ray release. */
+  dvdEpisodeNumber?: Maybe<Scalars['Int']['output']>;
+  /** The season of the episode in the DVD or Blu-ray release. */
+  dvdSeasonNumber?: Maybe<Scalars['Int']['output']>;
   episodeNumber: Scalars['Int']['output'];
   id: Scalars['Int']['output'];
   name: Scalars['String']['output'];
@@ -2192,7 +2434,19 @@ export type ShowEpisode = {
   runtime?: Maybe<Scalars['Int']['output']>;
 };
 
+export enum ShowEpisodeOrdering {
+  Absolute = 'ABSOLUTE',
+  Aired = 'AIRED',
+  Dvd = 'DVD'
+}
+
 export type ShowEpisodeSpecificsInput = {
+  /** The number of the episode when all regular episodes are numbered sequentially. */
+  absoluteNumber?: InputMaybe<Scalars['Int']['input']>;
+  /** The number of the episode in the DVD or Blu-ray release. */
+  dvdEpisodeNumber?: InputMaybe<Scalars['Int']['input']>;
+  /** The season of the episode in the DVD or Blu-ray release. */
+  dvdSeasonNumber?: InputMaybe<Scalars['Int']['input']>;
   episodeNumber: Scalars['Int']['input'];
   id: Scalars['Int']['input'];
   name: Scalars['String']['input'];
@@ -2245,6 +2499,22 @@ export type StringIdObject = {
   id: Scalars['String']['output'];
 };
 
+export type SwitchMetadataSourceInput = {
+  identifier: Scalars['String']['input'];
+  metadataId: Scalars['String']['input'];
+  source: MediaSource;
+};
+
+/** The code that the user has to enter on Trakt to authorize Ryot. */
+export type TraktDeviceCode = {
+  __typename?: 'TraktDeviceCode';
+  deviceCode: Scalars['String']['output'];
+  /** The number of seconds after which the code expires. */
+  expiresIn: Scalars['Int']['output'];
+  userCode: Scalars['String']['output'];
+  verificationUrl: Scalars['String']['output'];
+};
+
 export type UniqueMediaIdentifier = {
   identifier: Scalars['String']['input'];
   lot: MediaLot;
@@ -2301,7 +2571,11 @@ export type UpdateUserIntegrationInput = {
 };
 
 export type UpdateUserNotificationPlatformInput = {
+  /** Pass an empty list to send notifications of all types to the platform. */
+  contentTypes?: InputMaybe<Array<UserNotificationContent>>;
   isDisabled?: InputMaybe<Scalars['Boolean']['input']>;
+  /** Pass an empty list to send notifications about media of all lots to the platform. */
+  mediaLots?: InputMaybe<Array<MediaLot>>;
   notificationId: Scalars['String']['input'];
 };
 
@@ -2508,8 +2782,13 @@ export type UserGeneralPreferences = {
   disableWatchProviders: Scalars['Boolean']['output'];
   displayNsfw: Scalars['Boolean']['output'];
   gridPacking: GridPacking;
+  /** The ISO 639-1 code of the language to display titles and descriptions in. */
+  language?: Maybe<Scalars['String']['output']>;
   persistQueries: Scalars['Boolean']['output'];
+  /** The ISO 3166-1 code of the country to display watch providers for. */
+  region?: Maybe<Scalars['String']['output']>;
   reviewScale: UserReviewScale;
+  showEpisodeOrdering: ShowEpisodeOrdering;
   watchProviders: Array<UserGeneralWatchProvider>;
 };
 
@@ -2522,8 +2801,13 @@ export type UserGeneralPreferencesInput = {
   disableWatchProviders: Scalars['Boolean']['input'];
   displayNsfw: Scalars['Boolean']['input'];
   gridPacking: GridPacking;
+  /** The ISO 639-1 code of the language to display titles and descriptions in. */
+  language?: InputMaybe<Scalars['String']['input']>;
   persistQueries: Scalars['Boolean']['input'];
+  /** The ISO 3166-1 code of the country to display watch providers for. */
+  region?: InputMaybe<Scalars['String']['input']>;
   reviewScale: UserReviewScale;
+  showEpisodeOrdering?: InputMaybe<ShowEpisodeOrdering>;
   watchProviders: Array<UserGeneralWatchProviderInput>;
 };
 
@@ -2759,6 +3043,7 @@ export type UserMetadataGroupDetails = {
 };
 
 export enum UserNotificationContent {
+  EntityReminderDue = 'ENTITY_REMINDER_DUE',
   MetadataChaptersOrEpisodesChanged = 'METADATA_CHAPTERS_OR_EPISODES_CHANGED',
   MetadataEpisodeImagesChanged = 'METADATA_EPISODE_IMAGES_CHANGED',
   MetadataEpisodeNameChanged = 'METADATA_EPISODE_NAME_CHANGED',
@@ -2773,13 +3058,61 @@ export enum UserNotificationContent {
   ReviewPosted = 'REVIEW_POSTED'
 }
 
+export enum UserNotificationDigest {
+  Daily = 'DAILY',
+  Immediate = 'IMMEDIATE',
+  Weekly = 'WEEKLY'
+}
+
+export type UserNotificationPlatform = {
+  __typename?: 'UserNotificationPlatform';
+  /** The number of deliveries that have failed in a row. */
+  consecutiveFailures?: Maybe<Scalars['Int']['output']>;
+  /** If set, only notifications of these types are sent to this platform. */
+  contentTypes?: Maybe<Array<UserNotificationContent>>;
+  createdOn: Scalars['DateTime']['output'];
+  /** The most recent attempts to deliver notifications to this platform. */
+  deliveries: Array<NotificationDelivery>;
+  description: Scalars['String']['output'];
+  id: Scalars['String']['output'];
+  isDisabled?: Maybe<Scalars['Boolean']['output']>;
+  lot: NotificationPlatformLot;
+  /**
+   * If set, notifications about media are only sent to this platform if the media
+   * belongs to one of these lots.
+   */
+  mediaLots?: Maybe<Array<MediaLot>>;
+};
+
 export type UserNotificationsPreferences = {
   __typename?: 'UserNotificationsPreferences';
+  digest: UserNotificationDigest;
+  /**
+   * The hour (0-23) at which digests are sent. This is in the timezone of the server
+   * (set with `TZ`), not the timezone of the user.
+   */
+  digestHour: Scalars['Int']['output'];
+  /**
+   * The day of the week (1-7, starting with 1 for Monday) on which weekly digests are
+   * sent. Like the hour, it is in the timezone of the server.
+   */
+  digestWeekday: Scalars['Int']['output'];
   enabled: Scalars['Boolean']['output'];
   toSend: Array<UserNotificationContent>;
 };
 
 export type UserNotificationsPreferencesInput = {
+  digest?: UserNotificationDigest;
+  /**
+   * The hour (0-23) at which digests are sent. This is in the timezone of the server
+   * (set with `TZ`), not the timezone of the user.
+   */
+  digestHour?: Scalars['Int']['input'];
+  /**
+   * The day of the week (1-7, starting with 1 for Monday) on which weekly digests are
+   * sent. Like the hour, it is in the timezone of the server.
+   */
+  digestWeekday?: Scalars['Int']['input'];
   enabled: Scalars['Boolean']['input'];
   toSend: Array<UserNotificationContent>;
 };
@@ -0,0 +1,21 @@
+import { Button, Img, Text } from "@react-email/components";
+import Layout from "../components/Layout";
+
+const NotificationEmail = () => (
+	<Layout headingText="{{ title }}">
+		<Img
+			src="{{ image }}"
+			alt="{{ title }}"
+			className="max-h-72 max-w-full rounded-md"
+		/>
+		<Text>{"{{ body }}"}</Text>
+		<Button
+			href="{{ action_url }}"
+			className="rounded-md bg-[#5e6ad2] px-4 py-3 text-sm text-white"
+		>
+			Open in Ryot
+		</Button>
+	</Layout>
+);
+
+export default NotificationEmail;